@@ -0,0 +1,132 @@
+//! What the live recording loop needs from a speech engine. Whisper, Parakeet and
+//! Cohere all implement [`Transcriber`], so one loop in `commands/recording.rs`
+//! buffers, cuts and transcribes chunks for every engine.
+
+use crate::cohere::CohereManager;
+use crate::parakeet::ParakeetManager;
+use crate::whisper::WhisperManager;
+
+/// Default live Whisper window (pause cuts can end a chunk sooner).
+const WHISPER_LIVE_CHUNK_SECS: f32 = 6.0;
+
+/// Live Parakeet chunk length in seconds. Very short windows (~1s) hurt accuracy on
+/// streaming CTC; ~4s trades a bit of latency for much better context (see NeMo
+/// streaming / buffered-chunk guidance).
+const PARAKEET_LIVE_CHUNK_SECS: f32 = 4.0;
+
+/// Cohere decodes a fixed 15 s window.
+const COHERE_LIVE_CHUNK_SECS: f32 = 15.0;
+
+pub trait Transcriber {
+    /// Engine name used in logs and `transcription-chunk` payloads.
+    fn method(&self) -> &'static str;
+
+    /// Tag for this engine's log lines.
+    fn emoji(&self) -> &'static str;
+
+    /// Live chunk length in samples at `rate` Hz.
+    fn preferred_chunk_samples(&self, rate: u32) -> usize;
+
+    /// Transcribe one preprocessed chunk.
+    fn transcribe(&mut self, chunk: &[f32], rate: u32) -> Result<String, String>;
+
+    /// Confidence (0.0–1.0) of the last `transcribe` call, if the engine reports one.
+    fn last_confidence(&self) -> Option<f32> {
+        None
+    }
+
+    /// Whether live chunks go through the Silero speech gate before transcribing.
+    fn vad_gated(&self) -> bool {
+        true
+    }
+
+    /// Whether live chunks may be cut early at a natural pause.
+    fn pause_aware(&self) -> bool {
+        false
+    }
+
+    /// Whether `live_chunk_secs` may override `preferred_chunk_samples`.
+    fn chunk_size_adjustable(&self) -> bool {
+        true
+    }
+}
+
+impl Transcriber for WhisperManager {
+    fn method(&self) -> &'static str {
+        "Whisper"
+    }
+
+    fn emoji(&self) -> &'static str {
+        "🎙️"
+    }
+
+    fn preferred_chunk_samples(&self, rate: u32) -> usize {
+        (rate as f32 * WHISPER_LIVE_CHUNK_SECS) as usize
+    }
+
+    fn transcribe(&mut self, chunk: &[f32], rate: u32) -> Result<String, String> {
+        self.transcribe_chunk(chunk, rate)
+    }
+
+    fn last_confidence(&self) -> Option<f32> {
+        self.last_chunk_confidence()
+    }
+
+    fn pause_aware(&self) -> bool {
+        true
+    }
+}
+
+impl Transcriber for ParakeetManager {
+    fn method(&self) -> &'static str {
+        "Parakeet"
+    }
+
+    fn emoji(&self) -> &'static str {
+        "🦜"
+    }
+
+    fn preferred_chunk_samples(&self, rate: u32) -> usize {
+        (rate as f32 * PARAKEET_LIVE_CHUNK_SECS) as usize
+    }
+
+    /// Short chunks are padded with silence to the default window, which the CTC/TDT
+    /// models need for stable output.
+    fn transcribe(&mut self, chunk: &[f32], rate: u32) -> Result<String, String> {
+        let min_len = self.preferred_chunk_samples(rate);
+        if chunk.len() < min_len {
+            let mut padded = chunk.to_vec();
+            padded.resize(min_len, 0.0);
+            self.transcribe_chunk(&padded, rate)
+        } else {
+            self.transcribe_chunk(chunk, rate)
+        }
+    }
+
+    /// Nemotron/EOU keep streaming state across chunks, so every chunk is fed.
+    fn vad_gated(&self) -> bool {
+        false
+    }
+}
+
+impl Transcriber for CohereManager {
+    fn method(&self) -> &'static str {
+        "Cohere"
+    }
+
+    fn emoji(&self) -> &'static str {
+        "🪨"
+    }
+
+    fn preferred_chunk_samples(&self, rate: u32) -> usize {
+        (rate as f32 * COHERE_LIVE_CHUNK_SECS) as usize
+    }
+
+    fn transcribe(&mut self, chunk: &[f32], rate: u32) -> Result<String, String> {
+        self.transcribe_chunk(chunk, rate)
+    }
+
+    fn chunk_size_adjustable(&self) -> bool {
+        false
+    }
+}
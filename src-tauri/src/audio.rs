@@ -19,6 +19,6 @@ pub struct RecordingHandle {
     pub transcriber_thread: std::thread::JoinHandle<()>,
     pub level_stop: Arc<AtomicBool>, // Signal the level-emitter thread to exit
     pub level_thread: std::thread::JoinHandle<()>,
-    #[allow(dead_code)]
     pub sample_rate: u32, // Sample rate of the recording (needed for silence padding)
+    pub capture: crate::commands::CaptureTaps, // Lets switch_input_device rebuild the stream
 }
@@ -82,6 +82,29 @@ pub fn resample_mono_to_16k(samples: &[f32], from_rate: u32) -> Result<Vec<f32>,
     resample_mono_ratio(samples, from_rate, 16000)
 }
 
+/// Average interleaved PCM with any channel count down to mono. A trailing partial
+/// frame (sample count not a multiple of `channels`, e.g. a truncated file or a
+/// wrong channel count in the header) is dropped with a warning instead of
+/// shifting every following frame.
+pub fn downmix_to_mono(samples: &[f32], channels: usize) -> Vec<f32> {
+    if channels <= 1 {
+        return samples.to_vec();
+    }
+    let remainder = samples.len() % channels;
+    if remainder != 0 {
+        eprintln!(
+            "[WARN] {} samples is not a multiple of {} channels; dropping {} trailing sample(s)",
+            samples.len(),
+            channels,
+            remainder
+        );
+    }
+    samples
+        .chunks_exact(channels)
+        .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+        .collect()
+}
+
 fn frame_rms_list(samples: &[f32], frame: usize) -> Vec<f32> {
     if frame == 0 || samples.is_empty() {
         return Vec::new();
@@ -361,4 +384,14 @@ mod tests {
             .iter()
             .all(|&x| x.is_finite() && (-1.0..=1.0).contains(&x)));
     }
+
+    #[test]
+    fn downmix_averages_six_channels_and_drops_partial_frame() {
+        let frame = [0.6, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let mut interleaved: Vec<f32> = frame.iter().chain(frame.iter()).copied().collect();
+        interleaved.push(1.0); // partial trailing frame
+        let mono = downmix_to_mono(&interleaved, 6);
+        assert_eq!(mono.len(), 2);
+        assert!(mono.iter().all(|&s| (s - 0.1).abs() < 1e-6));
+    }
 }
@@ -291,6 +291,12 @@ impl CohereManager {
         Ok(msg)
     }
 
+    /// Run 1 second of silence through the encoder/decoder to wake up the ONNX sessions.
+    pub fn warm_up(&mut self) -> Result<(), String> {
+        let warmup_audio = vec![0.0_f32; 16000];
+        self.transcribe_chunk(&warmup_audio, 16000).map(|_| ())
+    }
+
     pub fn transcribe_chunk(
         &mut self,
         samples: &[f32],
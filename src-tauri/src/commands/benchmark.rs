@@ -0,0 +1,207 @@
+//! Batch benchmarking: run the active engine over a set of files and report speed
+//! per file as CSV, so regressions can be tracked across model/engine changes.
+
+use super::file_transcription::{
+    register_cancel_flag, transcribe_file_blocking, unregister_cancel_flag,
+};
+use crate::state::AudioState;
+use crate::types::{ASREngine, CommandResult};
+use serde::Serialize;
+use tauri::{AppHandle, State};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkRow {
+    pub file: String,
+    pub engine: String,
+    pub model_id: Option<String>,
+    pub audio_duration_ms: i64,
+    pub processing_time_ms: i64,
+    /// processing time / audio duration (lower is faster; < 1.0 is faster than real time).
+    pub real_time_factor: f64,
+    pub word_count: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkSuiteResult {
+    pub rows: Vec<BenchmarkRow>,
+    pub csv: String,
+}
+
+const CSV_HEADER: &str =
+    "file,engine,model_id,audio_duration_ms,processing_time_ms,real_time_factor,word_count,error";
+
+/// Quote a CSV field when it contains a delimiter, quote, or newline (RFC 4180).
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn rows_to_csv(rows: &[BenchmarkRow]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+    for row in rows {
+        let fields = [
+            csv_field(&row.file),
+            csv_field(&row.engine),
+            csv_field(row.model_id.as_deref().unwrap_or("")),
+            row.audio_duration_ms.to_string(),
+            row.processing_time_ms.to_string(),
+            format!("{:.4}", row.real_time_factor),
+            row.word_count.to_string(),
+            csv_field(row.error.as_deref().unwrap_or("")),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Transcribe each file with the currently loaded engine and collect timing rows.
+/// Files are processed sequentially so timings don't interfere with each other.
+/// When `output_path` is given the CSV is also written there.
+#[tauri::command]
+pub async fn benchmark_suite(
+    app: AppHandle,
+    state: State<'_, AudioState>,
+    files: Vec<String>,
+    output_path: Option<String>,
+) -> Result<BenchmarkSuiteResult, String> {
+    let whisper = state.whisper.clone();
+    let parakeet = state.parakeet.clone();
+    let cohere = state.cohere.clone();
+    let active_engine = *state.active_engine.lock().unwrap();
+
+    let (engine, model_id) = match active_engine {
+        ASREngine::Whisper => (
+            "whisper",
+            whisper.lock().unwrap().get_current_model().cloned(),
+        ),
+        ASREngine::Parakeet => ("parakeet", parakeet.lock().unwrap().get_status().model_id),
+        ASREngine::Cohere => ("cohere", cohere.lock().unwrap().get_status().model_id),
+    };
+    if model_id.is_none() {
+        return Err(format!("No {} model loaded", engine));
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut rows = Vec::with_capacity(files.len());
+        for (i, file) in files.iter().enumerate() {
+            println!(
+                "[BENCHMARK] {}/{} {} ({})",
+                i + 1,
+                files.len(),
+                file,
+                engine
+            );
+            let cancel = register_cancel_flag(file);
+            let result = transcribe_file_blocking(
+                &app,
+                file,
+                active_engine,
+                whisper.clone(),
+                parakeet.clone(),
+                cohere.clone(),
+                cancel,
+            );
+            unregister_cancel_flag(file);
+
+            let row = match result {
+                Ok(r) => BenchmarkRow {
+                    file: file.clone(),
+                    engine: engine.to_string(),
+                    model_id: model_id.clone(),
+                    audio_duration_ms: r.audio_duration_ms,
+                    processing_time_ms: r.processing_time_ms,
+                    real_time_factor: if r.audio_duration_ms > 0 {
+                        r.processing_time_ms as f64 / r.audio_duration_ms as f64
+                    } else {
+                        0.0
+                    },
+                    word_count: r.transcript.split_whitespace().count(),
+                    error: None,
+                },
+                Err(e) => {
+                    eprintln!("[BENCHMARK] {} failed: {}", file, e);
+                    BenchmarkRow {
+                        file: file.clone(),
+                        engine: engine.to_string(),
+                        model_id: model_id.clone(),
+                        audio_duration_ms: 0,
+                        processing_time_ms: 0,
+                        real_time_factor: 0.0,
+                        word_count: 0,
+                        error: Some(e),
+                    }
+                }
+            };
+            rows.push(row);
+        }
+
+        let csv = rows_to_csv(&rows);
+        if let Some(path) = output_path {
+            std::fs::write(&path, &csv)
+                .map_err(|e| format!("Failed to write benchmark CSV: {}", e))?;
+            println!("[BENCHMARK] Wrote {} rows to {}", rows.len(), path);
+        }
+        Ok(BenchmarkSuiteResult { rows, csv })
+    })
+    .await
+    .map_err(|e| format!("benchmark_suite task failed: {}", e))?
+}
+
+/// 11 s JFK clip used by `measure_rtf`, embedded so the check works without any files.
+const RTF_SAMPLE_WAV: &[u8] = include_bytes!("../../../taurscribe-runtime/samples/jfk.wav");
+
+/// Quick health check: transcribe the built-in sample with `engine` (default: the active
+/// one) and return its real-time factor. Below 1.0 is faster than real time; 0.2 means 5x.
+#[tauri::command]
+pub async fn measure_rtf(
+    app: AppHandle,
+    state: State<'_, AudioState>,
+    engine: Option<ASREngine>,
+) -> Result<CommandResult<f64>, String> {
+    let engine = engine.unwrap_or_else(|| *state.active_engine.lock().unwrap());
+    let whisper = state.whisper.clone();
+    let parakeet = state.parakeet.clone();
+    let cohere = state.cohere.clone();
+
+    // Unique per call: overlapping checks must not share the file or its cancel flag.
+    let sample_path = std::env::temp_dir().join(format!(
+        "taurscribe_rtf_sample_{}_{}.wav",
+        std::process::id(),
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    ));
+    std::fs::write(&sample_path, RTF_SAMPLE_WAV)
+        .map_err(|e| format!("Failed to write RTF sample: {}", e))?;
+    let path = sample_path.to_string_lossy().into_owned();
+
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        let cancel = register_cancel_flag(&path);
+        let result =
+            transcribe_file_blocking(&app, &path, engine, whisper, parakeet, cohere, cancel);
+        unregister_cancel_flag(&path);
+        let _ = std::fs::remove_file(&path);
+        result
+    })
+    .await
+    .map_err(|e| format!("measure_rtf task failed: {}", e))?;
+
+    Ok(match result {
+        Ok(r) if r.audio_duration_ms > 0 => {
+            let rtf = r.processing_time_ms as f64 / r.audio_duration_ms as f64;
+            println!(
+                "[BENCHMARK] {:?} RTF {:.3} ({:.1}x real time)",
+                engine,
+                rtf,
+                1.0 / rtf.max(1e-6)
+            );
+            CommandResult::ok(rtf)
+        }
+        Ok(_) => CommandResult::err("transcription_failed", "Sample decoded to zero length"),
+        Err(e) => CommandResult::err("transcription_failed", e),
+    })
+}
@@ -69,6 +69,7 @@ pub async fn init_cohere(
     let parakeet_arc = state.parakeet.clone();
     let cohere_arc = state.cohere.clone();
     let active_engine_arc = state.active_engine.clone();
+    let persisted_model_id = model_id.clone();
 
     let result = tauri::async_runtime::spawn_blocking(move || {
         // 2. Check what is currently loaded.
@@ -125,6 +126,10 @@ pub async fn init_cohere(
         Ok(Ok(msg)) => {
             state.model_loaded.store(true, Ordering::Relaxed);
             tray::update_tray_model_item(&app, true);
+            if let Some(model_id) = persisted_model_id {
+                super::persist_setting(&app, "granite_model", Some(model_id.into()));
+            }
+            super::persist_setting(&app, "active_engine", Some("cohere".into()));
             crate::memory::log_process_memory("init_cohere command success");
             Ok(CommandResult::ok(msg))
         }
@@ -154,9 +159,5 @@ pub async fn init_cohere(
 pub fn get_cohere_status(
     state: State<'_, AudioState>,
 ) -> Result<crate::cohere::CohereStatus, String> {
-    let gs = state
-        .cohere
-        .lock()
-        .map_err(|e| format!("Lock error: {}", e))?;
-    Ok(gs.get_status())
+    Ok(state.cohere_status())
 }
@@ -2,12 +2,13 @@ use crate::types::CommandResult;
 use futures_util::StreamExt;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, VecDeque};
 use std::fs::File;
 use std::io::{Read, Write};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
 use zip::ZipArchive;
 
@@ -32,8 +33,70 @@ fn unregister_cancel_flag(model_id: &str) {
     cancel_flags().lock().unwrap().remove(model_id);
 }
 
-/// Delete all files/directories belonging to a model (used on cancel or hash mismatch).
-fn delete_model_files(config: &ModelConfig, base_dir: &std::path::Path) {
+// ── HuggingFace endpoint ──────────────────────────────────────────────────────
+//
+// Some regions can't reach huggingface.co and use a mirror such as hf-mirror.com.
+// The endpoint is the `hf_endpoint` setting if set, else the `HF_ENDPOINT`
+// environment variable (the name huggingface_hub uses), else huggingface.co.
+
+const DEFAULT_HF_ENDPOINT: &str = "https://huggingface.co";
+
+fn hf_endpoint_setting() -> &'static Mutex<Option<String>> {
+    static SETTING: OnceLock<Mutex<Option<String>>> = OnceLock::new();
+    SETTING.get_or_init(|| Mutex::new(None))
+}
+
+/// Base URL for HuggingFace downloads, without a trailing slash.
+fn hf_endpoint() -> String {
+    let endpoint = hf_endpoint_setting()
+        .lock()
+        .unwrap()
+        .clone()
+        .or_else(|| std::env::var("HF_ENDPOINT").ok())
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_HF_ENDPOINT.to_string());
+    endpoint.trim().trim_end_matches('/').to_string()
+}
+
+/// Set the `hf_endpoint` override; `None` or an empty string falls back to
+/// `HF_ENDPOINT` / huggingface.co.
+pub fn set_hf_endpoint_override(endpoint: Option<String>) -> Result<(), String> {
+    let endpoint = endpoint
+        .map(|e| e.trim().to_string())
+        .filter(|e| !e.is_empty());
+    if let Some(e) = &endpoint {
+        if !e.starts_with("https://") && !e.starts_with("http://") {
+            return Err(format!(
+                "HuggingFace endpoint must be an http(s) URL: {}",
+                e
+            ));
+        }
+    }
+    *hf_endpoint_setting().lock().unwrap() = endpoint;
+    Ok(())
+}
+
+/// The HuggingFace base URL downloads currently use.
+#[tauri::command]
+pub fn get_hf_endpoint() -> String {
+    hf_endpoint()
+}
+
+/// Download from a HuggingFace mirror (e.g. `https://hf-mirror.com`) instead of
+/// huggingface.co. Pass `None` or "" to go back to the default. Persisted as
+/// `hf_endpoint` and restored at startup.
+#[tauri::command]
+pub fn set_hf_endpoint(app: AppHandle, endpoint: Option<String>) -> Result<(), String> {
+    set_hf_endpoint_override(endpoint)?;
+    let saved = hf_endpoint_setting().lock().unwrap().clone();
+    super::persist_setting(&app, "hf_endpoint", saved.map(Into::into));
+    println!("[DOWNLOAD] HuggingFace endpoint: {}", hf_endpoint());
+    Ok(())
+}
+
+/// Delete a model's finished files but keep any `.part` downloads, so the next
+/// attempt can resume them with a Range request.
+fn delete_finished_files(config: &ModelConfig, base_dir: &std::path::Path) {
     for fs in &config.files {
         let p = base_dir.join(fs.filename);
         if p.is_dir() {
@@ -47,6 +110,32 @@ fn delete_model_files(config: &ModelConfig, base_dir: &std::path::Path) {
     }
 }
 
+/// Delete all files/directories belonging to a model, partial downloads included
+/// (used on cancel or hash mismatch).
+fn delete_model_files(config: &ModelConfig, base_dir: &std::path::Path) {
+    for fs in &config.files {
+        let _ = std::fs::remove_file(part_path(&download_path(base_dir, fs)));
+    }
+    delete_finished_files(config, base_dir);
+}
+
+/// Where a model file is streamed to: the file itself, or `<filename>.zip` for
+/// zipped entries that are extracted afterwards.
+fn download_path(base_dir: &std::path::Path, file_spec: &ModelFile) -> std::path::PathBuf {
+    if file_spec.remote_path.ends_with(".zip") {
+        base_dir.join(format!("{}.zip", file_spec.filename))
+    } else {
+        base_dir.join(file_spec.filename)
+    }
+}
+
+/// In-progress download of `path`; renamed to `path` once the last byte is written.
+fn part_path(path: &std::path::Path) -> std::path::PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".part");
+    std::path::PathBuf::from(name)
+}
+
 // ── Download lock files ───────────────────────────────────────────────────────
 //
 // A `<model_id>.downloading` sentinel is written into the models directory at
@@ -55,7 +144,8 @@ fn delete_model_files(config: &ModelConfig, base_dir: &std::path::Path) {
 //
 // If the app is force-quit or crashes while a download is running the sentinel
 // is never removed, so on the next launch `scan_and_clean_stale_downloads`
-// finds it and wipes the orphaned partial files before the UI is shown.
+// finds it and wipes the orphaned model files before the UI is shown. `.part`
+// files are kept so the next download of that model resumes where it stopped.
 
 fn lock_file_path(model_id: &str) -> Option<std::path::PathBuf> {
     crate::utils::get_models_dir()
@@ -77,7 +167,8 @@ fn remove_download_lock(model_id: &str) {
 
 /// Called once at app startup.  Finds any leftover `*.downloading` sentinel
 /// files (from a crash or force-quit during a previous download), wipes the
-/// associated partial model files, and removes the sentinel.
+/// associated finished model files (keeping resumable `.part` files), and
+/// removes the sentinel.
 pub fn scan_and_clean_stale_downloads() {
     let models_dir = match crate::utils::get_models_dir() {
         Ok(d) => d,
@@ -107,7 +198,7 @@ pub fn scan_and_clean_stale_downloads() {
             } else {
                 models_dir.clone()
             };
-            delete_model_files(&config, &base_dir);
+            delete_finished_files(&config, &base_dir);
             // Remove the stale verified.json entry if any
             let mut store = load_verified_store();
             if store.remove(model_id).is_some() {
@@ -199,12 +290,59 @@ pub struct DownloadProgressPayload {
     pub model_id: String,
     pub total_bytes: u64,
     pub downloaded_bytes: u64,
-    pub status: String, // "downloading" | "verifying" | "done" | "error"
+    pub status: String, // "downloading" | "extracting" | "done" | "error" | "cancelled"
     pub current_file: u32,
     pub total_files: u32,
+    /// Recent download speed; 0 outside the `downloading` phase.
+    pub bytes_per_sec: u64,
+    /// Seconds left at `bytes_per_sec`; 0 when unknown.
+    pub eta_secs: u64,
+}
+
+/// Emitted as `download-stalled` once no bytes have arrived for [`STALL_NOTICE`], so
+/// the UI can offer to cancel and restart before the read timeout fails the download.
+#[derive(Clone, Serialize)]
+pub struct DownloadStalledPayload {
+    pub model_id: String,
+    pub downloaded_bytes: u64,
+    pub stalled_secs: u64,
 }
 
-/// Delete partial model files and emit `error` to the download manager UI.
+/// How long the stream may go quiet before `download-stalled` is emitted.
+const STALL_NOTICE: Duration = Duration::from_secs(10);
+/// How often a quiet stream wakes up to check the cancel flag.
+const CANCEL_POLL: Duration = Duration::from_millis(500);
+/// Span of recent progress the reported download speed is averaged over.
+const SPEED_WINDOW: Duration = Duration::from_secs(5);
+
+/// Delete partial model files and emit `cancelled` to the download manager UI.
+fn emit_cancelled_and_cleanup(
+    app: &AppHandle,
+    model_id: &str,
+    config: &ModelConfig,
+    base_dir: &std::path::Path,
+    current_file: u32,
+    files_count: u32,
+) -> String {
+    delete_model_files(config, base_dir);
+    let _ = app.emit(
+        "download-progress",
+        DownloadProgressPayload {
+            model_id: model_id.to_string(),
+            total_bytes: 0,
+            downloaded_bytes: 0,
+            status: "cancelled".to_string(),
+            current_file,
+            total_files: files_count,
+            bytes_per_sec: 0,
+            eta_secs: 0,
+        },
+    );
+    "Download cancelled by user".to_string()
+}
+
+/// Delete finished model files and emit `error` to the download manager UI.
+/// `.part` files stay behind so retrying resumes them.
 fn emit_download_error_and_cleanup(
     app: &AppHandle,
     model_id: &str,
@@ -214,7 +352,7 @@ fn emit_download_error_and_cleanup(
     files_count: u32,
     message: &str,
 ) -> String {
-    delete_model_files(config, base_dir);
+    delete_finished_files(config, base_dir);
     let _ = app.emit(
         "download-progress",
         DownloadProgressPayload {
@@ -224,6 +362,8 @@ fn emit_download_error_and_cleanup(
             status: "error".to_string(),
             current_file,
             total_files: files_count,
+            bytes_per_sec: 0,
+            eta_secs: 0,
         },
     );
     eprintln!("[DOWNLOAD] {}", message);
@@ -265,7 +405,13 @@ pub async fn get_download_status(
 
             for file_spec in &config.files {
                 let file_path = base_dir.join(file_spec.filename);
-                if file_path.exists() {
+                // A zipped entry's archive is only removed once extraction finishes, so
+                // while it's still there the directory may be half-populated.
+                if file_spec.remote_path.ends_with(".zip")
+                    && download_path(&base_dir, file_spec).exists()
+                {
+                    all_exist = false;
+                } else if file_path.exists() {
                     if file_path.is_dir() {
                         total_size += 1; // CoreML .mlmodelc directories
                     } else if let Ok(metadata) = std::fs::metadata(&file_path) {
@@ -312,6 +458,524 @@ pub async fn get_download_status(
     Ok(statuses)
 }
 
+/// Bytes on disk per category, for the "Taurscribe is using X GB" view.
+#[derive(Serialize)]
+pub struct StorageSummary {
+    pub models_bytes: u64,
+    /// SymSpell frequency dictionaries (stored alongside the models).
+    pub dictionaries_bytes: u64,
+    /// WAVs kept in the recordings folder.
+    pub recordings_bytes: u64,
+    pub history_bytes: u64,
+    pub total_bytes: u64,
+}
+
+/// Recursively sum file sizes under `path`, splitting off SymSpell dictionary files.
+/// Returns (other_bytes, dictionary_bytes). Unreadable entries are skipped.
+fn dir_usage(path: &std::path::Path) -> (u64, u64) {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return (0, 0);
+    };
+    let (mut other, mut dicts) = (0u64, 0u64);
+    for entry in entries.flatten() {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            let (o, d) = dir_usage(&entry.path());
+            other += o;
+            dicts += d;
+        } else if entry
+            .file_name()
+            .to_string_lossy()
+            .starts_with("frequency_dictionary")
+        {
+            dicts += metadata.len();
+        } else {
+            other += metadata.len();
+        }
+    }
+    (other, dicts)
+}
+
+#[tauri::command]
+pub async fn get_storage_summary() -> Result<StorageSummary, String> {
+    tauri::async_runtime::spawn_blocking(|| {
+        let (models_bytes, dictionaries_bytes) = dir_usage(&crate::utils::get_models_dir()?);
+        let (recordings_bytes, _) = dir_usage(&crate::utils::get_recordings_dir()?);
+        let history_bytes = dirs::data_local_dir()
+            .map(|d| d.join("Taurscribe").join("transcript_history.db"))
+            .and_then(|p| std::fs::metadata(p).ok())
+            .map(|m| m.len())
+            .unwrap_or(0);
+
+        Ok(StorageSummary {
+            models_bytes,
+            dictionaries_bytes,
+            recordings_bytes,
+            history_bytes,
+            total_bytes: models_bytes + dictionaries_bytes + recordings_bytes + history_bytes,
+        })
+    })
+    .await
+    .map_err(|e| format!("get_storage_summary task failed: {}", e))?
+}
+
+/// Files of one model fetched concurrently.
+const MAX_PARALLEL_FILES: usize = 4;
+/// Bytes a file download receives between `download-progress` emits.
+const EMIT_THRESHOLD: u64 = 1024 * 1024; // 1 MB
+
+/// Why one file of a model stopped downloading.
+enum FileFailure {
+    Cancelled,
+    Error(String),
+}
+
+/// Counters shared by a model's concurrent file downloads, so every progress event
+/// describes the whole model.
+struct DownloadProgress {
+    total_bytes: AtomicU64,
+    downloaded_bytes: AtomicU64,
+    files_done: AtomicU32,
+    files_count: u32,
+    /// One `download-stalled` per stall, however many files are waiting.
+    stall_reported: AtomicBool,
+    /// Bytes actually received this run (unlike `downloaded_bytes`, excludes resumed
+    /// `.part` contents), sampled at each emit for the speed estimate.
+    received_bytes: AtomicU64,
+    speed_samples: Mutex<VecDeque<(Instant, u64)>>,
+}
+
+impl DownloadProgress {
+    /// 1-based index of the file being worked on: finished files + 1.
+    fn current_file(&self) -> u32 {
+        (self.files_done.load(Ordering::Relaxed) + 1).min(self.files_count)
+    }
+
+    /// `(bytes_per_sec, eta_secs)` over roughly the last [`SPEED_WINDOW`] of emits.
+    fn speed(&self, total_bytes: u64, downloaded_bytes: u64) -> (u64, u64) {
+        let now = Instant::now();
+        let received = self.received_bytes.load(Ordering::Relaxed);
+        let mut samples = self.speed_samples.lock().unwrap();
+        samples.push_back((now, received));
+        // Keep one sample at or before the window start as the baseline.
+        while samples.len() > 1 && now.duration_since(samples[1].0) >= SPEED_WINDOW {
+            samples.pop_front();
+        }
+        let (start, start_received) = samples[0];
+        let secs = now.duration_since(start).as_secs_f64();
+        if secs <= 0.0 || received <= start_received {
+            return (0, 0);
+        }
+        let bytes_per_sec = ((received - start_received) as f64 / secs) as u64;
+        let eta_secs = if bytes_per_sec > 0 {
+            total_bytes.saturating_sub(downloaded_bytes) / bytes_per_sec
+        } else {
+            0
+        };
+        (bytes_per_sec, eta_secs)
+    }
+}
+
+/// URL of one model file on HuggingFace or raw GitHub.
+fn file_url(config: &ModelConfig, file_spec: &ModelFile) -> String {
+    if let Some(repo_path) = config.repo.strip_prefix("github:") {
+        format!(
+            "https://raw.githubusercontent.com/{}/{}/{}",
+            repo_path, config.branch, file_spec.remote_path
+        )
+    } else {
+        format!(
+            "{}/{}/resolve/{}/{}",
+            hf_endpoint(),
+            config.repo,
+            config.branch,
+            file_spec.remote_path
+        )
+    }
+}
+
+/// Feed a file's bytes into `hasher`.
+fn hash_file(path: &std::path::Path, hasher: &mut Sha256) -> std::io::Result<()> {
+    let mut file = File::open(path)?;
+    let mut buffer = [0u8; 65536]; // 64 KiB chunks for speed
+    loop {
+        let count = file.read(&mut buffer)?;
+        if count == 0 {
+            return Ok(());
+        }
+        hasher.update(&buffer[..count]);
+    }
+}
+
+/// Size of a remote file from a HEAD request (redirects followed), if the server sends one.
+async fn head_content_length(client: &Client, url: &str) -> Option<u64> {
+    let res = client.head(url).send().await.ok()?;
+    if !res.status().is_success() {
+        return None;
+    }
+    res.headers()
+        .get(reqwest::header::CONTENT_LENGTH)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+}
+
+/// Everything a single file download needs from `download_model_inner`.
+struct DownloadJob<'a> {
+    app: &'a AppHandle,
+    client: &'a Client,
+    model_id: &'a str,
+    config: &'a ModelConfig,
+    base_dir: &'a std::path::Path,
+    progress: &'a DownloadProgress,
+    cancel_flag: &'a AtomicBool,
+}
+
+impl DownloadJob<'_> {
+    fn emit(&self, status: &str, total_bytes: u64, downloaded_bytes: u64, speed: (u64, u64)) {
+        let _ = self.app.emit(
+            "download-progress",
+            DownloadProgressPayload {
+                model_id: self.model_id.to_string(),
+                total_bytes,
+                downloaded_bytes,
+                status: status.to_string(),
+                current_file: self.progress.current_file(),
+                total_files: self.progress.files_count,
+                bytes_per_sec: speed.0,
+                eta_secs: speed.1,
+            },
+        );
+    }
+
+    fn emit_downloading(&self) {
+        let total = self.progress.total_bytes.load(Ordering::Relaxed);
+        let downloaded = self.progress.downloaded_bytes.load(Ordering::Relaxed);
+        self.emit(
+            "downloading",
+            total,
+            downloaded,
+            self.progress.speed(total, downloaded),
+        );
+    }
+
+    /// Download (and, for zips, extract) one file. Bytes go to a `.part` file that is
+    /// resumed with a Range request if one is left over from an earlier attempt, and
+    /// are SHA-256 hashed as they arrive. Returns the verified hash, or "" when the
+    /// file has no expected hash.
+    async fn download_file(
+        &self,
+        index: usize,
+        file_spec: &ModelFile,
+        known_size: Option<u64>,
+    ) -> Result<String, FileFailure> {
+        let url = file_url(self.config, file_spec);
+        let download_path = download_path(self.base_dir, file_spec);
+        let part = part_path(&download_path);
+        let existing = std::fs::metadata(&part).map(|m| m.len()).unwrap_or(0);
+
+        println!(
+            "[DOWNLOAD] {} ({}/{}) from {}",
+            self.model_id,
+            index + 1,
+            self.progress.files_count,
+            url
+        );
+
+        let mut req = self.client.get(&url);
+        if existing > 0 {
+            req = req.header(reqwest::header::RANGE, format!("bytes={}-", existing));
+        }
+        let res = req.send().await.map_err(|e| {
+            let reason = if e.is_connect() || e.is_timeout() {
+                "No internet connection — check your network and try again."
+            } else {
+                "Failed to connect to download server."
+            };
+            FileFailure::Error(reason.to_string())
+        })?;
+
+        let expected = self.expected_hash(file_spec).await;
+        let mut hasher = Sha256::new();
+
+        // 416: the range starts at the end of the file, so the `.part` is already whole.
+        // The hash check below still catches a corrupt one.
+        if existing > 0 && res.status() == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+            println!(
+                "[DOWNLOAD] {:?} already complete ({} bytes)",
+                part, existing
+            );
+            if known_size.is_none() {
+                self.progress
+                    .total_bytes
+                    .fetch_add(existing, Ordering::Relaxed);
+            }
+            self.progress
+                .downloaded_bytes
+                .fetch_add(existing, Ordering::Relaxed);
+            hash_file(&part, &mut hasher).map_err(|e| {
+                FileFailure::Error(format!(
+                    "Download failed — could not read file for verification ({})",
+                    e
+                ))
+            })?;
+            return self.finish_file(&part, &download_path, file_spec, hasher, &expected);
+        }
+
+        if !res.status().is_success() {
+            return Err(FileFailure::Error(format!(
+                "Download server returned HTTP {}",
+                res.status()
+            )));
+        }
+
+        // Anything but 206 means the server sent the whole file, so start the part over.
+        let resumed = existing > 0 && res.status() == reqwest::StatusCode::PARTIAL_CONTENT;
+        if resumed {
+            println!("[DOWNLOAD] Resuming {:?} from byte {}", part, existing);
+            // The hash covers the whole file, so feed it what's already on disk.
+            hash_file(&part, &mut hasher).map_err(|e| {
+                FileFailure::Error(format!(
+                    "Download failed — could not read file for verification ({})",
+                    e
+                ))
+            })?;
+            self.progress
+                .downloaded_bytes
+                .fetch_add(existing, Ordering::Relaxed);
+        } else if existing > 0 {
+            println!(
+                "[DOWNLOAD] Server ignored Range for {:?} — starting over",
+                part
+            );
+        }
+
+        // The HEAD pre-pass didn't get this file's size; count it now that it's known.
+        if known_size.is_none() {
+            if let Some(len) = res.content_length() {
+                let already = if resumed { existing } else { 0 };
+                self.progress
+                    .total_bytes
+                    .fetch_add(len + already, Ordering::Relaxed);
+            }
+        }
+
+        let mut file = if resumed {
+            std::fs::OpenOptions::new().append(true).open(&part)
+        } else {
+            File::create(&part)
+        }
+        .map_err(|e| FileFailure::Error(format!("Failed to create file: {}", e)))?;
+
+        let mut stream = res.bytes_stream();
+        let mut since_emit: u64 = 0;
+        let mut last_data = Instant::now();
+        let mut seen_total = self.progress.downloaded_bytes.load(Ordering::Relaxed);
+
+        loop {
+            // Poll with a short timeout so a silent stream still notices cancellation
+            // (otherwise the flag is only seen when the next chunk arrives).
+            let item = match tokio::time::timeout(CANCEL_POLL, stream.next()).await {
+                Ok(Some(item)) => item,
+                Ok(None) => break,
+                Err(_) => {
+                    if self.cancel_flag.load(Ordering::Relaxed) {
+                        return Err(FileFailure::Cancelled);
+                    }
+                    // Only a stall when no file of the model is receiving data.
+                    let total_now = self.progress.downloaded_bytes.load(Ordering::Relaxed);
+                    if total_now != seen_total {
+                        seen_total = total_now;
+                        last_data = Instant::now();
+                    }
+                    let stalled = last_data.elapsed();
+                    if stalled >= STALL_NOTICE
+                        && !self.progress.stall_reported.swap(true, Ordering::Relaxed)
+                    {
+                        println!(
+                            "[DOWNLOAD] {} stalled at {} bytes ({}s without data)",
+                            self.model_id,
+                            total_now,
+                            stalled.as_secs()
+                        );
+                        let _ = self.app.emit(
+                            "download-stalled",
+                            DownloadStalledPayload {
+                                model_id: self.model_id.to_string(),
+                                downloaded_bytes: total_now,
+                                stalled_secs: stalled.as_secs(),
+                            },
+                        );
+                    }
+                    continue;
+                }
+            };
+            last_data = Instant::now();
+            self.progress.stall_reported.store(false, Ordering::Relaxed);
+            let chunk = match item {
+                Ok(c) => c,
+                Err(e) => {
+                    let reason = if e.is_timeout() {
+                        "Connection lost — no data received for 30 seconds. Check your internet and try again."
+                    } else if e.is_connect()
+                        || e.to_string().contains("reset")
+                        || e.to_string().contains("connection")
+                    {
+                        "Connection lost during download. Check your internet and try again."
+                    } else {
+                        "Download interrupted — a network error occurred."
+                    };
+                    return Err(FileFailure::Error(reason.to_string()));
+                }
+            };
+            if let Err(e) = file.write_all(&chunk) {
+                return Err(FileFailure::Error(format!(
+                    "Download failed — could not write file ({})",
+                    e
+                )));
+            }
+            hasher.update(&chunk);
+            let n = chunk.len() as u64;
+            self.progress.received_bytes.fetch_add(n, Ordering::Relaxed);
+            seen_total = self
+                .progress
+                .downloaded_bytes
+                .fetch_add(n, Ordering::Relaxed)
+                + n;
+            since_emit += n;
+
+            if since_emit > EMIT_THRESHOLD {
+                since_emit = 0;
+                self.emit_downloading();
+
+                // Check for user cancellation at each progress emit.
+                if self.cancel_flag.load(Ordering::Relaxed) {
+                    return Err(FileFailure::Cancelled);
+                }
+            }
+        }
+        drop(file);
+        self.finish_file(&part, &download_path, file_spec, hasher, &expected)
+    }
+
+    /// Expected SHA-256 of a file: the live HuggingFace LFS pointer when available,
+    /// otherwise the registry hash (empty when the registry has none).
+    async fn expected_hash(&self, file_spec: &ModelFile) -> String {
+        if !self.config.repo.starts_with("github:") {
+            if let Some(h) = fetch_hf_lfs_sha256(
+                self.client,
+                self.config.repo,
+                self.config.branch,
+                file_spec.remote_path,
+            )
+            .await
+            {
+                return h;
+            }
+        }
+        file_spec.sha1.to_string()
+    }
+
+    /// Check a completed `.part` against its expected hash, move it into place, count
+    /// the file as done and unpack zips. A mismatching part is deleted so the retry
+    /// starts from scratch instead of resuming corrupt bytes.
+    fn finish_file(
+        &self,
+        part: &std::path::Path,
+        download_path: &std::path::Path,
+        file_spec: &ModelFile,
+        hasher: Sha256,
+        expected: &str,
+    ) -> Result<String, FileFailure> {
+        let hash_hex = if expected.is_empty() {
+            String::new()
+        } else {
+            let hash_hex = hex::encode(hasher.finalize());
+            println!(
+                "[VERIFY] {} — Expected: {}, Got: {}",
+                file_spec.filename, expected, hash_hex
+            );
+            if hash_hex != expected {
+                eprintln!("[VERIFY] Hash mismatch! Deleting corrupted download.");
+                let _ = std::fs::remove_file(part);
+                return Err(FileFailure::Error(format!(
+                    "Download failed — file may be corrupted ({}). Try again.",
+                    file_spec.filename
+                )));
+            }
+            hash_hex
+        };
+
+        std::fs::rename(part, download_path)
+            .map_err(|e| FileFailure::Error(format!("Failed to finalize download: {}", e)))?;
+        self.progress.files_done.fetch_add(1, Ordering::Relaxed);
+        self.emit_downloading();
+
+        if file_spec.remote_path.ends_with(".zip") {
+            self.extract_zip(download_path)?;
+        }
+        Ok(hash_hex)
+    }
+
+    /// Unpack a downloaded zip into the model folder and delete the archive.
+    fn extract_zip(&self, download_path: &std::path::Path) -> Result<(), FileFailure> {
+        // Emit extraction-start event so the UI can show the purple bar.
+        self.emit("extracting", 0, 0, (0, 0));
+
+        println!("[DOWNLOAD] Extracting zip: {:?}", download_path);
+
+        let zip_fail = |detail: String| -> FileFailure {
+            let _ = std::fs::remove_file(download_path);
+            FileFailure::Error(detail)
+        };
+
+        let zip_file = File::open(download_path)
+            .map_err(|e| zip_fail(format!("Failed to open zip for extraction: {}", e)))?;
+        let mut archive = ZipArchive::new(zip_file)
+            .map_err(|e| zip_fail(format!("Failed to read zip archive: {}", e)))?;
+        let total_entries = archive.len() as u64;
+
+        for entry_idx in 0..archive.len() {
+            let mut entry = archive
+                .by_index(entry_idx)
+                .map_err(|e| zip_fail(format!("Failed to read zip entry: {}", e)))?;
+            let outpath = match entry.enclosed_name() {
+                Some(path) => self.base_dir.join(path),
+                None => continue,
+            };
+            if entry.is_dir() {
+                std::fs::create_dir_all(&outpath).map_err(|e| {
+                    zip_fail(format!("Failed to create dir during extraction: {}", e))
+                })?;
+            } else {
+                if let Some(parent) = outpath.parent() {
+                    std::fs::create_dir_all(parent)
+                        .map_err(|e| zip_fail(format!("Failed to create parent dir: {}", e)))?;
+                }
+                let mut out_file = File::create(&outpath)
+                    .map_err(|e| zip_fail(format!("Failed to create extracted file: {}", e)))?;
+                std::io::copy(&mut entry, &mut out_file)
+                    .map_err(|e| zip_fail(format!("Failed to write extracted file: {}", e)))?;
+            }
+            // Progress: bytes = entries done, total = total entries
+            self.emit("extracting", total_entries, (entry_idx + 1) as u64, (0, 0));
+
+            // Check for cancellation during extraction.
+            if self.cancel_flag.load(Ordering::Relaxed) {
+                let _ = std::fs::remove_file(download_path);
+                return Err(FileFailure::Cancelled);
+            }
+        }
+
+        std::fs::remove_file(download_path).ok();
+        println!("[DOWNLOAD] Extraction complete.");
+        Ok(())
+    }
+}
+
 #[tauri::command]
 pub async fn download_model(app: AppHandle, model_id: String) -> Result<String, String> {
     let cancel_flag = register_cancel_flag(&model_id);
@@ -330,10 +994,7 @@ async fn fetch_hf_lfs_sha256(
     branch: &str,
     remote_path: &str,
 ) -> Option<String> {
-    let url = format!(
-        "https://huggingface.co/{}/raw/{}/{}",
-        repo, branch, remote_path
-    );
+    let url = format!("{}/{}/raw/{}/{}", hf_endpoint(), repo, branch, remote_path);
     let res = client.get(&url).send().await.ok()?;
     if !res.status().is_success() {
         return None;
@@ -369,7 +1030,8 @@ async fn download_model_inner(
     // Clean up any orphaned files from a previous partial/crashed download before
     // starting fresh.  This covers the case where the app was force-quit after
     // some files had already been written but before the lock was removed.
-    delete_model_files(&config, &base_dir);
+    // `.part` files survive and are resumed below.
+    delete_finished_files(&config, &base_dir);
     // Also clear any stale verified.json entry so the UI won't flash "Verified"
     // for a fraction of a second before the new download completes.
     {
@@ -394,233 +1056,96 @@ async fn download_model_inner(
         .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
 
     // ── Download phase ────────────────────────────────────────────────────────
-    for (i, file_spec) in config.files.iter().enumerate() {
-        let url = if config.repo.starts_with("github:") {
-            let repo_path = config.repo.trim_start_matches("github:");
-            format!(
-                "https://raw.githubusercontent.com/{}/{}/{}",
-                repo_path, config.branch, file_spec.remote_path
-            )
-        } else {
-            format!(
-                "https://huggingface.co/{}/resolve/{}/{}",
-                config.repo, config.branch, file_spec.remote_path
-            )
-        };
-
-        let is_zip = file_spec.remote_path.ends_with(".zip");
-        let download_path = if is_zip {
-            base_dir.join(format!("{}.zip", file_spec.filename))
-        } else {
-            base_dir.join(file_spec.filename)
-        };
-
-        println!(
-            "[DOWNLOAD] {} ({}/{}) from {}",
+    // Sizes come from a HEAD pre-pass so progress reports the whole model's total from
+    // the start; then up to MAX_PARALLEL_FILES files download at once.
+    let sizes = futures_util::future::join_all(
+        config
+            .files
+            .iter()
+            .map(|file_spec| head_content_length(&client, &file_url(&config, file_spec))),
+    )
+    .await;
+    if cancel_flag.load(Ordering::Relaxed) {
+        return Err(emit_cancelled_and_cleanup(
+            app,
             model_id,
-            i + 1,
-            files_count,
-            url
-        );
-
-        let emit_error =
-            |app: &AppHandle, model_id: &str, i: usize, files_count: usize, msg: &str| {
-                emit_download_error_and_cleanup(
-                    app,
-                    model_id,
-                    &config,
-                    &base_dir,
-                    (i + 1) as u32,
-                    files_count as u32,
-                    msg,
-                )
-            };
-
-        let res = client.get(&url).send().await.map_err(|e| {
-            let reason = if e.is_connect() || e.is_timeout() {
-                "No internet connection — check your network and try again."
-            } else {
-                "Failed to connect to download server."
-            };
-            emit_error(app, model_id, i, files_count, reason)
-        })?;
-
-        if !res.status().is_success() {
-            return Err(emit_error(
-                app,
-                model_id,
-                i,
-                files_count,
-                &format!("Download server returned HTTP {}", res.status()),
-            ));
-        }
-
-        let total_size = res.content_length().unwrap_or(0);
-        let mut file =
-            File::create(&download_path).map_err(|e| format!("Failed to create file: {}", e))?;
-
-        let mut downloaded: u64 = 0;
-        let mut stream = res.bytes_stream();
-        let mut last_emit: u64 = 0;
-        let emit_threshold = 1024 * 1024; // 1 MB
-
-        while let Some(item) = stream.next().await {
-            let chunk = match item {
-                Ok(c) => c,
-                Err(e) => {
-                    drop(file);
-                    let _ = std::fs::remove_file(&download_path);
-                    let reason = if e.is_timeout() {
-                        "Connection lost — no data received for 30 seconds. Check your internet and try again."
-                    } else if e.is_connect()
-                        || e.to_string().contains("reset")
-                        || e.to_string().contains("connection")
-                    {
-                        "Connection lost during download. Check your internet and try again."
-                    } else {
-                        "Download interrupted — a network error occurred."
-                    };
-                    return Err(emit_error(app, model_id, i, files_count, reason));
-                }
-            };
-            if let Err(e) = file.write_all(&chunk) {
-                drop(file);
-                let _ = std::fs::remove_file(&download_path);
-                return Err(emit_error(
-                    app,
-                    model_id,
-                    i,
-                    files_count,
-                    &format!("Download failed — could not write file ({})", e),
-                ));
-            }
-            downloaded += chunk.len() as u64;
-
-            if downloaded - last_emit > emit_threshold || downloaded == total_size {
-                last_emit = downloaded;
-                let _ = app.emit(
-                    "download-progress",
-                    DownloadProgressPayload {
-                        model_id: model_id.to_string(),
-                        total_bytes: total_size,
-                        downloaded_bytes: downloaded,
-                        status: "downloading".to_string(),
-                        current_file: (i + 1) as u32,
-                        total_files: files_count as u32,
-                    },
-                );
+            &config,
+            &base_dir,
+            1,
+            files_count as u32,
+        ));
+    }
+    let progress = DownloadProgress {
+        total_bytes: AtomicU64::new(sizes.iter().flatten().sum()),
+        downloaded_bytes: AtomicU64::new(0),
+        files_done: AtomicU32::new(0),
+        files_count: files_count as u32,
+        stall_reported: AtomicBool::new(false),
+        received_bytes: AtomicU64::new(0),
+        speed_samples: Mutex::new(VecDeque::from([(Instant::now(), 0)])),
+    };
+    let job = DownloadJob {
+        app,
+        client: &client,
+        model_id,
+        config: &config,
+        base_dir: &base_dir,
+        progress: &progress,
+        cancel_flag,
+    };
 
-                // Check for user cancellation at each progress emit.
-                if cancel_flag.load(Ordering::Relaxed) {
-                    drop(file);
-                    let _ = std::fs::remove_file(&download_path);
-                    delete_model_files(&config, &base_dir);
-                    let _ = app.emit(
-                        "download-progress",
-                        DownloadProgressPayload {
-                            model_id: model_id.to_string(),
-                            total_bytes: 0,
-                            downloaded_bytes: 0,
-                            status: "cancelled".to_string(),
-                            current_file: (i + 1) as u32,
-                            total_files: files_count as u32,
-                        },
-                    );
-                    return Err("Download cancelled by user".to_string());
-                }
+    let mut downloads = futures_util::stream::iter(config.files.iter().zip(sizes).enumerate().map(
+        |(i, (file_spec, size))| {
+            let job = &job;
+            async move {
+                job.download_file(i, file_spec, size)
+                    .await
+                    .map(|hash| (i, hash))
+                    .map_err(|failure| (i, failure))
             }
-        }
-        drop(file);
-
-        if is_zip {
-            // Emit extraction-start event so the UI can show the purple bar.
-            let _ = app.emit(
-                "download-progress",
-                DownloadProgressPayload {
-                    model_id: model_id.to_string(),
-                    total_bytes: 0,
-                    downloaded_bytes: 0,
-                    status: "extracting".to_string(),
-                    current_file: (i + 1) as u32,
-                    total_files: files_count as u32,
-                },
-            );
-
-            println!("[DOWNLOAD] Extracting zip: {:?}", download_path);
-
-            let zip_fail = |detail: String| -> String {
-                let _ = std::fs::remove_file(&download_path);
-                emit_error(app, model_id, i, files_count, &detail)
-            };
-
-            let zip_file = File::open(&download_path)
-                .map_err(|e| zip_fail(format!("Failed to open zip for extraction: {}", e)))?;
-            let mut archive = ZipArchive::new(zip_file)
-                .map_err(|e| zip_fail(format!("Failed to read zip archive: {}", e)))?;
-            let total_entries = archive.len() as u64;
-
-            for entry_idx in 0..archive.len() {
-                let mut entry = archive
-                    .by_index(entry_idx)
-                    .map_err(|e| zip_fail(format!("Failed to read zip entry: {}", e)))?;
-                let outpath = match entry.enclosed_name() {
-                    Some(path) => base_dir.join(path),
-                    None => continue,
-                };
-                if entry.is_dir() {
-                    std::fs::create_dir_all(&outpath).map_err(|e| {
-                        zip_fail(format!("Failed to create dir during extraction: {}", e))
-                    })?;
-                } else {
-                    if let Some(parent) = outpath.parent() {
-                        std::fs::create_dir_all(parent)
-                            .map_err(|e| zip_fail(format!("Failed to create parent dir: {}", e)))?;
-                    }
-                    let mut out_file = File::create(&outpath)
-                        .map_err(|e| zip_fail(format!("Failed to create extracted file: {}", e)))?;
-                    std::io::copy(&mut entry, &mut out_file)
-                        .map_err(|e| zip_fail(format!("Failed to write extracted file: {}", e)))?;
-                }
-                // Progress: bytes = entries done, total = total entries
-                let _ = app.emit(
-                    "download-progress",
-                    DownloadProgressPayload {
-                        model_id: model_id.to_string(),
-                        total_bytes: total_entries,
-                        downloaded_bytes: (entry_idx + 1) as u64,
-                        status: "extracting".to_string(),
-                        current_file: (i + 1) as u32,
-                        total_files: files_count as u32,
-                    },
-                );
-
-                // Check for cancellation during extraction.
-                if cancel_flag.load(Ordering::Relaxed) {
-                    let _ = std::fs::remove_file(&download_path);
-                    delete_model_files(&config, &base_dir);
-                    let _ = app.emit(
-                        "download-progress",
-                        DownloadProgressPayload {
-                            model_id: model_id.to_string(),
-                            total_bytes: 0,
-                            downloaded_bytes: 0,
-                            status: "cancelled".to_string(),
-                            current_file: (i + 1) as u32,
-                            total_files: files_count as u32,
-                        },
-                    );
-                    return Err("Download cancelled by user".to_string());
-                }
+        },
+    ))
+    .buffer_unordered(MAX_PARALLEL_FILES);
+
+    let mut hashes = vec![String::new(); files_count];
+    let mut failed = None;
+    while let Some(result) = downloads.next().await {
+        match result {
+            Ok((i, hash)) => hashes[i] = hash,
+            Err(failure) => {
+                failed = Some(failure);
+                break;
             }
-
-            std::fs::remove_file(&download_path).ok();
-            println!("[DOWNLOAD] Extraction complete.");
         }
     }
+    // Dropping the stream aborts the downloads still in flight and closes their files.
+    drop(downloads);
+    if let Some((i, failure)) = failed {
+        return Err(match failure {
+            FileFailure::Cancelled => emit_cancelled_and_cleanup(
+                app,
+                model_id,
+                &config,
+                &base_dir,
+                (i + 1) as u32,
+                files_count as u32,
+            ),
+            FileFailure::Error(message) => emit_download_error_and_cleanup(
+                app,
+                model_id,
+                &config,
+                &base_dir,
+                (i + 1) as u32,
+                files_count as u32,
+                &message,
+            ),
+        });
+    }
 
     println!("[DOWNLOAD] Finished downloading {}", model_id);
 
-    // ── Auto-verify phase ─────────────────────────────────────────────────────
+    // ── Record verification ───────────────────────────────────────────────────
+    // Every file's hash was checked as it streamed in (see `finish_file`).
     let expected_fp = registry_fingerprint(&config.files);
 
     // Only skip verification entirely for non-HuggingFace repos with no hashes.
@@ -635,170 +1160,15 @@ async fn download_model_inner(
                 status: "done".to_string(),
                 current_file: files_count as u32,
                 total_files: files_count as u32,
+                bytes_per_sec: 0,
+                eta_secs: 0,
             },
         );
         return Ok(format!("Downloaded to {:?}", base_dir));
     }
 
-    // Pre-calculate total bytes for progress reporting (all non-directory files).
-    let mut total_verify_bytes: u64 = 0;
-    for file_spec in &config.files {
-        let file_path = base_dir.join(file_spec.filename);
-        if file_path.is_dir() {
-            continue;
-        }
-        if let Ok(meta) = std::fs::metadata(&file_path) {
-            total_verify_bytes += meta.len();
-        }
-    }
-
-    // Hash each file and build the actual fingerprint.
-    let mut computed_fp_parts: Vec<String> = Vec::new();
-    let mut verified_bytes: u64 = 0;
-    let emit_threshold: u64 = 512 * 1024; // emit every 512 KiB
-
-    for (i, file_spec) in config.files.iter().enumerate() {
-        // For HuggingFace repos, fetch the current expected hash from the LFS pointer.
-        // Fall back to the registry sha1 if the fetch fails (e.g. brief network blip).
-        let expected_hash: String = if is_hf_repo {
-            match fetch_hf_lfs_sha256(&client, config.repo, config.branch, file_spec.remote_path)
-                .await
-            {
-                Some(h) => h,
-                None => file_spec.sha1.to_string(),
-            }
-        } else {
-            file_spec.sha1.to_string()
-        };
-
-        if expected_hash.is_empty() {
-            computed_fp_parts.push(String::new());
-            continue;
-        }
-
-        let file_path = base_dir.join(file_spec.filename);
-
-        println!(
-            "[VERIFY] SHA256 for {} ({}/{})...",
-            file_spec.filename,
-            i + 1,
-            files_count
-        );
-
-        // Emit initial progress for this file.
-        let _ = app.emit(
-            "download-progress",
-            DownloadProgressPayload {
-                model_id: model_id.to_string(),
-                total_bytes: total_verify_bytes,
-                downloaded_bytes: verified_bytes,
-                status: "verifying".to_string(),
-                current_file: (i + 1) as u32,
-                total_files: files_count as u32,
-            },
-        );
-
-        // Directories (e.g. .mlmodelc CoreML bundles) can't be file-hashed.
-        // Trust the extraction; push the expected hash so the fingerprint matches.
-        if file_path.is_dir() {
-            computed_fp_parts.push(expected_hash);
-            continue;
-        }
-
-        let mut file = File::open(&file_path).map_err(|e| {
-            emit_download_error_and_cleanup(
-                app,
-                model_id,
-                &config,
-                &base_dir,
-                (i + 1) as u32,
-                files_count as u32,
-                &format!(
-                    "Download failed — could not read file for verification ({})",
-                    e
-                ),
-            )
-        })?;
-        use sha2::{Digest, Sha256};
-        let mut hasher = Sha256::new();
-        let mut buffer = [0u8; 65536]; // 64 KiB chunks for speed
-        let mut last_emit: u64 = verified_bytes;
-
-        loop {
-            let count = file.read(&mut buffer).map_err(|e| {
-                emit_download_error_and_cleanup(
-                    app,
-                    model_id,
-                    &config,
-                    &base_dir,
-                    (i + 1) as u32,
-                    files_count as u32,
-                    &format!("Download failed — verification read error ({})", e),
-                )
-            })?;
-            if count == 0 {
-                break;
-            }
-            hasher.update(&buffer[..count]);
-            verified_bytes += count as u64;
-
-            if verified_bytes - last_emit > emit_threshold {
-                last_emit = verified_bytes;
-                let _ = app.emit(
-                    "download-progress",
-                    DownloadProgressPayload {
-                        model_id: model_id.to_string(),
-                        total_bytes: total_verify_bytes,
-                        downloaded_bytes: verified_bytes,
-                        status: "verifying".to_string(),
-                        current_file: (i + 1) as u32,
-                        total_files: files_count as u32,
-                    },
-                );
-            }
-        }
-
-        // Final emit for this file at 100% of bytes processed so far.
-        let _ = app.emit(
-            "download-progress",
-            DownloadProgressPayload {
-                model_id: model_id.to_string(),
-                total_bytes: total_verify_bytes,
-                downloaded_bytes: verified_bytes,
-                status: "verifying".to_string(),
-                current_file: (i + 1) as u32,
-                total_files: files_count as u32,
-            },
-        );
-
-        let hash_hex = hex::encode(hasher.finalize());
-        println!(
-            "[VERIFY] {} — Expected: {}, Got: {}",
-            file_spec.filename, expected_hash, hash_hex
-        );
-
-        if hash_hex != expected_hash {
-            eprintln!("[VERIFY] Hash mismatch! Deleting corrupted files.");
-            let msg = format!(
-                "Download failed — file may be corrupted ({}). Try again.",
-                file_spec.filename
-            );
-            return Err(emit_download_error_and_cleanup(
-                app,
-                model_id,
-                &config,
-                &base_dir,
-                (i + 1) as u32,
-                files_count as u32,
-                &msg,
-            ));
-        }
-
-        computed_fp_parts.push(hash_hex);
-    }
-
     // All hashes matched — write to verified.json.
-    let computed_fp = computed_fp_parts.join("+");
+    let computed_fp = hashes.join("+");
     let now = chrono::Utc::now().to_rfc3339();
 
     let mut store = load_verified_store();
@@ -822,6 +1192,8 @@ async fn download_model_inner(
             status: "done".to_string(),
             current_file: files_count as u32,
             total_files: files_count as u32,
+            bytes_per_sec: 0,
+            eta_secs: 0,
         },
     );
 
@@ -940,3 +1312,23 @@ pub async fn delete_model(
 
     Ok(CommandResult::ok(format!("Deleted model {}", model_id)))
 }
+
+/// Forget that a model was verified (its verified.json entry) without touching the
+/// files, so the model shows as unverified until its hashes are checked again.
+/// Returns false when the model had no verification record.
+#[tauri::command]
+pub async fn clear_verification(model_id: String) -> Result<CommandResult<bool>, String> {
+    if get_model_config(&model_id).is_none() {
+        return Ok(CommandResult::err(
+            "model_missing",
+            format!("Unknown model ID: {}", model_id),
+        ));
+    }
+    let mut store = load_verified_store();
+    let removed = store.remove(&model_id).is_some();
+    if removed {
+        save_verified_store(&store);
+        println!("[VERIFY] Cleared verification record for {}", model_id);
+    }
+    Ok(CommandResult::ok(removed))
+}
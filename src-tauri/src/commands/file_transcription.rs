@@ -8,10 +8,10 @@
 
 use crate::audio_preprocess;
 use crate::state::AudioState;
-use crate::types::ASREngine;
+use crate::types::{ASREngine, CommandResult, Segment};
 use crate::utils::clean_transcript;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
 use tauri::{AppHandle, Emitter, State};
@@ -31,6 +31,41 @@ pub struct FileTranscriptionResult {
     pub audio_duration_ms: i64,
     /// Wall-clock time taken to transcribe in milliseconds.
     pub processing_time_ms: i64,
+    /// Where `processing_time_ms` went, stage by stage.
+    pub timing: TimingBreakdown,
+}
+
+/// Per-stage wall-clock time (ms) of one file transcription.
+#[derive(Serialize, Default, Clone, Copy, Debug)]
+pub struct TimingBreakdown {
+    /// Reading and decoding the file to PCM.
+    pub decode_ms: u64,
+    /// Channel downmix to mono.
+    pub downmix_ms: u64,
+    /// Resampling to 16 kHz (0 when the file is already 16 kHz).
+    pub resample_ms: u64,
+    /// Edge trim, energy VAD and the speech preprocessing chain.
+    pub vad_ms: u64,
+    /// ASR inference across all chunks, including waiting for the engine lock.
+    pub inference_ms: u64,
+    /// Transcript clean-up.
+    pub postprocess_ms: u64,
+}
+
+/// Milliseconds since `since`, then restart the stopwatch.
+fn lap(since: &mut std::time::Instant) -> u64 {
+    let ms = since.elapsed().as_millis() as u64;
+    *since = std::time::Instant::now();
+    ms
+}
+
+/// One VAD segment of an n-best transcription.
+#[derive(Serialize)]
+pub struct NBestSegment {
+    pub start_secs: f32,
+    pub end_secs: f32,
+    /// Distinct candidate transcripts, best first.
+    pub alternatives: Vec<String>,
 }
 
 // ── Cancellation (same pattern as model downloads) ───────────────────────────
@@ -41,7 +76,7 @@ fn cancel_flags() -> &'static Mutex<HashMap<String, Arc<AtomicBool>>> {
     FILE_TRANSCRIBE_CANCEL.get_or_init(|| Mutex::new(HashMap::new()))
 }
 
-fn register_cancel_flag(path: &str) -> Arc<AtomicBool> {
+pub(super) fn register_cancel_flag(path: &str) -> Arc<AtomicBool> {
     let flag = Arc::new(AtomicBool::new(false));
     cancel_flags()
         .lock()
@@ -50,7 +85,7 @@ fn register_cancel_flag(path: &str) -> Arc<AtomicBool> {
     flag
 }
 
-fn unregister_cancel_flag(path: &str) {
+pub(super) fn unregister_cancel_flag(path: &str) {
     cancel_flags().lock().unwrap().remove(path);
 }
 
@@ -100,6 +135,199 @@ pub async fn transcribe_file(
         .and_then(|r| r)
 }
 
+/// Outcome of one file in a [`transcribe_files`] batch.
+#[derive(Serialize)]
+pub struct BatchFileResult {
+    pub path: String,
+    pub result: Option<FileTranscriptionResult>,
+    pub error: Option<String>,
+}
+
+/// Upper bound on parallel batch workers; each Whisper worker holds its own model copy.
+const MAX_BATCH_WORKERS: usize = 4;
+
+/// Transcribe several files using a small pool of worker threads.
+///
+/// With Whisper, worker 0 reuses the loaded model and every extra worker loads its own
+/// `WhisperContext` for the same model, so files genuinely run in parallel at the cost of
+/// one more model in RAM/VRAM per worker. Parakeet and Cohere sessions are shared, so
+/// those engines always run one file at a time. Progress is reported per file through the
+/// usual `file-transcription-progress` event, and each path can be cancelled on its own.
+#[tauri::command]
+pub async fn transcribe_files(
+    app: AppHandle,
+    state: State<'_, AudioState>,
+    paths: Vec<String>,
+    workers: Option<usize>,
+) -> Result<CommandResult<Vec<BatchFileResult>>, String> {
+    let active_engine = *state.active_engine.lock().unwrap();
+    let mut workers = workers
+        .unwrap_or(1)
+        .clamp(1, MAX_BATCH_WORKERS)
+        .min(paths.len().max(1));
+    if active_engine != ASREngine::Whisper && workers > 1 {
+        println!(
+            "[BATCH] {:?} does not support parallel workers; running sequentially",
+            active_engine
+        );
+        workers = 1;
+    }
+
+    let status = state.whisper_status();
+    if active_engine == ASREngine::Whisper && status.model.is_none() {
+        return Ok(CommandResult::err(
+            "model_missing",
+            "No Whisper model loaded",
+        ));
+    }
+    let force_cpu = status.backend == "CPU";
+
+    let whisper = state.whisper.clone();
+    let parakeet = state.parakeet.clone();
+    let cohere = state.cohere.clone();
+    println!(
+        "[BATCH] Transcribing {} file(s) with {} worker(s)",
+        paths.len(),
+        workers
+    );
+
+    let results = tauri::async_runtime::spawn_blocking(move || {
+        let queue = Mutex::new(paths.into_iter().enumerate().collect::<VecDeque<_>>());
+        let results = Mutex::new(Vec::new());
+
+        std::thread::scope(|scope| {
+            for worker in 0..workers {
+                let (app, queue, results) = (&app, &queue, &results);
+                let (parakeet, cohere) = (parakeet.clone(), cohere.clone());
+                let shared_whisper = whisper.clone();
+                let model = status.model.clone();
+                scope.spawn(move || {
+                    let whisper = if worker == 0 {
+                        shared_whisper
+                    } else {
+                        let mut extra = crate::whisper::WhisperManager::new();
+                        if let Err(e) = extra.initialize(model.as_deref(), force_cpu) {
+                            // The remaining workers keep draining the queue.
+                            eprintln!("[BATCH] Worker {} could not load model: {}", worker, e);
+                            return;
+                        }
+                        Arc::new(Mutex::new(extra))
+                    };
+
+                    loop {
+                        let Some((index, path)) = queue.lock().unwrap().pop_front() else {
+                            break;
+                        };
+                        let cancel = register_cancel_flag(&path);
+                        let outcome = transcribe_file_blocking(
+                            app,
+                            &path,
+                            active_engine,
+                            whisper.clone(),
+                            parakeet.clone(),
+                            cohere.clone(),
+                            cancel,
+                        );
+                        unregister_cancel_flag(&path);
+
+                        let (result, error) = match outcome {
+                            Ok(r) => (Some(r), None),
+                            Err(e) => (None, Some(e)),
+                        };
+                        results.lock().unwrap().push((
+                            index,
+                            BatchFileResult {
+                                path,
+                                result,
+                                error,
+                            },
+                        ));
+                    }
+                });
+            }
+        });
+
+        let mut results = results.into_inner().unwrap();
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, r)| r).collect::<Vec<_>>()
+    })
+    .await
+    .map_err(|e| format!("transcribe_files task failed: {}", e))?;
+
+    Ok(CommandResult::ok(results))
+}
+
+/// Re-run transcription on the most recent recording, optionally switching engine/model
+/// first (e.g. retry a tiny.en result with base.en without repeating yourself).
+/// Only kept recordings (`set_keep_recordings`) are still on disk by then.
+#[tauri::command]
+pub async fn retranscribe_last(
+    app: AppHandle,
+    state: State<'_, AudioState>,
+    engine: Option<ASREngine>,
+    model_id: Option<String>,
+) -> Result<CommandResult<FileTranscriptionResult>, String> {
+    if state.recording_handle.lock().unwrap().is_some() {
+        return Ok(CommandResult::err(
+            "already_recording",
+            "Stop the current recording before re-transcribing",
+        ));
+    }
+
+    let path = state.last_recording_path.lock().unwrap().clone();
+    let Some(path) = path.filter(|p| std::path::Path::new(p).exists()) else {
+        return Ok(CommandResult::err(
+            "recording_missing",
+            "No kept recording to re-transcribe (recordings are deleted after transcription unless kept)",
+        ));
+    };
+
+    let engine = engine.unwrap_or_else(|| *state.active_engine.lock().unwrap());
+    if let Some(model_id) = model_id {
+        let loaded = match engine {
+            ASREngine::Whisper => {
+                super::switch_model(state.clone(), app.clone(), model_id, None).await?
+            }
+            ASREngine::Parakeet => {
+                super::init_parakeet(state.clone(), app.clone(), Some(model_id), None).await?
+            }
+            ASREngine::Cohere => {
+                super::init_cohere(state.clone(), app.clone(), Some(model_id), None).await?
+            }
+        };
+        if let Some(error) = loaded.error {
+            return Ok(CommandResult::err(error.code, error.message));
+        }
+    }
+
+    println!("[PROCESSING] Re-transcribing {} with {:?}", path, engine);
+    let cancel = register_cancel_flag(&path);
+    let whisper = state.whisper.clone();
+    let parakeet = state.parakeet.clone();
+    let cohere = state.cohere.clone();
+    let path_for_task = path.clone();
+
+    let join_result = tauri::async_runtime::spawn_blocking(move || {
+        transcribe_file_blocking(
+            &app,
+            &path_for_task,
+            engine,
+            whisper,
+            parakeet,
+            cohere,
+            cancel,
+        )
+    })
+    .await;
+
+    unregister_cancel_flag(&path);
+
+    match join_result.map_err(|e| format!("retranscribe_last task failed: {}", e))? {
+        Ok(result) => Ok(CommandResult::ok(result)),
+        Err(e) => Ok(CommandResult::err("transcription_failed", e)),
+    }
+}
+
 fn emit_progress(app: &AppHandle, path: &str, percent: u8, status: &str, error: Option<String>) {
     let _ = app.emit(
         "file-transcription-progress",
@@ -131,7 +359,7 @@ fn ensure_not_cancelled(
     }
 }
 
-fn transcribe_file_blocking(
+pub(super) fn transcribe_file_blocking(
     app: &AppHandle,
     path: &str,
     active_engine: ASREngine,
@@ -159,29 +387,27 @@ fn transcribe_file_blocking(
 
     emit_progress(app, path, 5, "decoding", None);
 
+    let mut timing = TimingBreakdown::default();
+    let mut stage = std::time::Instant::now();
+
     // Decode audio file to raw f32 samples
     let (raw_samples, sample_rate, channels) =
         crate::audio_decode::decode_audio_interleaved_f32(std::path::Path::new(path))?;
+    timing.decode_ms = lap(&mut stage);
 
     ensure_not_cancelled(app, path, &cancel)?;
 
     emit_progress(app, path, 20, "decoding", None);
 
     // Merge to mono
-    let mut mono = if channels > 1 {
-        let ch = channels as usize;
-        raw_samples
-            .chunks(ch)
-            .map(|frame| frame.iter().sum::<f32>() / ch as f32)
-            .collect::<Vec<f32>>()
-    } else {
-        raw_samples
-    };
+    let mut mono = audio_preprocess::downmix_to_mono(&raw_samples, channels as usize);
+    timing.downmix_ms = lap(&mut stage);
 
     // Resample to 16 kHz (all engines require this)
     if sample_rate != 16000 {
         mono = audio_preprocess::resample_mono_to_16k(&mono, sample_rate)?;
     }
+    timing.resample_ms = lap(&mut stage);
 
     // Trim long edge silence before energy VAD.
     audio_preprocess::trim_file_buffer_edges_16k(&mut mono);
@@ -211,6 +437,7 @@ fn transcribe_file_blocking(
 
     // Universal chain on speech-only buffer (HPF / RNNoise if noisy / level assist / clamp).
     audio_preprocess::preprocess_assembled_speech_16k(&mut speech_audio);
+    timing.vad_ms = lap(&mut stage);
 
     if speech_audio.is_empty() {
         println!(
@@ -222,6 +449,7 @@ fn transcribe_file_blocking(
             transcript: String::new(),
             audio_duration_ms,
             processing_time_ms: transcribe_start.elapsed().as_millis() as i64,
+            timing,
         });
     }
 
@@ -334,8 +562,12 @@ fn transcribe_file_blocking(
         }
     };
 
+    timing.inference_ms = lap(&mut stage);
+
     let final_text = clean_transcript(&text);
+    timing.postprocess_ms = lap(&mut stage);
     let processing_time_ms = transcribe_start.elapsed().as_millis() as i64;
+    println!("[FILE_TRANSCRIBE] Timing: {:?}", timing);
 
     emit_progress(app, path, 100, "done", None);
 
@@ -343,5 +575,397 @@ fn transcribe_file_blocking(
         transcript: final_text,
         audio_duration_ms,
         processing_time_ms,
+        timing,
+    })
+}
+
+/// Transcribe an audio file with Whisper and return up to `n` (default 3) candidate
+/// transcripts for each speech segment, so a mis-heard word can be picked from the
+/// runner-up hypotheses instead of re-recording.
+#[tauri::command]
+pub async fn transcribe_nbest(
+    state: State<'_, AudioState>,
+    path: String,
+    n: Option<usize>,
+) -> Result<CommandResult<Vec<NBestSegment>>, String> {
+    let n = n.unwrap_or(3).clamp(1, 10);
+    if *state.active_engine.lock().unwrap() != ASREngine::Whisper {
+        return Ok(CommandResult::err(
+            "unsupported_engine",
+            "Alternatives are only available with Whisper",
+        ));
+    }
+    if state.whisper.lock().unwrap().get_current_model().is_none() {
+        return Ok(CommandResult::err(
+            "model_missing",
+            "No Whisper model loaded",
+        ));
+    }
+
+    let whisper_arc = state.whisper.clone();
+    let vad_arc = state.vad.clone();
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let mono = decode_mono_16k(&path)?;
+
+        let mut vad = vad_arc.lock().unwrap();
+        let (onset, offset) = vad.hysteresis(false);
+        let mut timestamps = vad.get_speech_timestamps_hysteresis(&mono, 500, onset, offset)?;
+        drop(vad);
+        if timestamps.is_empty() {
+            timestamps.push((0.0, mono.len() as f32 / 16000.0));
+        }
+
+        let mut segments = Vec::with_capacity(timestamps.len());
+        for (start, end) in timestamps {
+            let s = ((start * 16000.0) as usize).min(mono.len());
+            let e = ((end * 16000.0) as usize).min(mono.len());
+            let mut audio = mono[s..e].to_vec();
+            // Whisper needs ~1 s of input; pad short segments with silence
+            if audio.len() < 16000 {
+                audio.resize(16000, 0.0);
+            }
+            let alternatives = whisper_arc
+                .lock()
+                .unwrap()
+                .transcribe_alternatives(&audio, n, None)?;
+            segments.push(NBestSegment {
+                start_secs: start,
+                end_secs: end,
+                alternatives,
+            });
+        }
+        Ok::<_, String>(segments)
+    })
+    .await
+    .map_err(|e| format!("transcribe_nbest task failed: {}", e))
+    .map(|result| match result {
+        Ok(segments) => CommandResult::ok(segments),
+        Err(message) => CommandResult::err("transcription_failed", message),
+    })
+}
+
+/// Transcribe an audio file and return its segments with start/end times, e.g. for
+/// subtitle export. The whole file is decoded without VAD so the times line up with
+/// the original audio. With Parakeet active, CTC models give one segment per word,
+/// TDT one per sentence, and the streaming Nemotron/EOU models none.
+#[tauri::command]
+pub async fn transcribe_file_timestamps(
+    state: State<'_, AudioState>,
+    path: String,
+) -> Result<CommandResult<Vec<Segment>>, String> {
+    if *state.active_engine.lock().unwrap() == ASREngine::Parakeet {
+        if !state.parakeet_status().loaded {
+            return Ok(CommandResult::err(
+                "model_missing",
+                "No Parakeet model loaded",
+            ));
+        }
+        let parakeet = state.parakeet.clone();
+        return tauri::async_runtime::spawn_blocking(move || {
+            let mono = decode_mono_16k(&path)?;
+            let spans = parakeet
+                .lock()
+                .map_err(|_| "Parakeet lock poisoned".to_string())?
+                .transcribe_with_timestamps(&mono)?;
+            Ok(spans
+                .into_iter()
+                .map(|(start_secs, end_secs, text)| Segment {
+                    start_secs,
+                    end_secs,
+                    text,
+                })
+                .collect())
+        })
+        .await
+        .map_err(|e| format!("transcribe_file_timestamps task failed: {}", e))
+        .map(|result: Result<Vec<Segment>, String>| match result {
+            Ok(segments) => CommandResult::ok(segments),
+            Err(message) => CommandResult::err("transcription_failed", message),
+        });
+    }
+    if state.whisper.lock().unwrap().get_current_model().is_none() {
+        return Ok(CommandResult::err(
+            "model_missing",
+            "No Whisper model loaded",
+        ));
+    }
+    let whisper = state.whisper.clone();
+
+    tauri::async_runtime::spawn_blocking(move || transcribe_segments_blocking(&whisper, &path))
+        .await
+        .map_err(|e| format!("transcribe_file_timestamps task failed: {}", e))
+        .map(|result| match result {
+            Ok(segments) => CommandResult::ok(segments),
+            Err(message) => CommandResult::err("transcription_failed", message),
+        })
+}
+
+/// Transcribe the most recent recording with timestamps and write it to `path` as
+/// subtitles. `format` is "srt" or "vtt"; returns the number of cues written.
+#[tauri::command]
+pub async fn export_subtitles(
+    state: State<'_, AudioState>,
+    path: String,
+    format: String,
+) -> Result<CommandResult<usize>, String> {
+    let format = format.to_lowercase();
+    if format != "srt" && format != "vtt" {
+        return Ok(CommandResult::err(
+            "invalid_format",
+            format!("Unsupported subtitle format: {}", format),
+        ));
+    }
+    let recording = state.last_recording_path.lock().unwrap().clone();
+    let Some(recording) = recording.filter(|p| std::path::Path::new(p).exists()) else {
+        return Ok(CommandResult::err(
+            "recording_missing",
+            "No kept recording to export subtitles from (recordings are deleted after transcription unless kept)",
+        ));
+    };
+    if state.whisper.lock().unwrap().get_current_model().is_none() {
+        return Ok(CommandResult::err(
+            "model_missing",
+            "No Whisper model loaded",
+        ));
+    }
+    let whisper = state.whisper.clone();
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let segments = match transcribe_segments_blocking(&whisper, &recording) {
+            Ok(segments) => segments,
+            Err(e) => return CommandResult::err("transcription_failed", e),
+        };
+        let body = if format == "srt" {
+            crate::subtitles::to_srt(&segments)
+        } else {
+            crate::subtitles::to_vtt(&segments)
+        };
+        let cues = body.matches(" --> ").count();
+        match std::fs::write(&path, body) {
+            Ok(()) => {
+                println!("[INFO] Wrote {} subtitle cues to {}", cues, path);
+                CommandResult::ok(cues)
+            }
+            Err(e) => {
+                CommandResult::err("write_failed", format!("Failed to write {}: {}", path, e))
+            }
+        }
     })
+    .await
+    .map_err(|e| format!("export_subtitles task failed: {}", e))
+}
+
+pub(super) fn transcribe_segments_blocking(
+    whisper: &Mutex<crate::whisper::WhisperManager>,
+    path: &str,
+) -> Result<Vec<Segment>, String> {
+    let mut mono = decode_mono_16k(path)?;
+    // Whisper needs ~1 s of input; trailing silence doesn't shift the timestamps
+    if mono.len() < 16000 {
+        mono.resize(16000, 0.0);
+    }
+    let segments = whisper
+        .lock()
+        .map_err(|_| "Whisper lock poisoned".to_string())?
+        .transcribe_with_timestamps(&mono)?;
+    Ok(segments
+        .into_iter()
+        .map(|(start_secs, end_secs, text)| Segment {
+            start_secs,
+            end_secs,
+            text,
+        })
+        .collect())
+}
+
+/// Decode any supported file to 16 kHz mono.
+fn decode_mono_16k(path: &str) -> Result<Vec<f32>, String> {
+    let (raw_samples, sample_rate, channels) =
+        crate::audio_decode::decode_audio_interleaved_f32(std::path::Path::new(path))?;
+    let mono = audio_preprocess::downmix_to_mono(&raw_samples, channels as usize);
+    if sample_rate != 16000 {
+        return audio_preprocess::resample_mono_to_16k(&mono, sample_rate);
+    }
+    Ok(mono)
+}
+
+/// Return `buckets` (default 200) peak amplitudes (0.0–1.0) for a WAV file, for
+/// drawing a waveform scrubber next to the transcript. Each value is the
+/// largest absolute sample across all channels in its slice of the file.
+#[tauri::command]
+pub async fn get_waveform(
+    path: String,
+    buckets: Option<usize>,
+) -> Result<CommandResult<Vec<f32>>, String> {
+    let buckets = buckets.unwrap_or(200).clamp(1, 10_000);
+    tauri::async_runtime::spawn_blocking(move || waveform_peaks(&path, buckets))
+        .await
+        .map_err(|e| format!("get_waveform task failed: {}", e))
+        .map(|result| match result {
+            Ok(peaks) => CommandResult::ok(peaks),
+            Err(message) => CommandResult::err("read_failed", message),
+        })
+}
+
+fn waveform_peaks(path: &str, buckets: usize) -> Result<Vec<f32>, String> {
+    let mut reader =
+        hound::WavReader::open(path).map_err(|e| format!("Failed to open WAV file: {}", e))?;
+    let spec = reader.spec();
+    let channels = spec.channels.max(1) as usize;
+    let frames = reader.len() as usize / channels;
+    if frames == 0 {
+        return Ok(vec![0.0; buckets]);
+    }
+
+    let mut peaks = vec![0.0_f32; buckets];
+    for (i, sample) in wav_samples(&mut reader).enumerate() {
+        let bucket = (i / channels) * buckets / frames;
+        let peak = &mut peaks[bucket.min(buckets - 1)];
+        *peak = peak.max(sample.abs().min(1.0));
+    }
+    Ok(peaks)
+}
+
+type FileWavReader = hound::WavReader<std::io::BufReader<std::fs::File>>;
+
+/// Interleaved samples as f32 in -1.0..=1.0, whatever the WAV's sample format.
+fn wav_samples(reader: &mut FileWavReader) -> Box<dyn Iterator<Item = f32> + '_> {
+    let spec = reader.spec();
+    match spec.sample_format {
+        hound::SampleFormat::Float => Box::new(reader.samples::<f32>().map(|s| s.unwrap_or(0.0))),
+        hound::SampleFormat::Int => {
+            let scale = (1_i64 << (spec.bits_per_sample.max(1) - 1)) as f32;
+            Box::new(
+                reader
+                    .samples::<i32>()
+                    .map(move |s| s.unwrap_or(0) as f32 / scale),
+            )
+        }
+    }
+}
+
+/// Output formats for `export_recording`. Both are 16 kHz mono — plenty for speech.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// 32 kbps MP3 (~240 KB per minute).
+    Mp3,
+    /// 16-bit PCM WAV (~1.9 MB per minute), lossless at speech bandwidth.
+    Wav,
+}
+
+/// Clip a saved recording to `start_secs`..`end_secs` (defaults: whole file) and
+/// re-encode it as compact 16 kHz mono next to the original. Returns the new path.
+#[tauri::command]
+pub async fn export_recording(
+    path: String,
+    format: ExportFormat,
+    start_secs: Option<f32>,
+    end_secs: Option<f32>,
+) -> Result<CommandResult<String>, String> {
+    tauri::async_runtime::spawn_blocking(move || export_clip(&path, format, start_secs, end_secs))
+        .await
+        .map_err(|e| format!("export_recording task failed: {}", e))
+        .map(|result| match result {
+            Ok(out) => CommandResult::ok(out),
+            Err(message) => CommandResult::err("export_failed", message),
+        })
+}
+
+fn export_clip(
+    path: &str,
+    format: ExportFormat,
+    start_secs: Option<f32>,
+    end_secs: Option<f32>,
+) -> Result<String, String> {
+    let mut reader =
+        hound::WavReader::open(path).map_err(|e| format!("Failed to open WAV file: {}", e))?;
+    let spec = reader.spec();
+    let samples: Vec<f32> = wav_samples(&mut reader).collect();
+    let mono = audio_preprocess::downmix_to_mono(&samples, spec.channels as usize);
+
+    let rate = spec.sample_rate as f32;
+    let total_secs = mono.len() as f32 / rate;
+    let start = start_secs.unwrap_or(0.0).max(0.0);
+    let end = end_secs.unwrap_or(total_secs).min(total_secs);
+    if end <= start {
+        return Err(format!(
+            "Invalid clip range {:.1}s–{:.1}s (recording is {:.1}s)",
+            start, end, total_secs
+        ));
+    }
+    let clip = &mono[(start * rate) as usize..((end * rate) as usize).min(mono.len())];
+    let clip_16k = audio_preprocess::resample_mono_to_16k(clip, spec.sample_rate)?;
+
+    let source = std::path::Path::new(path);
+    let stem = source
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "recording".to_string());
+    let ext = match format {
+        ExportFormat::Mp3 => "mp3",
+        ExportFormat::Wav => "wav",
+    };
+    let out = source.with_file_name(format!("{}_{:.0}-{:.0}s.{}", stem, start, end, ext));
+
+    match format {
+        ExportFormat::Mp3 => {
+            std::fs::write(&out, encode_mp3_16k(&clip_16k)?)
+                .map_err(|e| format!("Failed to write {}: {}", out.display(), e))?;
+        }
+        ExportFormat::Wav => {
+            let spec = hound::WavSpec {
+                channels: 1,
+                sample_rate: 16000,
+                bits_per_sample: 16,
+                sample_format: hound::SampleFormat::Int,
+            };
+            let mut writer = hound::WavWriter::create(&out, spec).map_err(|e| e.to_string())?;
+            for &s in &clip_16k {
+                writer
+                    .write_sample((s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+                    .map_err(|e| e.to_string())?;
+            }
+            writer.finalize().map_err(|e| e.to_string())?;
+        }
+    }
+
+    println!(
+        "[INFO] Exported {:.1}s–{:.1}s of {} to {}",
+        start,
+        end,
+        path,
+        out.display()
+    );
+    Ok(out.to_string_lossy().into_owned())
+}
+
+fn encode_mp3_16k(samples: &[f32]) -> Result<Vec<u8>, String> {
+    use mp3lame_encoder::{Bitrate, Builder, FlushNoGap, MonoPcm, Quality};
+
+    let setup_err = |e: mp3lame_encoder::BuildError| format!("MP3 encoder setup failed: {:?}", e);
+    let mut builder = Builder::new().ok_or("Failed to create MP3 encoder")?;
+    builder.set_num_channels(1).map_err(setup_err)?;
+    builder.set_sample_rate(16000).map_err(setup_err)?;
+    builder.set_brate(Bitrate::Kbps32).map_err(setup_err)?;
+    builder.set_quality(Quality::Good).map_err(setup_err)?;
+    let mut encoder = builder.build().map_err(setup_err)?;
+
+    let pcm: Vec<i16> = samples
+        .iter()
+        .map(|&s| (s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+        .collect();
+    let mut mp3 = Vec::with_capacity(mp3lame_encoder::max_required_buffer_size(pcm.len()));
+    let written = encoder
+        .encode(MonoPcm(&pcm), mp3.spare_capacity_mut())
+        .map_err(|e| format!("MP3 encoding failed: {:?}", e))?;
+    // SAFETY: the encoder initialised `written` bytes of spare capacity.
+    unsafe { mp3.set_len(written) };
+    let flushed = encoder
+        .flush::<FlushNoGap>(mp3.spare_capacity_mut())
+        .map_err(|e| format!("MP3 encoding failed: {:?}", e))?;
+    // SAFETY: as above, for the flushed tail.
+    unsafe { mp3.set_len(mp3.len() + flushed) };
+    Ok(mp3)
 }
@@ -1,8 +1,28 @@
-use crate::llm::{get_grammar_llm_dir, LLMEngine};
+use crate::llm::{get_grammar_llm_dir, LLMEngine, DEFAULT_N_CTX, GGUF_FILENAME};
 use crate::state::AudioState;
-use tauri::State;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::{AppHandle, Emitter, State};
 
-const GGUF_FILENAME: &str = "model_q4_k_m.gguf";
+/// Monotonic id linking a `correct_text_deferred` call to its "correction-ready" event.
+static NEXT_CORRECTION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Immediate result of `correct_text_deferred`.
+#[derive(serde::Serialize)]
+pub struct DeferredCorrection {
+    /// Rule-based cleanup of the input, ready to insert now.
+    pub text: String,
+    /// Id of the background LLM pass, or None when the LLM isn't loaded.
+    pub correction_id: Option<u64>,
+}
+
+/// Payload for the "correction-ready" event.
+#[derive(Clone, serde::Serialize)]
+pub struct CorrectionReady {
+    pub correction_id: u64,
+    /// The cleaned text that was returned (and probably inserted) earlier.
+    pub original: String,
+    pub corrected: String,
+}
 
 /// Returns true if the grammar LLM model file exists and can be loaded.
 #[tauri::command]
@@ -13,22 +33,50 @@ pub fn check_grammar_llm_available() -> bool {
     }
 }
 
+/// Load the grammar LLM. `n_ctx` (context window in tokens) defaults to `DEFAULT_N_CTX`;
+/// `n_threads` defaults to llama.cpp's choice.
 #[tauri::command]
-pub async fn init_llm(state: State<'_, AudioState>, use_gpu: bool) -> Result<String, String> {
-    println!("[COMMAND] init_llm requested. use_gpu: {}", use_gpu);
+pub async fn init_llm(
+    state: State<'_, AudioState>,
+    use_gpu: bool,
+    n_ctx: Option<u32>,
+    n_threads: Option<u32>,
+) -> Result<String, String> {
+    let n_ctx = n_ctx.unwrap_or(DEFAULT_N_CTX);
+    println!(
+        "[COMMAND] init_llm requested. use_gpu: {}, n_ctx: {}",
+        use_gpu, n_ctx
+    );
 
-    // Check if already loaded
+    // Check if already loaded. A GPU-loaded model is dropped when CPU is requested so
+    // the VRAM goes back to the ASR engine instead of being held until the next unload.
     {
-        let llm_guard = state.llm.lock().unwrap();
-        if llm_guard.is_some() {
-            return Ok("LLM already initialized".to_string());
+        let mut llm_guard = state.llm.lock().unwrap();
+        match llm_guard.as_ref() {
+            Some(engine) if !use_gpu && engine.gpu_layers() > 0 => {
+                println!("[LLM] CPU requested but model is on GPU — reloading on CPU.");
+                *llm_guard = None;
+            }
+            Some(engine) if engine.n_ctx() != n_ctx => {
+                println!(
+                    "[LLM] Context size changed ({} -> {}) — reloading.",
+                    engine.n_ctx(),
+                    n_ctx
+                );
+                *llm_guard = None;
+            }
+            Some(_) => return Ok("LLM already initialized".to_string()),
+            None => {}
         }
     }
 
     // Load in a blocking task since it's heavy
-    let result = tauri::async_runtime::spawn_blocking(move || LLMEngine::new(use_gpu))
-        .await
-        .map_err(|e| format!("JoinError: {}", e))?;
+    let cancel = state.llm_cancel.clone();
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        LLMEngine::new(use_gpu, n_ctx, n_threads, cancel)
+    })
+    .await
+    .map_err(|e| format!("JoinError: {}", e))?;
 
     match result {
         Ok(engine) => {
@@ -77,8 +125,14 @@ pub async fn run_llm_inference(
 
 #[tauri::command]
 pub fn check_llm_status(state: State<'_, AudioState>) -> bool {
-    let llm_guard = state.llm.lock().unwrap();
-    llm_guard.is_some()
+    state.llm_gpu_layers().is_some()
+}
+
+/// Number of LLM layers running on the GPU, or None when the LLM isn't loaded.
+/// 0 confirms the model is CPU-only.
+#[tauri::command]
+pub fn get_llm_gpu_layers(state: State<'_, AudioState>) -> Option<u32> {
+    state.llm_gpu_layers()
 }
 
 /// Grammar correction: fix punctuation and grammar. Uses same prompt as format_transcript.
@@ -104,6 +158,10 @@ pub async fn correct_text(
         if let Some(engine) = llm_guard.as_mut() {
             println!("[LLM] Running grammar correction...");
             match engine.format_transcript(&text, style.as_deref()) {
+                Ok(_) if engine.was_cancelled() => {
+                    println!("[LLM] Correction cancelled; keeping the raw text.");
+                    Ok(text)
+                }
                 Ok(formatted) => {
                     println!("[LLM] Correction finished. Output length: {}", formatted.len());
                     Ok(formatted)
@@ -114,7 +172,10 @@ pub async fn correct_text(
                 }
             }
         } else {
-            Err("LLM not initialized. Place the grammar model (model_q4_k_m.gguf) in taurscribe-runtime/models/qwen_finetuned_gguf.".to_string())
+            Err(format!(
+                "LLM not initialized. Load the grammar model ({}) first; download it from Settings > Download Manager if it's missing.",
+                GGUF_FILENAME
+            ))
         }
     })
     .await
@@ -123,6 +184,112 @@ pub async fn correct_text(
     Ok(output)
 }
 
+/// Grammar correction that emits each generated piece as an "llm-token" event so the UI can
+/// render the corrected text as it is written. Resolves with the full corrected text.
+#[tauri::command]
+pub async fn correct_text_streaming(
+    app: AppHandle,
+    state: State<'_, AudioState>,
+    text: String,
+    style: Option<String>,
+) -> Result<String, String> {
+    let text = text.trim().to_string();
+    if text.is_empty() {
+        return Ok(String::new());
+    }
+    let llm_handle = state.llm.clone();
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut llm_guard = llm_handle.lock().unwrap();
+        let Some(engine) = llm_guard.as_mut() else {
+            return Err(format!(
+                "LLM not initialized. Load the grammar model ({}) first; download it from Settings > Download Manager if it's missing.",
+                GGUF_FILENAME
+            ));
+        };
+        println!("[LLM] Running streaming grammar correction...");
+        match engine.format_transcript_streaming(&text, style.as_deref(), |piece| {
+            let _ = app.emit("llm-token", piece);
+        }) {
+            Ok(_) if engine.was_cancelled() => {
+                println!("[LLM] Streaming correction cancelled; keeping the raw text.");
+                Ok(text)
+            }
+            Ok(formatted) => Ok(formatted),
+            Err(e) => {
+                eprintln!("[LLM] Streaming correction failed: {}", e);
+                Ok(text)
+            }
+        }
+    })
+    .await
+    .map_err(|e| format!("Join Error: {}", e))?
+}
+
+/// Fast path for grammar correction: returns the cleaned-up text right away and runs the
+/// LLM in the background, emitting "correction-ready" when it finishes so the UI can offer
+/// to swap the inserted text for the corrected one.
+#[tauri::command]
+pub fn correct_text_deferred(
+    app: AppHandle,
+    state: State<'_, AudioState>,
+    text: String,
+    style: Option<String>,
+) -> DeferredCorrection {
+    let cleaned = crate::utils::clean_transcript(text.trim());
+    // try_lock: a busy lock means an earlier correction is running, so the LLM is loaded.
+    let llm_loaded = state.llm.try_lock().map_or(true, |llm| llm.is_some());
+    if cleaned.is_empty() || !llm_loaded {
+        return DeferredCorrection {
+            text: cleaned,
+            correction_id: None,
+        };
+    }
+
+    let correction_id = NEXT_CORRECTION_ID.fetch_add(1, Ordering::Relaxed);
+    let llm_handle = state.llm.clone();
+    let original = cleaned.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut llm_guard = llm_handle.lock().unwrap();
+        let Some(engine) = llm_guard.as_mut() else {
+            return;
+        };
+        println!("[LLM] Background correction #{} started", correction_id);
+        match engine.format_transcript(&original, style.as_deref()) {
+            Ok(_) if engine.was_cancelled() => {
+                println!("[LLM] Background correction #{} cancelled", correction_id)
+            }
+            Ok(corrected) => {
+                let _ = app.emit(
+                    "correction-ready",
+                    CorrectionReady {
+                        correction_id,
+                        original,
+                        corrected,
+                    },
+                );
+            }
+            Err(e) => eprintln!(
+                "[LLM] Background correction #{} failed: {}",
+                correction_id, e
+            ),
+        }
+    });
+
+    DeferredCorrection {
+        text: cleaned,
+        correction_id: Some(correction_id),
+    }
+}
+
+/// Stop the running LLM generation, if any. Corrections that are cut short return the
+/// raw input text; `run_llm_inference` returns whatever was generated so far.
+#[tauri::command]
+pub fn cancel_llm(state: State<'_, AudioState>) {
+    println!("[LLM] Cancel requested");
+    state.llm_cancel.store(true, Ordering::Relaxed);
+}
+
 #[tauri::command]
 pub fn unload_llm(state: State<'_, AudioState>) -> Result<String, String> {
     let mut llm_guard = state.llm.lock().unwrap();
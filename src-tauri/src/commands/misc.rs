@@ -67,19 +67,125 @@ pub fn request_overlay_action(app: tauri::AppHandle, action: String) -> Result<(
 }
 
 /// Returns the names of all available audio input devices on this machine.
+/// An empty list means no microphone is connected; Err means the audio host
+/// couldn't be enumerated at all.
 ///
 /// macOS fix: Async with spawn_blocking because cpal device enumeration
 /// touches CoreAudio, which can block and freeze the AppKit main thread.
 #[tauri::command]
-pub async fn list_input_devices() -> Vec<String> {
+pub async fn list_input_devices() -> Result<Vec<String>, String> {
     tauri::async_runtime::spawn_blocking(|| {
         let host = cpal::default_host();
         host.input_devices()
             .map(|devices| devices.filter_map(|d| d.name().ok()).collect())
-            .unwrap_or_default()
+            .map_err(|e| format!("Failed to enumerate input devices: {}", e))
     })
     .await
-    .unwrap_or_default()
+    .map_err(|e| format!("Device enumeration task failed: {}", e))?
+}
+
+/// Returns true when at least one input device (or a system default) exists,
+/// so the UI can ask for a microphone before trying to record.
+#[tauri::command]
+pub async fn has_input_device() -> bool {
+    tauri::async_runtime::spawn_blocking(|| {
+        let host = cpal::default_host();
+        host.default_input_device().is_some()
+            || host
+                .input_devices()
+                .map(|mut devices| devices.next().is_some())
+                .unwrap_or(false)
+    })
+    .await
+    .unwrap_or(false)
+}
+
+/// One input configuration range a device supports.
+#[derive(Serialize)]
+pub struct SupportedInputConfig {
+    pub channels: u16,
+    pub min_sample_rate: u32,
+    pub max_sample_rate: u32,
+    /// cpal sample format, e.g. "f32" or "i16".
+    pub sample_format: String,
+}
+
+/// What an input device can record, from `get_device_capabilities`.
+#[derive(Serialize)]
+pub struct DeviceCapabilities {
+    pub name: String,
+    pub configs: Vec<SupportedInputConfig>,
+    /// The config `start_recording` will open (the device default), if it has one.
+    pub default_sample_rate: Option<u32>,
+    pub default_channels: Option<u16>,
+    /// Whether the device can run at 48 kHz at all (what RNNoise needs).
+    pub supports_48khz: bool,
+    /// Whether live denoise will actually work: the default config is 48 kHz.
+    /// False means denoise is skipped for the session instead of producing
+    /// robotic audio.
+    pub denoise_supported: bool,
+}
+
+/// Returns the sample rates, channel counts and sample formats an input device
+/// supports, so the UI can warn before a session runs without denoise.
+#[tauri::command]
+pub async fn get_device_capabilities(name: String) -> Result<DeviceCapabilities, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let host = cpal::default_host();
+        let device = host
+            .input_devices()
+            .map_err(|e| format!("Failed to enumerate input devices: {}", e))?
+            .find(|d| d.name().ok().as_deref() == Some(name.as_str()))
+            .ok_or_else(|| format!("Input device '{}' not found", name))?;
+
+        let configs: Vec<SupportedInputConfig> = device
+            .supported_input_configs()
+            .map_err(|e| format!("Failed to query '{}' configs: {}", name, e))?
+            .map(|c| SupportedInputConfig {
+                channels: c.channels(),
+                min_sample_rate: c.min_sample_rate().0,
+                max_sample_rate: c.max_sample_rate().0,
+                sample_format: c.sample_format().to_string(),
+            })
+            .collect();
+        let default = device.default_input_config().ok();
+        let default_sample_rate = default.as_ref().map(|c| c.sample_rate().0);
+        let supports_48khz = configs
+            .iter()
+            .any(|c| c.min_sample_rate <= 48000 && 48000 <= c.max_sample_rate);
+
+        Ok(DeviceCapabilities {
+            name,
+            configs,
+            default_sample_rate,
+            default_channels: default.as_ref().map(|c| c.channels()),
+            supports_48khz,
+            denoise_supported: default_sample_rate == Some(48000),
+        })
+    })
+    .await
+    .map_err(|e| format!("Device capabilities task failed: {}", e))?
+}
+
+/// Returns the output devices that `start_recording` can capture with `use_loopback`.
+/// Loopback is WASAPI-only, so this is empty on macOS and Linux.
+#[tauri::command]
+pub async fn list_loopback_devices() -> Vec<String> {
+    #[cfg(target_os = "windows")]
+    {
+        tauri::async_runtime::spawn_blocking(|| {
+            let host = cpal::default_host();
+            host.output_devices()
+                .map(|devices| devices.filter_map(|d| d.name().ok()).collect())
+                .unwrap_or_default()
+        })
+        .await
+        .unwrap_or_default()
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        Vec::new()
+    }
 }
 
 /// Returns the name of the microphone that will actually be used for the next recording.
@@ -154,6 +260,18 @@ pub struct SystemInfo {
     pub cuda_available: bool,
     pub vram_gb: Option<f32>,
     pub backend_hint: String,
+    /// What the compiled runtimes can actually use here; `backend_hint` only
+    /// reflects the detected hardware.
+    pub backends: BackendAvailability,
+}
+
+#[derive(Serialize)]
+pub struct BackendAvailability {
+    /// GPU backend compiled into whisper.cpp ("CUDA", "Vulkan", "Metal", …), if any.
+    pub whisper_gpu: Option<String>,
+    /// ONNX Runtime execution providers (Parakeet / Cohere) that report as available.
+    /// "CPU" is always present.
+    pub onnx_providers: Vec<String>,
 }
 
 /// Returns CPU, RAM, and GPU info for the first-launch setup screen.
@@ -167,6 +285,23 @@ pub async fn get_system_info() -> Result<SystemInfo, String> {
         .map_err(|e| format!("get_system_info task failed: {}", e))
 }
 
+/// Run the post-processing chain a recording gets (cleanup, custom dictionary,
+/// spellcheck, snippets) on arbitrary text with the current settings, so corrections can be
+/// previewed without recording audio. The optional grammar LLM is skipped.
+#[tauri::command]
+pub fn preview_cleanup(app: tauri::AppHandle, text: String) -> String {
+    let text = crate::utils::clean_transcript(&text);
+    let text = crate::postprocess::correct(&app, &text);
+    crate::postprocess::expand_snippets(&app, &text)
+}
+
+/// Expand the user's snippets in `text`; the frontend calls this after the
+/// optional grammar LLM step.
+#[tauri::command]
+pub fn expand_snippets(app: tauri::AppHandle, text: String) -> String {
+    crate::postprocess::expand_snippets(&app, &text)
+}
+
 #[tauri::command]
 pub fn get_process_memory_stats() -> crate::memory::ProcessMemoryStats {
     crate::memory::process_memory_stats()
@@ -220,6 +355,33 @@ fn get_system_info_blocking() -> SystemInfo {
         cuda_available,
         vram_gb,
         backend_hint,
+        backends: detect_backend_availability(),
+    }
+}
+
+/// Ask the runtimes themselves which accelerators initialise, so a machine with an
+/// NVIDIA card but no usable ONNX CUDA provider shows up as such.
+fn detect_backend_availability() -> BackendAvailability {
+    use ort::ep::ExecutionProvider;
+
+    let probes = [
+        ("CUDA", ort::ep::CUDA::default().is_available()),
+        ("TensorRT", ort::ep::TensorRT::default().is_available()),
+        ("DirectML", ort::ep::DirectML::default().is_available()),
+        ("CoreML", ort::ep::coreml::CoreML::default().is_available()),
+    ];
+    let mut onnx_providers = vec!["CPU".to_string()];
+    for (name, available) in probes {
+        match available {
+            Ok(true) => onnx_providers.push(name.to_string()),
+            Ok(false) => {}
+            Err(e) => eprintln!("[WARN] Could not query ONNX {} provider: {}", name, e),
+        }
+    }
+
+    BackendAvailability {
+        whisper_gpu: crate::whisper::compiled_gpu_backend().map(|b| b.to_string()),
+        onnx_providers,
     }
 }
 
@@ -1,3 +1,4 @@
+mod benchmark;
 mod cohere;
 mod file_transcription;
 mod history;
@@ -8,6 +9,7 @@ mod models;
 mod recording;
 mod settings;
 
+pub use benchmark::*;
 pub use cohere::*;
 pub use file_transcription::*;
 pub use history::*;
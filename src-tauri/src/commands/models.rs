@@ -4,7 +4,7 @@ use crate::tray;
 use crate::types::{ASREngine, CommandResult};
 use crate::whisper;
 use std::sync::atomic::Ordering;
-use tauri::State;
+use tauri::{Emitter, Manager, State};
 
 /// List all available AI models found in the models folder
 #[tauri::command]
@@ -15,8 +15,7 @@ pub fn list_models() -> Result<Vec<whisper::ModelInfo>, String> {
 /// Ask which model is currently loaded
 #[tauri::command]
 pub fn get_current_model(state: State<AudioState>) -> Result<Option<String>, String> {
-    let whisper = state.whisper.lock().unwrap();
-    Ok(whisper.get_current_model().cloned())
+    Ok(state.whisper_status().model)
 }
 
 /// Command to swap the AI model (e.g. from Tiny to Large)
@@ -121,6 +120,13 @@ pub async fn switch_model(
         Ok(Ok(msg)) => {
             state.model_loaded.store(true, Ordering::Relaxed);
             tray::update_tray_model_item(&app, true);
+            super::persist_setting(&app, "whisper_model", Some(model_id.clone().into()));
+            state
+                .whisper
+                .lock()
+                .unwrap()
+                .set_default_model(Some(model_id.clone()));
+            super::persist_setting(&app, "active_engine", Some("whisper".into()));
             crate::memory::log_process_memory("switch_model command success");
             Ok(CommandResult::ok(msg))
         }
@@ -145,6 +151,26 @@ pub async fn switch_model(
     }
 }
 
+/// Swap the loaded Parakeet model (e.g. CTC → Nemotron) without restarting.
+/// Like `switch_model`, refuses while recording; the load itself is `init_parakeet`.
+#[tauri::command]
+pub async fn switch_parakeet_model(
+    state: State<'_, AudioState>,
+    app: tauri::AppHandle,
+    model_id: String,
+    use_gpu: Option<bool>,
+) -> Result<CommandResult<String>, String> {
+    if state.recording_handle.lock().unwrap().is_some() {
+        return Ok(CommandResult::err(
+            "already_recording",
+            "Cannot switch models while recording",
+        ));
+    }
+
+    println!("[INFO] Switching to Parakeet model: {}", model_id);
+    init_parakeet(state, app, Some(model_id), use_gpu).await
+}
+
 /// List Parakeet models
 #[tauri::command]
 pub fn list_parakeet_models() -> Result<Vec<parakeet::ParakeetModelInfo>, String> {
@@ -182,6 +208,7 @@ pub async fn init_parakeet(
     let parakeet_arc = state.parakeet.clone();
     let cohere_arc = state.cohere.clone();
     let active_engine_arc = state.active_engine.clone();
+    let persisted_model_id = model_id.clone();
 
     let result = tauri::async_runtime::spawn_blocking(move || {
         // 2. Check what is currently loaded.
@@ -235,6 +262,10 @@ pub async fn init_parakeet(
         Ok(Ok(msg)) => {
             state.model_loaded.store(true, Ordering::Relaxed);
             tray::update_tray_model_item(&app, true);
+            if let Some(model_id) = persisted_model_id {
+                super::persist_setting(&app, "parakeet_model", Some(model_id.into()));
+            }
+            super::persist_setting(&app, "active_engine", Some("parakeet".into()));
             crate::memory::log_process_memory("init_parakeet command success");
             Ok(CommandResult::ok(msg))
         }
@@ -262,6 +293,233 @@ pub async fn init_parakeet(
 /// Ask for Parakeet status (Model, Type, Backend)
 #[tauri::command]
 pub fn get_parakeet_status(state: State<AudioState>) -> Result<parakeet::ParakeetStatus, String> {
-    let parakeet = state.parakeet.lock().unwrap();
-    Ok(parakeet.get_status())
+    Ok(state.parakeet_status())
+}
+
+/// Run a short silence pass through every loaded ASR engine.
+/// Called by the frontend (e.g. on window focus) so the first dictation after
+/// idle or sleep doesn't pay the GPU / page-cache wake-up cost.
+/// Returns the names of the engines that were warmed up.
+#[tauri::command]
+pub async fn warmup_engines(
+    state: State<'_, AudioState>,
+) -> Result<CommandResult<Vec<String>>, String> {
+    if state.recording_handle.lock().unwrap().is_some() {
+        return Ok(CommandResult::err(
+            "already_recording",
+            "Cannot warm up engines while recording",
+        ));
+    }
+    if state.engine_loading.load(Ordering::Relaxed) {
+        return Ok(CommandResult::err(
+            "engine_loading",
+            "A model is loading — please wait",
+        ));
+    }
+
+    let whisper_arc = state.whisper.clone();
+    let parakeet_arc = state.parakeet.clone();
+    let cohere_arc = state.cohere.clone();
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let start = std::time::Instant::now();
+        let mut warmed = Vec::new();
+
+        {
+            let mut whisper = whisper_arc.lock().unwrap();
+            if whisper.get_current_model().is_some() {
+                match whisper.warm_up() {
+                    Ok(()) => warmed.push("whisper".to_string()),
+                    Err(e) => eprintln!("[WARN] Whisper warm-up failed: {}", e),
+                }
+            }
+        }
+        {
+            let mut parakeet = parakeet_arc.lock().unwrap();
+            if parakeet.get_status().loaded {
+                match parakeet.warm_up() {
+                    Ok(()) => warmed.push("parakeet".to_string()),
+                    Err(e) => eprintln!("[WARN] Parakeet warm-up failed: {}", e),
+                }
+            }
+        }
+        {
+            let mut cohere = cohere_arc.lock().unwrap();
+            if cohere.get_status().loaded {
+                match cohere.warm_up() {
+                    Ok(()) => warmed.push("cohere".to_string()),
+                    Err(e) => eprintln!("[WARN] Cohere warm-up failed: {}", e),
+                }
+            }
+        }
+
+        println!(
+            "[INFO] Warm-up finished for {:?} in {}ms",
+            warmed,
+            start.elapsed().as_millis()
+        );
+        CommandResult::ok(warmed)
+    })
+    .await
+    .map_err(|e| format!("warmup_engines task failed: {}", e))
+}
+
+/// Payload for the "model-mismatch" event: the loaded Whisper model is English-only
+/// but transcription was asked for another language.
+#[derive(Clone, serde::Serialize)]
+pub struct ModelMismatch {
+    pub language: String,
+    pub current_model: String,
+    /// Download-manager id of the multilingual counterpart, e.g. "whisper-base-q5_1".
+    pub recommended_model: String,
+    /// Already on disk, so it only needs switching to rather than downloading.
+    pub downloaded: bool,
+}
+
+/// Mismatch for `language` (ISO 639-1, or "auto") against a loaded Whisper model id
+/// such as "base.en-q5_1". English-only models are recommended their multilingual
+/// counterpart of the same size and quantization.
+pub(crate) fn model_language_mismatch(
+    current_model: &str,
+    language: &str,
+) -> Option<ModelMismatch> {
+    if language.eq_ignore_ascii_case("en") || !current_model.contains(".en") {
+        return None;
+    }
+    let multilingual = current_model.replacen(".en", "", 1);
+    let downloaded = crate::utils::get_models_dir()
+        .map(|dir| dir.join(format!("ggml-{}.bin", multilingual)).exists())
+        .unwrap_or(false);
+    Some(ModelMismatch {
+        language: language.to_string(),
+        current_model: current_model.to_string(),
+        recommended_model: format!("whisper-{}", multilingual.replace('.', "-")),
+        downloaded,
+    })
+}
+
+/// Check the loaded Whisper model against `language`; on a mismatch emit
+/// "model-mismatch" (so the UI can offer the download) and return it.
+#[tauri::command]
+pub fn check_model_language(
+    app: tauri::AppHandle,
+    state: State<AudioState>,
+    language: String,
+) -> Option<ModelMismatch> {
+    let current = state.whisper_status().model?;
+    let mismatch = model_language_mismatch(&current, &language)?;
+    println!(
+        "[WARN] Whisper model '{}' is English-only; recommending {} for '{}'",
+        mismatch.current_model, mismatch.recommended_model, language
+    );
+    let _ = app.emit("model-mismatch", mismatch.clone());
+    Some(mismatch)
+}
+
+/// How often the GPU keep-alive runs its silent pass.
+const GPU_KEEPALIVE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(4);
+
+/// Background loop (one thread for the app's lifetime). While `gpu_keepalive` is on,
+/// the main window is focused and nothing is recording or loading, run the active
+/// engine's warm-up pass every few seconds so the GPU stays clocked up.
+pub fn run_gpu_keepalive(state: AudioState) {
+    loop {
+        std::thread::sleep(GPU_KEEPALIVE_INTERVAL);
+        let idle = state.gpu_keepalive.load(Ordering::Relaxed)
+            && state.window_focused.load(Ordering::Relaxed)
+            && !state.engine_loading.load(Ordering::Relaxed)
+            && state.recording_handle.lock().unwrap().is_none();
+        if !idle {
+            continue;
+        }
+
+        // try_lock: never queue behind a real transcription.
+        let engine = *state.active_engine.lock().unwrap();
+        let result = match engine {
+            ASREngine::Whisper => state
+                .whisper
+                .try_lock()
+                .ok()
+                .and_then(|mut w| w.get_current_model().is_some().then(|| w.warm_up())),
+            ASREngine::Parakeet => state
+                .parakeet
+                .try_lock()
+                .ok()
+                .and_then(|mut p| p.get_status().loaded.then(|| p.warm_up())),
+            ASREngine::Cohere => state
+                .cohere
+                .try_lock()
+                .ok()
+                .and_then(|mut c| c.get_status().loaded.then(|| c.warm_up())),
+        };
+        if let Some(Err(e)) = result {
+            eprintln!("[WARN] GPU keep-alive pass failed: {}", e);
+        }
+    }
+}
+
+/// Payload for the "engine-ready" event emitted when a `preload_engine` load finishes.
+#[derive(Clone, serde::Serialize)]
+pub struct EngineReady {
+    pub engine: ASREngine,
+    pub model_id: Option<String>,
+    pub ok: bool,
+    pub error: Option<String>,
+}
+
+/// Start loading an engine in the background and return "loading" immediately, so
+/// startup auto-load never holds up the UI. Emits "engine-ready" when finished; until
+/// then the other model commands answer `engine_loading`.
+/// Whisper without a `model_id` loads the first installed model.
+#[tauri::command]
+pub fn preload_engine(
+    app: tauri::AppHandle,
+    engine: ASREngine,
+    model_id: Option<String>,
+    use_gpu: Option<bool>,
+) -> CommandResult<String> {
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<AudioState>();
+        let model_id = match engine {
+            ASREngine::Whisper => model_id.or_else(|| {
+                whisper::WhisperManager::list_available_models()
+                    .ok()?
+                    .into_iter()
+                    .next()
+                    .map(|m| m.id)
+            }),
+            _ => model_id,
+        };
+        println!(
+            "[INFO] Preloading {:?} ({:?}) in background",
+            engine, model_id
+        );
+
+        let result = match (engine, model_id.clone()) {
+            (ASREngine::Whisper, Some(id)) => switch_model(state, app.clone(), id, use_gpu).await,
+            (ASREngine::Whisper, None) => Ok(CommandResult::err(
+                "model_missing",
+                "No Whisper models installed",
+            )),
+            (ASREngine::Parakeet, id) => init_parakeet(state, app.clone(), id, use_gpu).await,
+            (ASREngine::Cohere, id) => {
+                super::init_cohere(state, app.clone(), id, use_gpu.map(|gpu| !gpu)).await
+            }
+        };
+
+        let (ok, error) = match result {
+            Ok(r) => (r.ok, r.error.map(|e| e.message)),
+            Err(e) => (false, Some(e)),
+        };
+        let _ = app.emit(
+            "engine-ready",
+            EngineReady {
+                engine,
+                model_id,
+                ok,
+                error,
+            },
+        );
+    });
+    CommandResult::ok("loading".to_string())
 }
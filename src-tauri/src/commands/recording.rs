@@ -1,49 +1,265 @@
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
-use crossbeam_channel::bounded;
+use crossbeam_channel::{bounded, unbounded, Receiver, Sender, TrySendError};
+use std::collections::VecDeque;
 use std::sync::{
-    atomic::{AtomicBool, AtomicU32, Ordering},
+    atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
     Arc, Mutex,
 };
-use tauri::{AppHandle, Emitter, State};
+use tauri::{AppHandle, Emitter, Manager, State};
 
+use crate::asr::Transcriber;
 use crate::audio::{RecordingHandle, SendStream};
 use crate::audio_preprocess;
 use crate::context::get_active_context;
 use crate::denoise::Denoiser;
 use crate::state::AudioState;
-use crate::types::{ASREngine, CommandResult, TranscriptionChunk};
+use crate::types::{
+    ASREngine, AppState, BufferDropPolicy, CommandResult, RecordingStatus, TranscriptionChunk,
+    TranscriptionOutcome, TypingMode, VoiceCommand,
+};
 use crate::utils::{clean_transcript, get_recordings_dir, strip_whitelisted_sound_captions};
 
-/// Live Parakeet chunk length in seconds. Very short windows (~1s) hurt accuracy on
-/// streaming CTC; ~4s trades a bit of latency for much better context (see NeMo
-/// streaming / buffered-chunk guidance).
-const PARAKEET_LIVE_CHUNK_SECS: f32 = 4.0;
+/// How many live chunks `get_recent_chunks` can return after a session.
+const RECENT_CHUNKS_CAPACITY: usize = 64;
+/// settings.json key holding the last recording's WAV while it awaits deletion.
+const UNKEPT_RECORDING_KEY: &str = "unkept_recording";
+
+/// Emit a live chunk to the frontend and remember it in the rolling buffer, so a
+/// window that was hidden during recording can catch up via `get_recent_chunks`.
+fn emit_transcription_chunk(
+    app: &AppHandle,
+    recent_chunks: &Arc<Mutex<VecDeque<TranscriptionChunk>>>,
+    chunk: TranscriptionChunk,
+) {
+    {
+        let mut recent = recent_chunks.lock().unwrap();
+        while recent.len() >= RECENT_CHUNKS_CAPACITY {
+            recent.pop_front();
+        }
+        recent.push_back(chunk.clone());
+    }
+    let _ = app.emit("transcription-chunk", chunk);
+}
 
-#[inline]
-fn parakeet_min_samples(sample_rate: u32) -> usize {
-    (sample_rate as f32 * PARAKEET_LIVE_CHUNK_SECS) as usize
+/// Log a transcription failure and surface it as a "transcription-error" event, so
+/// the UI can explain why the live preview stopped (e.g. the GPU ran out of VRAM).
+fn emit_transcription_error(app: &AppHandle, engine: &str, stage: &'static str, message: &str) {
+    eprintln!(
+        "[ERROR] {} {} transcription error: {}",
+        engine, stage, message
+    );
+    let _ = app.emit(
+        "transcription-error",
+        TranscriptionError {
+            engine: engine.to_string(),
+            stage,
+            message: message.to_string(),
+        },
+    );
 }
 
-/// Universal preprocess → 16 kHz, then pad to `PARAKEET_LIVE_CHUNK_SECS` at 16 kHz.
-fn parakeet_preprocess_for_transcribe(
-    buf: &[f32],
+/// Append a live chunk's text to the session transcript with a word boundary:
+/// engines return chunks without a leading space, so consecutive chunks would
+/// otherwise run together ("helloworld"). Any doubled spacing is collapsed later
+/// by `clean_transcript`.
+fn append_to_session(session: &mut String, text: &str) {
+    if !session.is_empty()
+        && !session.ends_with(char::is_whitespace)
+        && !text.starts_with(char::is_whitespace)
+    {
+        session.push(' ');
+    }
+    session.push_str(text);
+}
+
+/// Hand a mic callback's samples to the transcriber without ever blocking the
+/// real-time audio thread. When the queue is full the drop policy decides which
+/// audio is lost (`Block` uses an unbounded queue, so it never gets here).
+fn send_live_samples(
+    tx: &Sender<Vec<f32>>,
+    rx: &Receiver<Vec<f32>>,
+    samples: Vec<f32>,
+    policy: BufferDropPolicy,
+    dropped: &AtomicU64,
+) {
+    let samples = match tx.try_send(samples) {
+        Err(TrySendError::Full(samples)) => samples,
+        _ => return,
+    };
+    if policy == BufferDropPolicy::DropOldest {
+        if let Ok(oldest) = rx.try_recv() {
+            dropped.fetch_add(oldest.len() as u64, Ordering::Relaxed);
+        }
+        if let Err(TrySendError::Full(samples)) = tx.try_send(samples) {
+            dropped.fetch_add(samples.len() as u64, Ordering::Relaxed);
+        }
+    } else {
+        dropped.fetch_add(samples.len() as u64, Ordering::Relaxed);
+    }
+}
+
+/// Keep the transcriber's accumulator within `max_len` samples according to the
+/// drop policy. `Block` lets it grow so no audio is lost.
+fn enforce_backlog_limit(
+    buffer: &mut Vec<f32>,
+    max_len: usize,
+    chunk_len: usize,
+    policy: BufferDropPolicy,
+    dropped: &AtomicU64,
+) {
+    if buffer.len() <= max_len {
+        return;
+    }
+    match policy {
+        BufferDropPolicy::DropOldest => {
+            println!("[WARNING] Buffer full, dropping old audio to catch up");
+            buffer.drain(..chunk_len);
+            dropped.fetch_add(chunk_len as u64, Ordering::Relaxed);
+        }
+        BufferDropPolicy::DropNewest => {
+            println!("[WARNING] Buffer full, dropping new audio to catch up");
+            let excess = buffer.len() - max_len;
+            buffer.truncate(max_len);
+            dropped.fetch_add(excess as u64, Ordering::Relaxed);
+        }
+        BufferDropPolicy::Block => {}
+    }
+}
+
+/// Shortest live Whisper chunk cut at a pause; below this, context is too thin.
+const WHISPER_LIVE_MIN_CHUNK_SECS: u32 = 3;
+/// Silence needed between words before the live Whisper path treats it as a boundary.
+const WHISPER_LIVE_PAUSE_MS: u32 = 300;
+
+/// Where to cut the next live chunk from the accumulator, if anywhere yet.
+/// Pause-aware engines cut at the first natural pause past `min_chunk`; otherwise
+/// (or when no pause shows up within `chunk_size`) the fixed window is used.
+fn next_live_cut(
+    buffer: &[f32],
     sample_rate: u32,
+    chunk_size: usize,
+    min_chunk: usize,
+    pause_aware: bool,
+) -> Option<usize> {
+    if pause_aware {
+        let window = &buffer[..buffer.len().min(chunk_size)];
+        if let Some(cut) =
+            crate::vad::find_pause_cut(window, sample_rate, min_chunk, WHISPER_LIVE_PAUSE_MS)
+        {
+            return Some(cut);
+        }
+    }
+    (buffer.len() >= chunk_size).then_some(chunk_size)
+}
+
+/// The speech engines, so the live loop can lock whichever one is active.
+struct LiveEngines {
+    whisper: Arc<Mutex<crate::whisper::WhisperManager>>,
+    parakeet: Arc<Mutex<crate::parakeet::ParakeetManager>>,
+    cohere: Arc<Mutex<crate::cohere::CohereManager>>,
+}
+
+impl LiveEngines {
+    fn with<R>(&self, engine: ASREngine, f: impl FnOnce(&mut dyn Transcriber) -> R) -> R {
+        match engine {
+            ASREngine::Whisper => f(&mut *self.whisper.lock().unwrap()),
+            ASREngine::Parakeet => f(&mut *self.parakeet.lock().unwrap()),
+            ASREngine::Cohere => f(&mut *self.cohere.lock().unwrap()),
+        }
+    }
+}
+
+/// Where live transcripts go and what preprocessing feeds them, for every engine.
+struct LiveChunkSink<'a> {
+    app: &'a AppHandle,
+    vad: &'a Arc<Mutex<crate::vad::VADManager>>,
+    session_transcript: &'a Arc<Mutex<String>>,
+    recent_chunks: &'a Arc<Mutex<VecDeque<TranscriptionChunk>>>,
     user_denoise: bool,
-    denoiser_arc: &Arc<Mutex<Option<Denoiser>>>,
-) -> Vec<f32> {
-    let mut guard = denoiser_arc.lock().unwrap();
-    let mut pcm16 = audio_preprocess::preprocess_live_transcribe_chunk(
-        buf,
+    denoiser_arc: &'a Arc<Mutex<Option<Denoiser>>>,
+}
+
+/// Live transcription of one chunk: normalize → VAD-check → transcribe → emit, the
+/// same for every engine. The Silero gate runs when `gate` is set and the engine
+/// wants it (streaming Parakeet doesn't); `label` tags log lines, e.g. "(Tail) ".
+fn transcribe_live_chunk(
+    engine: &mut dyn Transcriber,
+    chunk: &[f32],
+    sample_rate: u32,
+    gate: bool,
+    label: &str,
+    sink: &LiveChunkSink,
+) {
+    let mut denoise_guard = sink.denoiser_arc.lock().unwrap();
+    let pcm16 = audio_preprocess::preprocess_live_transcribe_chunk(
+        chunk,
         sample_rate,
-        user_denoise,
-        guard.as_mut(),
+        sink.user_denoise,
+        denoise_guard.as_mut(),
     );
-    drop(guard);
-    let min_len = parakeet_min_samples(16000);
-    if pcm16.len() < min_len {
-        pcm16.resize(min_len, 0.0);
+    drop(denoise_guard);
+
+    if pcm16.is_empty() {
+        return;
+    }
+    let (method, emoji) = (engine.method(), engine.emoji());
+
+    // Scan the full chunk frame-by-frame and take the peak speech probability.
+    // Evaluating only the first 32 ms (one Silero frame) of a 6-second chunk is
+    // unreliable: the LSTM needs several warmup frames from a cold state, and speech
+    // can begin anywhere in the window. The default cutoff 0.25 matches assemble_speech_audio's
+    // second Silero pass (onset=0.28) — Silero returns 0.25–0.40 for clean speech.
+    // It moves with the user's VAD sensitivity.
+    let vad_probability = if gate && engine.vad_gated() {
+        let (is_speech, speech_cutoff) = {
+            let mut vad = sink.vad.lock().unwrap();
+            (vad.max_speech_prob(&pcm16, usize::MAX), vad.speech_cutoff())
+        };
+        if is_speech <= speech_cutoff {
+            println!(
+                "[VAD] 🔇 Silence ({:.0}%) - Skipping {} chunk",
+                (1.0 - is_speech) * 100.0,
+                method,
+            );
+            return;
+        }
+        println!(
+            "[PROCESSING] {} Speech ({:.0}%) - {} transcribing {:.2}s chunk...",
+            emoji,
+            is_speech * 100.0,
+            method,
+            pcm16.len() as f32 / 16000.0,
+        );
+        Some(is_speech)
+    } else {
+        None
+    };
+
+    let start = std::time::Instant::now();
+    match engine.transcribe(&pcm16, 16000) {
+        Ok(text) => {
+            let text = strip_whitelisted_sound_captions(&text);
+            if text.trim().is_empty() {
+                return;
+            }
+            let elapsed = start.elapsed().as_millis() as u32;
+            println!(
+                "[TRANSCRIPT] {} {}\"{}\" (took {}ms)",
+                emoji,
+                label,
+                text.trim(),
+                elapsed
+            );
+            emit_transcription_chunk(
+                sink.app,
+                sink.recent_chunks,
+                TranscriptionChunk::new(text.clone(), elapsed, method)
+                    .with_quality(vad_probability, engine.last_confidence()),
+            );
+            append_to_session(&mut sink.session_transcript.lock().unwrap(), &text);
+        }
+        Err(e) => emit_transcription_error(sink.app, method, "live", &e),
     }
-    pcm16
 }
 
 /// COMMAND: START RECORDING
@@ -58,6 +274,10 @@ pub async fn start_recording(
     app_handle: AppHandle,
     state: State<'_, AudioState>,
     denoise: Option<bool>,
+    use_loopback: Option<bool>,
+    loopback_device: Option<String>,
+    save_denoised: Option<bool>,
+    denoise_strength: Option<f32>,
 ) -> Result<CommandResult<String>, String> {
     // Guard: reject if already recording (e.g. spam hotkey)
     if state.recording_handle.lock().unwrap().is_some() {
@@ -66,13 +286,23 @@ pub async fn start_recording(
 
     // Clone the whole state — every field is Arc<…> so this is just ref-count bumps.
     let state = (*state).clone();
+    let hotkey_recording_active = state.hotkey_recording_active.clone();
     tauri::async_runtime::spawn_blocking(move || {
-        start_recording_blocking(app_handle, state, denoise)
+        start_recording_blocking(
+            app_handle,
+            state,
+            denoise,
+            use_loopback.unwrap_or(false),
+            loopback_device,
+            save_denoised.unwrap_or(false),
+            denoise_strength.unwrap_or(1.0),
+        )
     })
     .await
     .map(|result| match result {
         Ok(message) => CommandResult::ok(message),
         Err(message) => {
+            hotkey_recording_active.store(false, Ordering::SeqCst);
             let lower = message.to_lowercase();
             let code = if lower.contains("microphone permission denied") {
                 "mic_permission_denied"
@@ -82,6 +312,8 @@ pub async fn start_recording(
                 "no_input_device"
             } else if lower.contains("already recording") {
                 "already_recording"
+            } else if lower.contains("loopback capture is only supported") {
+                "loopback_unsupported"
             } else {
                 "recording_start_failed"
             };
@@ -91,6 +323,123 @@ pub async fn start_recording(
     .map_err(|e| format!("start_recording task failed: {}", e))
 }
 
+/// COMMAND: Record a one-shot note for `seconds` (default 10, max 120), then stop
+/// and transcribe it through the normal stop path. Fails with `quick_note_interrupted`
+/// if the recording was stopped or replaced by another one while waiting.
+#[tauri::command]
+pub async fn quick_note(
+    app_handle: AppHandle,
+    state: State<'_, AudioState>,
+    seconds: Option<u32>,
+) -> Result<CommandResult<TranscriptionOutcome>, String> {
+    let duration = std::time::Duration::from_secs(seconds.unwrap_or(10).clamp(1, 120) as u64);
+
+    let started = start_recording(
+        app_handle.clone(),
+        state.clone(),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await?;
+    if let Some(error) = started.error {
+        return Ok(CommandResult::err(error.code, error.message));
+    }
+    let session_path = state.last_recording_path.lock().unwrap().clone();
+    println!("[INFO] Quick note: recording for {}s", duration.as_secs());
+
+    tauri::async_runtime::spawn_blocking(move || std::thread::sleep(duration))
+        .await
+        .map_err(|e| format!("quick_note task failed: {}", e))?;
+
+    let same_session = state.recording_handle.lock().unwrap().is_some()
+        && *state.last_recording_path.lock().unwrap() == session_path;
+    if !same_session {
+        return Ok(CommandResult::err(
+            "quick_note_interrupted",
+            "Quick note recording was stopped before the timer ran out",
+        ));
+    }
+    stop_recording(app_handle, state).await
+}
+
+/// How often the file saver rewrites the in-progress WAV header.
+const WAV_HEADER_FLUSH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Payload for the "device-fallback" event: the saved input device was missing at
+/// recording start, so the system default was used instead.
+#[derive(Clone, serde::Serialize)]
+pub struct DeviceFallback {
+    pub requested: String,
+    pub fallback: String,
+}
+
+/// Payload for the "transcription-final" event: the cleaned transcript `stop_recording`
+/// returns, so a UI driven by events can replace the live preview with it.
+#[derive(Clone, serde::Serialize)]
+pub struct TranscriptionFinal {
+    pub text: String,
+    /// Time from stop to the finished transcript, including the final pass.
+    pub processing_time_ms: u32,
+    pub method: String,
+}
+
+/// Payload for the "transcription-error" event.
+#[derive(Clone, serde::Serialize)]
+pub struct TranscriptionError {
+    pub engine: String,
+    /// "live" for a streaming chunk, "final" for the post-stop pass.
+    pub stage: &'static str,
+    pub message: String,
+}
+
+/// Payload for the "recording-auto-stopped" event: the recording reached the
+/// `max_recording_secs` limit and the backend already ran `stop_recording`. `result`
+/// is what that call returned, so the frontend finishes the session (insert, history)
+/// without stopping again.
+#[derive(Clone, serde::Serialize)]
+pub struct RecordingAutoStopped {
+    pub max_secs: u32,
+    pub result: CommandResult<TranscriptionOutcome>,
+}
+
+/// Payload for the "recording-device-lost" event: the input stream failed mid-recording
+/// (usually the mic was unplugged) and the backend already ran `stop_recording`, so the
+/// WAV is finalized and what was captured before the loss is transcribed. The session
+/// is not moved to another device. `result` is what the stop returned, as for
+/// "recording-auto-stopped".
+#[derive(Clone, serde::Serialize)]
+pub struct RecordingDeviceLost {
+    pub code: String,
+    pub message: String,
+    pub result: CommandResult<TranscriptionOutcome>,
+}
+
+/// Pick the output device to capture in loopback mode: the named one, or the default.
+/// WASAPI records whatever is playing on an output device when an input stream is
+/// built on it; other hosts have no equivalent in cpal.
+#[cfg(target_os = "windows")]
+fn select_loopback_device(host: &cpal::Host, name: Option<&str>) -> Result<cpal::Device, String> {
+    let device = match name {
+        Some(name) => host
+            .output_devices()
+            .ok()
+            .and_then(|mut iter| iter.find(|d| d.name().ok().as_deref() == Some(name))),
+        None => host.default_output_device(),
+    };
+    device.ok_or_else(|| match name {
+        Some(name) => format!("Loopback output device '{}' not found", name),
+        None => "No output device found for loopback capture".to_string(),
+    })
+}
+
+#[cfg(not(target_os = "windows"))]
+fn select_loopback_device(_host: &cpal::Host, _name: Option<&str>) -> Result<cpal::Device, String> {
+    Err("Loopback capture is only supported on Windows".to_string())
+}
+
 /// The blocking core of start_recording, run inside spawn_blocking.
 /// Receives a cloned AudioState (cheap — all fields are Arc) instead of
 /// 13 individually-cloned Arc parameters.
@@ -98,69 +447,96 @@ fn start_recording_blocking(
     app_handle: AppHandle,
     state: AudioState,
     denoise: Option<bool>,
+    use_loopback: bool,
+    loopback_device: Option<String>,
+    save_denoised: bool,
+    denoise_strength: f32,
 ) -> Result<String, String> {
     let denoise_enabled = denoise.unwrap_or(true);
     state.recording_paused.store(false, Ordering::Relaxed);
 
-    // 1. Setup Microphone
+    // 1. Setup Microphone (or, with loopback, the output device being played to)
     let host = cpal::default_host();
-    let preferred = state.selected_input_device.lock().unwrap().clone();
+    let device = if use_loopback {
+        select_loopback_device(&host, loopback_device.as_deref())?
+    } else {
+        let preferred = state.selected_input_device.lock().unwrap().clone();
 
-    let mut device_opt = None;
-    let mut fallback_triggered = false;
+        let mut device_opt = None;
+        let mut missing_preferred = None;
 
-    if let Some(ref name) = preferred {
-        device_opt = host
-            .input_devices()
-            .ok()
-            .and_then(|mut iter| iter.find(|d| d.name().ok().as_deref() == Some(name.as_str())));
+        if let Some(ref name) = preferred {
+            device_opt = host.input_devices().ok().and_then(|mut iter| {
+                iter.find(|d| d.name().ok().as_deref() == Some(name.as_str()))
+            });
 
-        if device_opt.is_none() {
-            println!(
-                "[WARNING] Preferred input device '{}' not found, falling back to default",
-                name
-            );
-            fallback_triggered = true;
+            if device_opt.is_none() {
+                println!(
+                    "[WARNING] Preferred input device '{}' not found, falling back to default",
+                    name
+                );
+                missing_preferred = Some(name.clone());
+            }
         }
-    }
 
-    if device_opt.is_none() {
-        device_opt = host.default_input_device();
-    }
+        if device_opt.is_none() {
+            device_opt = host.default_input_device();
+        }
 
-    let device =
-        device_opt.ok_or("No input device found. Check that a microphone is connected.")?;
+        let device =
+            device_opt.ok_or("No input device found. Check that a microphone is connected.")?;
+        let device_name = device
+            .name()
+            .unwrap_or_else(|_| "Unknown Device".to_string());
+
+        if let Some(requested) = missing_preferred {
+            let _ = app_handle.emit(
+                "device-fallback",
+                DeviceFallback {
+                    requested,
+                    fallback: device_name,
+                },
+            );
+        }
+        device
+    };
     let device_name = device
         .name()
         .unwrap_or_else(|_| "Unknown Device".to_string());
+    println!(
+        "[INFO] Using {} device: {}",
+        if use_loopback { "loopback" } else { "input" },
+        device_name
+    );
 
-    println!("[INFO] Using input device: {}", device_name);
-
-    if fallback_triggered {
-        let _ = app_handle.emit("audio-fallback", device_name);
-    }
-
-    let config: cpal::StreamConfig = device
-        .default_input_config()
-        .or_else(|e| {
-            println!("[WARNING] default_input_config failed: {}, falling back to iterating supported configs", e);
-            device.supported_input_configs()
-                .map_err(|_err| cpal::DefaultStreamConfigError::DeviceNotAvailable)?
-                .find(|c| c.sample_format() == cpal::SampleFormat::F32 || c.sample_format() == cpal::SampleFormat::I16)
-                .map(|c| c.with_max_sample_rate())
-                .ok_or(cpal::DefaultStreamConfigError::StreamTypeNotSupported)
-        })
-        .map_err(|e| {
-            // macOS: permission denial often surfaces as a vague
-            // CoreAudio error during config or stream creation.
-            let msg = e.to_string();
-            if msg.contains("permission") || msg.contains("denied") || msg.contains("not supported") {
-                "Microphone permission denied. Grant access in System Settings → Privacy & Security → Microphone.".to_string()
-            } else {
-                format!("Failed to get audio config: {}", msg)
-            }
-        })?
-        .into();
+    let config: cpal::StreamConfig = if use_loopback {
+        device
+            .default_output_config()
+            .map_err(|e| format!("Failed to get loopback audio config: {}", e))?
+            .into()
+    } else {
+        device
+            .default_input_config()
+            .or_else(|e| {
+                println!("[WARNING] default_input_config failed: {}, falling back to iterating supported configs", e);
+                device.supported_input_configs()
+                    .map_err(|_err| cpal::DefaultStreamConfigError::DeviceNotAvailable)?
+                    .find(|c| c.sample_format() == cpal::SampleFormat::F32 || c.sample_format() == cpal::SampleFormat::I16)
+                    .map(|c| c.with_max_sample_rate())
+                    .ok_or(cpal::DefaultStreamConfigError::StreamTypeNotSupported)
+            })
+            .map_err(|e| {
+                // macOS: permission denial often surfaces as a vague
+                // CoreAudio error during config or stream creation.
+                let msg = e.to_string();
+                if msg.contains("permission") || msg.contains("denied") || msg.contains("not supported") {
+                    "Microphone permission denied. Grant access in System Settings → Privacy & Security → Microphone.".to_string()
+                } else {
+                    format!("Failed to get audio config: {}", msg)
+                }
+            })?
+            .into()
+    };
 
     // 2. Prepare Output File
     let recordings_dir = get_recordings_dir()?;
@@ -179,13 +555,43 @@ fn start_recording_blocking(
     // Reset Silero VAD LSTM state so prior session context doesn't bleed in
     state.vad.lock().unwrap().reset_state();
 
-    *state.last_recording_path.lock().unwrap() = Some(path.to_string_lossy().into_owned());
+    let previous_recording = state
+        .last_recording_path
+        .lock()
+        .unwrap()
+        .replace(path.to_string_lossy().into_owned());
+    let keep_recording = state.keep_recordings.load(Ordering::Relaxed);
+    if let Some(previous) = previous_recording {
+        discard_recording(&previous, keep_recording);
+    }
+    // Note the WAV in settings.json so it (and the `_denoised` copy derived from its
+    // name) is swept at the next launch if the app dies before it can be discarded.
+    super::persist_setting(
+        &app_handle,
+        UNKEPT_RECORDING_KEY,
+        (!keep_recording).then(|| path.to_string_lossy().into_owned().into()),
+    );
     state.session_transcript.lock().unwrap().clear();
+    state.recent_chunks.lock().unwrap().clear();
+    state.dropped_samples.store(0, Ordering::Relaxed);
+    let drop_policy = *state.buffer_drop_policy.lock().unwrap();
 
-    // Create a fresh denoiser for this session (RNNoise GRU state must not leak across sessions)
-    if denoise_enabled {
-        *state.denoiser.lock().unwrap() = Some(Denoiser::new());
-        println!("[INFO] RNNoise denoiser enabled for this session");
+    // Create a fresh denoiser for this session (RNNoise GRU state must not leak across sessions).
+    // RNNoise only works on 48 kHz frames; at other rates it mangles speech, so it stays off.
+    if denoise_enabled && config.sample_rate.0 != 48000 {
+        println!(
+            "[WARNING] RNNoise needs 48 kHz input but the device runs at {} Hz — live denoise disabled for this session",
+            config.sample_rate.0
+        );
+        *state.denoiser.lock().unwrap() = None;
+    } else if denoise_enabled {
+        let mut denoiser = Denoiser::new();
+        denoiser.set_strength(denoise_strength);
+        *state.denoiser.lock().unwrap() = Some(denoiser);
+        println!(
+            "[INFO] RNNoise denoiser enabled for this session (strength {:.2})",
+            denoise_strength.clamp(0.0, 1.0)
+        );
     } else {
         *state.denoiser.lock().unwrap() = None;
     }
@@ -200,14 +606,46 @@ fn start_recording_blocking(
 
     let writer = hound::WavWriter::create(&path, spec).map_err(|e| e.to_string())?;
 
+    // Optional cleaned copy: mono, denoised by its own RNNoise instance over the whole
+    // stream (the transcriber's denoiser only sees the chunks it transcribes).
+    let denoised_path = denoised_recording_path(&path);
+    let denoised_writer = if !save_denoised {
+        None
+    } else if config.sample_rate.0 != 48000 {
+        println!(
+            "[WARNING] Denoised copy skipped: RNNoise needs 48 kHz input, device runs at {} Hz",
+            config.sample_rate.0
+        );
+        None
+    } else {
+        let denoised_spec = hound::WavSpec {
+            channels: 1,
+            ..spec
+        };
+        let w =
+            hound::WavWriter::create(&denoised_path, denoised_spec).map_err(|e| e.to_string())?;
+        println!(
+            "[INFO] Saving denoised copy to: {}",
+            denoised_path.display()
+        );
+        let mut denoiser = Denoiser::new();
+        denoiser.set_strength(denoise_strength);
+        Some((w, denoiser))
+    };
+    let file_channels = config.channels as usize;
+
     // 5. Create COMMUNICATION PIPES (Channels)
     // Bounded: prevents unbounded memory growth if file writer or transcriber falls behind.
     // Audio callback uses try_send so it never blocks the real-time capture thread.
+    // The Block policy trades that memory bound for never losing live audio.
     let (file_tx, file_rx) = bounded::<Vec<f32>>(256); // ~5s headroom at 48kHz/1024
-    let (whisper_tx, whisper_rx) = bounded::<Vec<f32>>(32); // transcriber has its own accumulator
+    let (whisper_tx, whisper_rx) = match drop_policy {
+        BufferDropPolicy::Block => unbounded::<Vec<f32>>(),
+        _ => bounded::<Vec<f32>>(32), // transcriber has its own accumulator
+    };
 
-    let file_tx_clone = file_tx.clone();
-    let whisper_tx_clone = whisper_tx.clone();
+    let whisper_rx_for_drop = whisper_rx.clone();
+    let dropped_samples = state.dropped_samples.clone();
 
     let sample_rate = config.sample_rate.0;
 
@@ -216,12 +654,45 @@ fn start_recording_blocking(
     let level_stop_clone2 = level_stop.clone();
     let level_stop_clone3 = level_stop.clone();
 
+    // Max-duration guard: the callback counts frames and stops capturing at the limit;
+    // the level thread (not the cpal callback, see below) emits the event.
+    let max_recording_secs = *state.max_recording_secs.lock().unwrap();
+    let max_frames = max_recording_secs.map(|secs| secs as u64 * sample_rate as u64);
+    let captured_frames = Arc::new(AtomicU64::new(0));
+    let limit_reached = Arc::new(AtomicBool::new(false));
+
+    // Set by the stream error callback; the level thread reports it (emit from the cpal
+    // error callback has the same Windows COM-thread problem as the data callback).
+    let device_lost: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
+    // The error stays in `device_lost` (so a dead device's repeated errors don't
+    // re-arm it); this flag makes the level thread emit it only once.
+    let device_lost_reported = Arc::new(AtomicBool::new(false));
+
     // 6. SPAWN THREAD 1: THE FILE SAVER
     let writer_thread = std::thread::spawn(move || {
         let mut writer = writer;
+        let mut denoised_writer = denoised_writer;
+        let mut write_denoised = |samples: &[f32]| {
+            if let Some((w, denoiser)) = denoised_writer.as_mut() {
+                let mono = audio_preprocess::downmix_to_mono(samples, file_channels);
+                for sample in denoiser.process(&mono) {
+                    w.write_sample(sample).ok();
+                }
+            }
+        };
+        let mut last_header_flush = std::time::Instant::now();
         loop {
+            // Rewrite the header sizes periodically so a crash mid-recording
+            // still leaves a playable WAV (hound only fixes them up on flush/finalize).
+            if last_header_flush.elapsed() >= WAV_HEADER_FLUSH_INTERVAL {
+                if let Err(e) = writer.flush() {
+                    eprintln!("[WARN] WAV header flush failed: {}", e);
+                }
+                last_header_flush = std::time::Instant::now();
+            }
             match file_rx.recv_timeout(std::time::Duration::from_millis(50)) {
                 Ok(samples) => {
+                    write_denoised(&samples);
                     for sample in samples {
                         writer.write_sample(sample).ok();
                     }
@@ -244,127 +715,61 @@ fn start_recording_blocking(
 
         // Drain any remaining
         while let Ok(samples) = file_rx.try_recv() {
+            write_denoised(&samples);
             for sample in samples {
                 writer.write_sample(sample).ok();
             }
         }
         writer.finalize().ok();
+        if let Some((w, _)) = denoised_writer {
+            w.finalize().ok();
+            println!("Denoised WAV file saved.");
+        }
         println!("WAV file saved.");
     });
 
     // Pull shared references out of state for the transcriber thread
-    let whisper = state.whisper.clone();
-    let parakeet_manager = state.parakeet.clone();
-    let cohere = state.cohere.clone();
     let vad = state.vad.clone();
     let active_engine = *state.active_engine.lock().unwrap();
     let session_transcript = state.session_transcript.clone();
+    let recent_chunks = state.recent_chunks.clone();
     let denoiser_arc = state.denoiser.clone();
     let recording_handle_arc = state.recording_handle.clone();
     let denoise_enabled_thread = denoise_enabled;
 
-    /// VAD-gated transcription — shared logic for Whisper and Cohere.
-    /// Both managers expose the same `transcribe_chunk(&[f32], u32) -> Result<String, _>` API,
-    /// so the entire accumulate → normalize → VAD-check → transcribe → emit pipeline
-    /// lives here once instead of being copy-pasted per engine.
-    ///
-    /// Returns the transcript text if speech was detected and transcription succeeded,
-    /// or `None` when the chunk was silence or the transcription was empty.
-    #[allow(clippy::too_many_arguments)]
-    fn vad_gated_transcribe(
-        chunk: &mut Vec<f32>,
-        sample_rate: u32,
-        vad: &std::sync::Arc<std::sync::Mutex<crate::vad::VADManager>>,
-        transcribe: &mut impl FnMut(&[f32], u32) -> Result<String, String>,
-        method: &str,
-        emoji: &str,
-        app: &AppHandle,
-        session_transcript: &std::sync::Arc<std::sync::Mutex<String>>,
-        user_denoise: bool,
-        denoiser_arc: &Arc<Mutex<Option<Denoiser>>>,
-    ) -> bool {
-        let mut denoise_guard = denoiser_arc.lock().unwrap();
-        let pcm16 = audio_preprocess::preprocess_live_transcribe_chunk(
-            chunk.as_slice(),
-            sample_rate,
-            user_denoise,
-            denoise_guard.as_mut(),
-        );
-        drop(denoise_guard);
-
-        if pcm16.is_empty() {
-            return false;
-        }
-
-        // Scan the full chunk frame-by-frame and take the peak speech probability.
-        // Evaluating only the first 32 ms (one Silero frame) of a 6-second chunk is
-        // unreliable: the LSTM needs several warmup frames from a cold state, and speech
-        // can begin anywhere in the window. Threshold 0.25 matches assemble_speech_audio's
-        // second Silero pass (onset=0.28) — Silero returns 0.25–0.40 for clean speech.
-        let is_speech = vad.lock().unwrap().max_speech_prob(&pcm16, usize::MAX);
-
-        if is_speech > 0.25 {
-            println!(
-                "[PROCESSING] {} Speech ({:.0}%) - {} transcribing {:.2}s chunk...",
-                emoji,
-                is_speech * 100.0,
-                method,
-                pcm16.len() as f32 / 16000.0,
-            );
-            let start = std::time::Instant::now();
-            match transcribe(&pcm16, 16000) {
-                Ok(text) if !text.trim().is_empty() => {
-                    let text = if matches!(method, "Whisper" | "Cohere") {
-                        strip_whitelisted_sound_captions(&text)
-                    } else {
-                        text
-                    };
-                    if text.trim().is_empty() {
-                        return false;
-                    }
-                    let elapsed = start.elapsed().as_millis() as u32;
-                    println!(
-                        "[TRANSCRIPT] {} \"{}\" (took {}ms)",
-                        emoji,
-                        text.trim(),
-                        elapsed
-                    );
-                    let _ = app.emit(
-                        "transcription-chunk",
-                        crate::types::TranscriptionChunk {
-                            text: text.clone(),
-                            processing_time_ms: elapsed,
-                            method: method.to_string(),
-                        },
-                    );
-                    session_transcript.lock().unwrap().push_str(&text);
-                    true
-                }
-                Ok(_) => false,
-                Err(e) => {
-                    eprintln!("[ERROR] {} transcription error: {}", method, e);
-                    false
-                }
-            }
-        } else {
-            println!(
-                "[VAD] 🔇 Silence ({:.0}%) - Skipping {} chunk",
-                (1.0 - is_speech) * 100.0,
-                method,
-            );
-            false
-        }
-    }
-
     // 7. SPAWN THREAD 2: THE REAL-TIME TRANSCRIBER
+    let live_chunk_secs = *state.live_chunk_secs.lock().unwrap();
+    let engines = LiveEngines {
+        whisper: state.whisper.clone(),
+        parakeet: state.parakeet.clone(),
+        cohere: state.cohere.clone(),
+    };
     let app_clone = app_handle.clone();
     let transcriber_thread = std::thread::spawn(move || {
+        let sink = LiveChunkSink {
+            app: &app_clone,
+            vad: &vad,
+            session_transcript: &session_transcript,
+            recent_chunks: &recent_chunks,
+            user_denoise: denoise_enabled_thread,
+            denoiser_arc: &denoiser_arc,
+        };
         let mut buffer = Vec::new();
-        let chunk_size = match active_engine {
-            ASREngine::Cohere => (sample_rate * 15) as usize,
-            _ => (sample_rate * 6) as usize,
+        let (default_chunk_size, adjustable, pause_aware) = engines.with(active_engine, |t| {
+            (
+                t.preferred_chunk_samples(sample_rate),
+                t.chunk_size_adjustable(),
+                t.pause_aware(),
+            )
+        });
+        // `live_chunk_secs` overrides the engine's window where it allows it (not Cohere).
+        let chunk_size = match live_chunk_secs {
+            Some(secs) if adjustable => (sample_rate as f32 * secs) as usize,
+            _ => default_chunk_size,
         };
         let max_buffer_size = chunk_size * 2;
+        // Pause-aware engines (Whisper) cut at natural pauses between 3 s and chunk_size.
+        let min_chunk_size = ((sample_rate * WHISPER_LIVE_MIN_CHUNK_SECS) as usize).min(chunk_size);
         // Pre-allocated scratch buffer reused each iteration to avoid per-chunk Vec allocation
         let mut chunk = Vec::with_capacity(chunk_size);
         println!(
@@ -379,144 +784,38 @@ fn start_recording_blocking(
                 Err(crossbeam_channel::RecvTimeoutError::Disconnected) => break,
             };
 
-            match active_engine {
-                ASREngine::Whisper | ASREngine::Cohere => {
-                    buffer.extend(samples);
-                    while buffer.len() >= chunk_size {
-                        if buffer.len() > max_buffer_size {
-                            println!("[WARNING] Buffer full, dropping old audio to catch up");
-                            buffer.drain(..chunk_size);
-                        }
-                        chunk.clear();
-                        chunk.extend_from_slice(&buffer[..chunk_size]);
-                        buffer.drain(..chunk_size);
-                        if active_engine == ASREngine::Whisper {
-                            crate::memory::maybe_log_process_memory_with_sizes(
-                                "recording whisper live chunk start",
-                                &[
-                                    ("buffer_len_samples", buffer.len()),
-                                    ("chunk_samples", chunk.len()),
-                                    (
-                                        "chunk_audio_bytes",
-                                        chunk.len() * std::mem::size_of::<f32>(),
-                                    ),
-                                ],
-                            );
-                            let mut wm = whisper.lock().unwrap();
-                            let mut transcribe = |c: &[f32], sr| {
-                                wm.transcribe_chunk(c, sr).map_err(|e| e.to_string())
-                            };
-                            vad_gated_transcribe(
-                                &mut chunk,
-                                sample_rate,
-                                &vad,
-                                &mut transcribe,
-                                "Whisper",
-                                "🎙️",
-                                &app_clone,
-                                &session_transcript,
-                                denoise_enabled_thread,
-                                &denoiser_arc,
-                            );
-                        } else {
-                            crate::memory::maybe_log_process_memory_with_sizes(
-                                "recording cohere live chunk start",
-                                &[
-                                    ("buffer_len_samples", buffer.len()),
-                                    ("chunk_samples", chunk.len()),
-                                    (
-                                        "chunk_audio_bytes",
-                                        chunk.len() * std::mem::size_of::<f32>(),
-                                    ),
-                                ],
-                            );
-                            let mut gs = cohere.lock().unwrap();
-                            let mut transcribe = |c: &[f32], sr| {
-                                gs.transcribe_chunk(c, sr).map_err(|e| e.to_string())
-                            };
-                            vad_gated_transcribe(
-                                &mut chunk,
-                                sample_rate,
-                                &vad,
-                                &mut transcribe,
-                                "Cohere",
-                                "🪨",
-                                &app_clone,
-                                &session_transcript,
-                                denoise_enabled_thread,
-                                &denoiser_arc,
-                            );
-                        }
-                    }
-                }
-                ASREngine::Parakeet => {
-                    buffer.extend(samples);
-                    let parakeet_chunk_size =
-                        (sample_rate as f32 * PARAKEET_LIVE_CHUNK_SECS) as usize;
-                    let max_buffer_size = parakeet_chunk_size * 2;
-                    while buffer.len() >= parakeet_chunk_size {
-                        if buffer.len() > max_buffer_size {
-                            buffer.drain(..parakeet_chunk_size);
-                        }
-                        chunk.clear();
-                        chunk.extend_from_slice(&buffer[..parakeet_chunk_size]);
-                        buffer.drain(..parakeet_chunk_size);
-                        crate::memory::maybe_log_process_memory_with_sizes(
-                            "recording parakeet live chunk before preprocess",
-                            &[
-                                ("buffer_len_samples", buffer.len()),
-                                ("chunk_samples", chunk.len()),
-                                (
-                                    "chunk_audio_bytes",
-                                    chunk.len() * std::mem::size_of::<f32>(),
-                                ),
-                            ],
-                        );
-                        let buf16 = parakeet_preprocess_for_transcribe(
-                            &chunk,
-                            sample_rate,
-                            denoise_enabled_thread,
-                            &denoiser_arc,
-                        );
-                        crate::memory::maybe_log_process_memory_with_sizes(
-                            "recording parakeet live chunk after preprocess",
-                            &[
-                                ("chunk_samples", chunk.len()),
-                                ("buf16_samples", buf16.len()),
-                                (
-                                    "buf16_audio_bytes",
-                                    buf16.len() * std::mem::size_of::<f32>(),
-                                ),
-                            ],
-                        );
-                        let start_time = std::time::Instant::now();
-                        match parakeet_manager
-                            .lock()
-                            .unwrap()
-                            .transcribe_chunk(&buf16, 16000)
-                        {
-                            Ok(transcript) if !transcript.is_empty() => {
-                                let elapsed = start_time.elapsed().as_millis() as u32;
-                                println!(
-                                    "[TRANSCRIPT] 🦜 \"{}\" (took {}ms)",
-                                    transcript.trim(),
-                                    elapsed
-                                );
-                                let _ = app_clone.emit(
-                                    "transcription-chunk",
-                                    TranscriptionChunk {
-                                        text: transcript.clone(),
-                                        processing_time_ms: elapsed,
-                                        method: "Parakeet".to_string(),
-                                    },
-                                );
-                                session_transcript.lock().unwrap().push_str(&transcript);
-                            }
-                            Ok(_) => {}
-                            Err(e) => eprintln!("[ERROR] Parakeet error: {}", e),
-                        }
-                    }
-                }
+            buffer.extend(samples);
+            enforce_backlog_limit(
+                &mut buffer,
+                max_buffer_size,
+                chunk_size,
+                drop_policy,
+                &dropped_samples,
+            );
+            while let Some(cut) = next_live_cut(
+                &buffer,
+                sample_rate,
+                chunk_size,
+                min_chunk_size,
+                pause_aware,
+            ) {
+                chunk.clear();
+                chunk.extend_from_slice(&buffer[..cut]);
+                buffer.drain(..cut);
+                crate::memory::maybe_log_process_memory_with_sizes(
+                    "recording live chunk start",
+                    &[
+                        ("buffer_len_samples", buffer.len()),
+                        ("chunk_samples", chunk.len()),
+                        (
+                            "chunk_audio_bytes",
+                            chunk.len() * std::mem::size_of::<f32>(),
+                        ),
+                    ],
+                );
+                engines.with(active_engine, |t| {
+                    transcribe_live_chunk(t, &chunk, sample_rate, true, "", &sink)
+                });
             }
         }
 
@@ -537,91 +836,16 @@ fn start_recording_blocking(
             chunk.clear();
             chunk.extend_from_slice(&buffer[..chunk_size]);
             buffer.drain(..chunk_size);
-            match active_engine {
-                ASREngine::Whisper => {
-                    crate::memory::maybe_log_process_memory_with_sizes(
-                        "recording whisper final flush chunk",
-                        &[
-                            ("remaining_buffer_samples", buffer.len()),
-                            ("chunk_samples", chunk.len()),
-                        ],
-                    );
-                    let mut wm = whisper.lock().unwrap();
-                    let mut t =
-                        |c: &[f32], sr| wm.transcribe_chunk(c, sr).map_err(|e| e.to_string());
-                    vad_gated_transcribe(
-                        &mut chunk,
-                        sample_rate,
-                        &vad,
-                        &mut t,
-                        "Whisper",
-                        "🎙️",
-                        &app_clone,
-                        &session_transcript,
-                        denoise_enabled_thread,
-                        &denoiser_arc,
-                    );
-                }
-                ASREngine::Cohere => {
-                    crate::memory::maybe_log_process_memory_with_sizes(
-                        "recording cohere final flush chunk",
-                        &[
-                            ("remaining_buffer_samples", buffer.len()),
-                            ("chunk_samples", chunk.len()),
-                        ],
-                    );
-                    let mut gs = cohere.lock().unwrap();
-                    let mut t =
-                        |c: &[f32], sr| gs.transcribe_chunk(c, sr).map_err(|e| e.to_string());
-                    vad_gated_transcribe(
-                        &mut chunk,
-                        sample_rate,
-                        &vad,
-                        &mut t,
-                        "Cohere",
-                        "🪨",
-                        &app_clone,
-                        &session_transcript,
-                        denoise_enabled_thread,
-                        &denoiser_arc,
-                    );
-                }
-                ASREngine::Parakeet => {
-                    crate::memory::maybe_log_process_memory_with_sizes(
-                        "recording parakeet final flush chunk before preprocess",
-                        &[
-                            ("remaining_buffer_samples", buffer.len()),
-                            ("chunk_samples", chunk.len()),
-                        ],
-                    );
-                    let buf16 = parakeet_preprocess_for_transcribe(
-                        &chunk,
-                        sample_rate,
-                        denoise_enabled_thread,
-                        &denoiser_arc,
-                    );
-                    crate::memory::maybe_log_process_memory_with_sizes(
-                        "recording parakeet final flush chunk after preprocess",
-                        &[
-                            ("buf16_samples", buf16.len()),
-                            (
-                                "buf16_audio_bytes",
-                                buf16.len() * std::mem::size_of::<f32>(),
-                            ),
-                        ],
-                    );
-                    if let Ok(transcript) = parakeet_manager
-                        .lock()
-                        .unwrap()
-                        .transcribe_chunk(&buf16, 16000)
-                    {
-                        if !transcript.is_empty() {
-                            session_transcript.lock().unwrap().push_str(&transcript);
-                            println!("[TRANSCRIPT] 🦜 (Final) \"{}\"", transcript.trim());
-                        }
-                    }
-                }
-            }
+            crate::memory::maybe_log_process_memory_with_sizes(
+                "recording final flush chunk",
+                &[
+                    ("remaining_buffer_samples", buffer.len()),
+                    ("chunk_samples", chunk.len()),
+                ],
+            );
+            engines.with(active_engine, |t| {
+                transcribe_live_chunk(t, &chunk, sample_rate, true, "(Final) ", &sink)
+            });
         }
 
         // Flush the sub-chunk tail (< chunk_size but > 0.1s)
@@ -631,169 +855,247 @@ fn start_recording_blocking(
         if !buffer.is_empty() && buffer.len() as f32 / sample_rate as f32 > 0.1 {
             let tail_secs = buffer.len() as f32 / sample_rate as f32;
             let use_vad = tail_secs >= 3.0;
-            match active_engine {
-                ASREngine::Whisper => {
-                    let mut wm = whisper.lock().unwrap();
-                    if use_vad {
-                        let mut t =
-                            |c: &[f32], sr| wm.transcribe_chunk(c, sr).map_err(|e| e.to_string());
-                        vad_gated_transcribe(
-                            &mut buffer,
-                            sample_rate,
-                            &vad,
-                            &mut t,
-                            "Whisper",
-                            "🎙️",
-                            &app_clone,
-                            &session_transcript,
-                            denoise_enabled_thread,
-                            &denoiser_arc,
-                        );
-                    } else {
-                        println!(
-                            "[PROCESSING] 🎙️ Short tail ({:.2}s) — bypassing VAD for Whisper",
-                            tail_secs
-                        );
-                        let mut dg = denoiser_arc.lock().unwrap();
-                        let pcm16 = audio_preprocess::preprocess_live_transcribe_chunk(
-                            &buffer,
-                            sample_rate,
-                            denoise_enabled_thread,
-                            dg.as_mut(),
-                        );
-                        drop(dg);
-                        if let Ok(text) = wm.transcribe_chunk(&pcm16, 16000) {
-                            let text = strip_whitelisted_sound_captions(&text);
-                            if !text.trim().is_empty() {
-                                println!("[TRANSCRIPT] 🎙️ (Tail) \"{}\"", text.trim());
-                                let _ = app_clone.emit(
-                                    "transcription-chunk",
-                                    crate::types::TranscriptionChunk {
-                                        text: text.clone(),
-                                        processing_time_ms: 0,
-                                        method: "Whisper".to_string(),
-                                    },
-                                );
-                                session_transcript.lock().unwrap().push_str(&text);
-                            }
-                        }
-                    }
-                }
-                ASREngine::Cohere => {
-                    let mut gs = cohere.lock().unwrap();
-                    if use_vad {
-                        let mut t =
-                            |c: &[f32], sr| gs.transcribe_chunk(c, sr).map_err(|e| e.to_string());
-                        vad_gated_transcribe(
-                            &mut buffer,
-                            sample_rate,
-                            &vad,
-                            &mut t,
-                            "Cohere",
-                            "🪨",
-                            &app_clone,
-                            &session_transcript,
-                            denoise_enabled_thread,
-                            &denoiser_arc,
-                        );
-                    } else {
-                        println!(
-                            "[PROCESSING] 🪨 Short tail ({:.2}s) — bypassing VAD for Cohere",
-                            tail_secs
-                        );
-                        let mut dg = denoiser_arc.lock().unwrap();
-                        let pcm16 = audio_preprocess::preprocess_live_transcribe_chunk(
-                            &buffer,
-                            sample_rate,
-                            denoise_enabled_thread,
-                            dg.as_mut(),
-                        );
-                        drop(dg);
-                        if let Ok(text) = gs.transcribe_chunk(&pcm16, 16000) {
-                            let text = strip_whitelisted_sound_captions(&text);
-                            if !text.trim().is_empty() {
-                                println!("[TRANSCRIPT] 🪨 (Tail) \"{}\"", text.trim());
-                                let _ = app_clone.emit(
-                                    "transcription-chunk",
-                                    crate::types::TranscriptionChunk {
-                                        text: text.clone(),
-                                        processing_time_ms: 0,
-                                        method: "Cohere".to_string(),
-                                    },
-                                );
-                                session_transcript.lock().unwrap().push_str(&text);
-                            }
-                        }
-                    }
-                }
-                ASREngine::Parakeet => {
-                    crate::memory::maybe_log_process_memory_with_sizes(
-                        "recording parakeet tail before preprocess",
-                        &[("tail_buffer_samples", buffer.len())],
-                    );
-                    let buf16 = parakeet_preprocess_for_transcribe(
-                        &buffer,
-                        sample_rate,
-                        denoise_enabled_thread,
-                        &denoiser_arc,
-                    );
-                    crate::memory::maybe_log_process_memory_with_sizes(
-                        "recording parakeet tail after preprocess",
-                        &[
-                            ("tail_buffer_samples", buffer.len()),
-                            ("buf16_samples", buf16.len()),
-                            (
-                                "buf16_audio_bytes",
-                                buf16.len() * std::mem::size_of::<f32>(),
-                            ),
-                        ],
-                    );
-                    if let Ok(transcript) = parakeet_manager
-                        .lock()
-                        .unwrap()
-                        .transcribe_chunk(&buf16, 16000)
-                    {
-                        if !transcript.is_empty() {
-                            session_transcript.lock().unwrap().push_str(&transcript);
-                            println!("[TRANSCRIPT] 🦜 (Final Partial) \"{}\"", transcript.trim());
-                        }
-                    }
-                }
+            if !use_vad {
+                println!(
+                    "[PROCESSING] Short tail ({:.2}s) — bypassing VAD",
+                    tail_secs
+                );
             }
+            engines.with(active_engine, |t| {
+                transcribe_live_chunk(t, &buffer, sample_rate, use_vad, "(Tail) ", &sink)
+            });
         }
 
         println!("[INFO] Transcriber thread finished");
     });
 
-    let channels = config.channels as usize;
-
-    // Audio level metering: the cpal callback writes a float (as AtomicU32 bits)
-    // and a dedicated thread reads it every 50ms to emit the Tauri event.
+    // Audio level metering: the cpal callback keeps the loudest buffer level (as AtomicU32
+    // bits) and a dedicated thread takes and resets it every 50ms to emit the Tauri event,
+    // so short transients between emits still show up.
     // We do NOT call emit() from inside the cpal callback because on Windows
     // the WASAPI callback runs on a COM apartment thread where Tauri IPC fails.
     let audio_level = Arc::new(AtomicU32::new(0u32));
-    let audio_level_writer = audio_level.clone();
-    let level_counter = Arc::new(AtomicU32::new(0));
-    let level_counter_clone = level_counter.clone();
+    let capture = CaptureTaps {
+        config: config.clone(),
+        loopback: use_loopback,
+        file_tx: file_tx.clone(),
+        whisper_tx: whisper_tx.clone(),
+        whisper_rx: whisper_rx_for_drop,
+        drop_policy,
+        dropped_samples: dropped_samples.clone(),
+        audio_level: audio_level.clone(),
+        max_frames,
+        captured_frames,
+        limit_reached: limit_reached.clone(),
+        device_lost: device_lost.clone(),
+        device_lost_reported: device_lost_reported.clone(),
+    };
 
     let app_for_level = app_handle.clone();
+    let level_session_path = path.to_string_lossy().into_owned();
 
     let level_thread = std::thread::spawn(move || {
+        let mut auto_stop_sent = false;
         while !level_stop_clone3.load(Ordering::Relaxed) {
             std::thread::sleep(std::time::Duration::from_millis(50));
-            let bits = audio_level.load(Ordering::Relaxed);
+            let bits = audio_level.swap(0, Ordering::Relaxed);
             let level = f32::from_bits(bits);
             let _ = app_for_level.emit("audio-level", level);
+
+            if !auto_stop_sent && limit_reached.load(Ordering::Relaxed) {
+                auto_stop_sent = true;
+                let max_secs = max_recording_secs.unwrap_or(0);
+                println!(
+                    "[INFO] Recording reached the {}s limit — auto-stopping",
+                    max_secs
+                );
+                // Not awaited here: stop_recording joins this thread during teardown.
+                tauri::async_runtime::spawn(auto_stop_recording(
+                    app_for_level.clone(),
+                    level_session_path.clone(),
+                    max_secs,
+                ));
+            }
+
+            let lost = device_lost.lock().unwrap().clone();
+            if let Some(message) =
+                lost.filter(|_| !device_lost_reported.swap(true, Ordering::Relaxed))
+            {
+                eprintln!("[ERROR] Input device lost mid-recording: {}", message);
+                // Not awaited, for the same reason as the length limit above.
+                tauri::async_runtime::spawn(device_lost_stop_recording(
+                    app_for_level.clone(),
+                    level_session_path.clone(),
+                    message,
+                ));
+            }
+        }
+    });
+
+    let stream = build_capture_stream(&device, &config, capture.clone())?;
+
+    stream.play().map_err(|e| {
+        let msg = e.to_string();
+        if msg.contains("permission") || msg.contains("denied") {
+            "Microphone permission denied. Grant access in System Settings → Privacy & Security → Microphone.".to_string()
+        } else {
+            format!("Failed to start audio stream: {}", msg)
         }
+    })?;
+
+    *recording_handle_arc.lock().unwrap() = Some(RecordingHandle {
+        stream: SendStream(stream),
+        file_tx,
+        whisper_tx,
+        writer_thread,
+        transcriber_thread,
+        level_stop,
+        level_thread,
+        sample_rate,
+        capture,
     });
 
-    let app_for_error = app_handle.clone();
-    let stream = device
+    Ok(format!("Recording started: {}", path.display()))
+}
+
+/// What the cpal callbacks feed during a recording. Kept on the `RecordingHandle`
+/// so `switch_input_device` can rebuild the stream on another mic mid-session.
+#[derive(Clone)]
+pub struct CaptureTaps {
+    config: cpal::StreamConfig,
+    loopback: bool,
+    file_tx: Sender<Vec<f32>>,
+    whisper_tx: Sender<Vec<f32>>,
+    whisper_rx: Receiver<Vec<f32>>,
+    drop_policy: BufferDropPolicy,
+    dropped_samples: Arc<AtomicU64>,
+    audio_level: Arc<AtomicU32>,
+    max_frames: Option<u64>,
+    captured_frames: Arc<AtomicU64>,
+    limit_reached: Arc<AtomicBool>,
+    device_lost: Arc<Mutex<Option<String>>>,
+    device_lost_reported: Arc<AtomicBool>,
+}
+
+/// Converts a switched-in mic's audio to the session's channel count and sample
+/// rate (downmix, linear resample, duplicate to every channel), so the WAV writer
+/// and transcriber keep receiving the format the recording started with.
+struct SessionFormatAdapter {
+    in_channels: usize,
+    out_channels: usize,
+    /// Input frames per output frame.
+    step: f64,
+    /// Position of the next output frame; index 0 is `prev`, 1.. the current buffer.
+    pos: f64,
+    /// Last mono sample of the previous buffer, so interpolation spans callbacks.
+    prev: f32,
+}
+
+impl SessionFormatAdapter {
+    /// `None` when the device already delivers the session format.
+    fn new(device: &cpal::StreamConfig, session: &cpal::StreamConfig) -> Option<Self> {
+        if device.channels == session.channels && device.sample_rate == session.sample_rate {
+            return None;
+        }
+        Some(Self {
+            in_channels: device.channels.max(1) as usize,
+            out_channels: session.channels.max(1) as usize,
+            step: device.sample_rate.0 as f64 / session.sample_rate.0 as f64,
+            pos: 0.0,
+            prev: 0.0,
+        })
+    }
+
+    fn convert(&mut self, data: &[f32]) -> Vec<f32> {
+        let mono = audio_preprocess::downmix_to_mono(data, self.in_channels);
+        let prev = self.prev;
+        let sample = |i: usize| if i == 0 { prev } else { mono[i - 1] };
+        let mut out = Vec::with_capacity(
+            (mono.len() as f64 / self.step) as usize * self.out_channels + self.out_channels,
+        );
+        while self.pos < mono.len() as f64 {
+            let i = self.pos as usize;
+            let frac = (self.pos - i as f64) as f32;
+            let (a, b) = (sample(i), sample(i + 1));
+            let value = a + (b - a) * frac;
+            out.extend(std::iter::repeat(value).take(self.out_channels));
+            self.pos += self.step;
+        }
+        self.pos -= mono.len() as f64;
+        if let Some(&last) = mono.last() {
+            self.prev = last;
+        }
+        out
+    }
+}
+
+/// Pick an f32 capture config for a mic switched in mid-session, preferring the
+/// session's own rate and channel count so no conversion is needed.
+fn switch_capture_config(
+    device: &cpal::Device,
+    session: &cpal::StreamConfig,
+) -> Option<cpal::StreamConfig> {
+    let configs: Vec<_> = device
+        .supported_input_configs()
+        .ok()?
+        .filter(|c| c.sample_format() == cpal::SampleFormat::F32)
+        .collect();
+    let has_rate = |c: &&cpal::SupportedStreamConfigRange| {
+        c.min_sample_rate() <= session.sample_rate && session.sample_rate <= c.max_sample_rate()
+    };
+    if let Some(c) = configs
+        .iter()
+        .filter(has_rate)
+        .find(|c| c.channels() == session.channels)
+        .or_else(|| configs.iter().find(has_rate))
+    {
+        return Some(c.clone().with_sample_rate(session.sample_rate).config());
+    }
+    match device.default_input_config() {
+        Ok(c) if c.sample_format() == cpal::SampleFormat::F32 => Some(c.config()),
+        _ => configs
+            .first()
+            .map(|c| c.clone().with_max_sample_rate().config()),
+    }
+}
+
+/// Open a (not yet playing) capture stream on `device` at `config` that feeds
+/// `taps`, converting to the session format when the two differ.
+fn build_capture_stream(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    taps: CaptureTaps,
+) -> Result<cpal::Stream, String> {
+    let channels = taps.config.channels as usize;
+    let device_lost = taps.device_lost.clone();
+    let mut adapter = SessionFormatAdapter::new(config, &taps.config);
+    device
         .build_input_stream(
-            &config,
+            config,
             move |data: &[f32], _: &_| {
+                let converted;
+                let data = match adapter.as_mut() {
+                    Some(adapter) => {
+                        converted = adapter.convert(data);
+                        &converted[..]
+                    }
+                    None => data,
+                };
+
+                // Past the duration limit, drop everything until the stop arrives.
+                if let Some(max) = taps.max_frames {
+                    if taps.limit_reached.load(Ordering::Relaxed) {
+                        return;
+                    }
+                    let frames = (data.len() / channels) as u64;
+                    if taps.captured_frames.fetch_add(frames, Ordering::Relaxed) + frames > max {
+                        taps.limit_reached.store(true, Ordering::Relaxed);
+                        return;
+                    }
+                }
+
                 // File writer always gets raw (unprocessed) audio
-                file_tx_clone.try_send(data.to_vec()).ok();
+                taps.file_tx.try_send(data.to_vec()).ok();
 
                 let mono_data: Vec<f32> = if channels > 1 {
                     data.chunks(channels)
@@ -805,59 +1107,90 @@ fn start_recording_blocking(
 
                 // RNNoise + universal chain run in the transcriber thread (48 kHz → 16 kHz order).
 
-                // Store audio level in atomic for the emitter thread to pick up.
-                // Only compute every ~5 callbacks to avoid unnecessary work.
-                let cnt = level_counter_clone.fetch_add(1, Ordering::Relaxed);
-                if cnt % 5 == 0 && !data.is_empty() {
+                // Level of the raw (pre-denoise) buffer for the emitter thread. Non-negative
+                // f32 bits order like the floats, so fetch_max keeps the loudest buffer.
+                if !data.is_empty() {
                     let rms = (data.iter().map(|&s| s * s).sum::<f32>() / data.len() as f32).sqrt();
                     let level = (rms / 0.015_f32).min(1.0_f32).sqrt();
-                    audio_level_writer.store(level.to_bits(), Ordering::Relaxed);
+                    taps.audio_level.fetch_max(level.to_bits(), Ordering::Relaxed);
                 }
 
-                whisper_tx_clone.try_send(mono_data).ok();
+                send_live_samples(
+                    &taps.whisper_tx,
+                    &taps.whisper_rx,
+                    mono_data,
+                    taps.drop_policy,
+                    &taps.dropped_samples,
+                );
             },
             move |err| {
                 eprintln!("[ERROR] Audio input stream error: {}", err);
-                let _ = app_for_error.emit(
-                    "audio-disconnected",
-                    serde_json::json!({
-                        "code": "audio_device_disconnected",
-                        "message": err.to_string(),
-                    }),
-                );
+                // Report only the first error; a dead device keeps failing until teardown.
+                let mut lost = device_lost.lock().unwrap();
+                if lost.is_none() {
+                    *lost = Some(err.to_string());
+                }
             },
             None,
         )
         .map_err(|e| {
             let msg = e.to_string();
             if msg.contains("permission") || msg.contains("denied") {
-                "Microphone permission denied. Grant access in System Settings → Privacy & Security → Microphone.".to_string()
-            } else {
-                format!("Failed to open audio stream: {}", msg)
-            }
-        })?;
-
-    stream.play().map_err(|e| {
-        let msg = e.to_string();
-        if msg.contains("permission") || msg.contains("denied") {
-            "Microphone permission denied. Grant access in System Settings → Privacy & Security → Microphone.".to_string()
-        } else {
-            format!("Failed to start audio stream: {}", msg)
-        }
-    })?;
-
-    *recording_handle_arc.lock().unwrap() = Some(RecordingHandle {
-        stream: SendStream(stream),
-        file_tx,
-        whisper_tx,
-        writer_thread,
-        transcriber_thread,
-        level_stop,
-        level_thread,
-        sample_rate,
-    });
+                "Microphone permission denied. Grant access in System Settings → Privacy & Security → Microphone.".to_string()
+            } else {
+                format!("Failed to open audio stream: {}", msg)
+            }
+        })
+}
 
-    Ok(format!("Recording started: {}", path.display()))
+/// `recording_<ts>.wav` → `recording_<ts>_denoised.wav`, next to the raw recording.
+fn denoised_recording_path(path: &std::path::Path) -> std::path::PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{}_denoised.wav", stem))
+}
+
+/// Stop an active recording without transcribing it so the WAV is finalized and
+/// the mic released before the process exits, then discard the last recording
+/// unless recordings are kept.
+pub fn stop_recording_for_exit(app: &AppHandle, state: &AudioState) {
+    let recording = state
+        .recording_handle
+        .lock()
+        .ok()
+        .and_then(|mut h| h.take());
+    if let Some(recording) = recording {
+        println!("[EXIT] Stopping active recording before exit");
+        state.recording_paused.store(false, Ordering::Relaxed);
+        state.hotkey_recording_active.store(false, Ordering::SeqCst);
+        teardown_recording(recording, 0);
+    }
+    if let Some(path) = state.last_recording_path.lock().unwrap().take() {
+        discard_recording(&path, state.keep_recordings.load(Ordering::Relaxed));
+    }
+    super::persist_setting(app, UNKEPT_RECORDING_KEY, None);
+}
+
+/// Delete a recording (and its `_denoised` sibling) left behind by a session that
+/// exited without discarding it (crash, forced quit). Called once during setup.
+pub fn sweep_unkept_recording(app: &AppHandle) {
+    use tauri_plugin_store::StoreExt;
+
+    let Some(path) = app
+        .store(super::SETTINGS_STORE)
+        .ok()
+        .and_then(|store| store.get(UNKEPT_RECORDING_KEY))
+        .and_then(|v| v.as_str().map(str::to_string))
+    else {
+        return;
+    };
+    let denoised = denoised_recording_path(std::path::Path::new(&path));
+    if std::fs::remove_file(&denoised).is_ok() {
+        println!("[INFO] Removed stale recording: {}", denoised.display());
+    }
+    if std::fs::remove_file(&path).is_ok() {
+        println!("[INFO] Removed stale recording: {}", path);
+    }
+    super::persist_setting(app, UNKEPT_RECORDING_KEY, None);
 }
 
 fn teardown_recording(recording: RecordingHandle, tail_capture_ms: u64) {
@@ -871,6 +1204,7 @@ fn teardown_recording(recording: RecordingHandle, tail_capture_ms: u64) {
         transcriber_thread,
         level_stop,
         level_thread,
+        capture,
         ..
     } = recording;
 
@@ -880,6 +1214,9 @@ fn teardown_recording(recording: RecordingHandle, tail_capture_ms: u64) {
 
     let _ = stream.0.pause();
     drop(stream);
+    // The taps hold sender clones; the worker threads only see a disconnect once
+    // these are gone too.
+    drop(capture);
     drop(file_tx);
     drop(whisper_tx);
 
@@ -898,8 +1235,13 @@ fn teardown_recording(recording: RecordingHandle, tail_capture_ms: u64) {
     println!("[INFO] Worker threads finished.");
 }
 
+/// Stop the mic stream without tearing down the session: the writer and transcriber
+/// threads stay up (they just receive nothing) and the WAV file stays open.
 #[tauri::command]
-pub fn pause_recording(state: State<'_, AudioState>) -> Result<CommandResult<String>, String> {
+pub fn pause_recording(
+    app: AppHandle,
+    state: State<'_, AudioState>,
+) -> Result<CommandResult<String>, String> {
     let guard = state.recording_handle.lock().unwrap();
     let Some(handle) = guard.as_ref() else {
         return Ok(CommandResult::err("not_recording", "Not recording"));
@@ -911,11 +1253,16 @@ pub fn pause_recording(state: State<'_, AudioState>) -> Result<CommandResult<Str
         .pause()
         .map_err(|e| format!("Failed to pause recording: {}", e))?;
     state.recording_paused.store(true, Ordering::Relaxed);
+    *state.current_app_state.lock().unwrap() = AppState::Paused;
+    let _ = crate::tray::update_tray_icon(&app, AppState::Paused);
     Ok(CommandResult::ok("Recording paused".to_string()))
 }
 
 #[tauri::command]
-pub fn resume_recording(state: State<'_, AudioState>) -> Result<CommandResult<String>, String> {
+pub fn resume_recording(
+    app: AppHandle,
+    state: State<'_, AudioState>,
+) -> Result<CommandResult<String>, String> {
     let guard = state.recording_handle.lock().unwrap();
     let Some(handle) = guard.as_ref() else {
         return Ok(CommandResult::err("not_recording", "Not recording"));
@@ -927,13 +1274,100 @@ pub fn resume_recording(state: State<'_, AudioState>) -> Result<CommandResult<St
         .play()
         .map_err(|e| format!("Failed to resume recording: {}", e))?;
     state.recording_paused.store(false, Ordering::Relaxed);
+    *state.current_app_state.lock().unwrap() = AppState::Recording;
+    let _ = crate::tray::update_tray_icon(&app, AppState::Recording);
     Ok(CommandResult::ok("Recording resumed".to_string()))
 }
 
+/// COMMAND: Move an active recording to another input device (e.g. a Bluetooth
+/// headset that just connected). Only the cpal stream is rebuilt; the WAV writer,
+/// transcriber and session transcript carry on. The open WAV can't change format, so
+/// a device with another rate or channel count is converted to the session's.
+#[tauri::command]
+pub async fn switch_input_device(
+    state: State<'_, AudioState>,
+    name: String,
+) -> Result<CommandResult<String>, String> {
+    let recording_handle = state.recording_handle.clone();
+    let paused = state.recording_paused.load(Ordering::Relaxed);
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut guard = recording_handle.lock().unwrap();
+        let Some(handle) = guard.as_mut() else {
+            return CommandResult::err("not_recording", "Not recording");
+        };
+        if handle.capture.loopback {
+            return CommandResult::err(
+                "loopback_active",
+                "Can't switch microphones while capturing system audio",
+            );
+        }
+
+        let host = cpal::default_host();
+        let Some(device) = host.input_devices().ok().and_then(|mut devices| {
+            devices.find(|d| d.name().ok().as_deref() == Some(name.as_str()))
+        }) else {
+            return CommandResult::err(
+                "device_not_found",
+                format!("Input device '{}' not found", name),
+            );
+        };
+
+        // The callback takes f32; the device's own rate and channel count are
+        // converted to the session format in the callback.
+        let Some(config) = switch_capture_config(&device, &handle.capture.config) else {
+            return CommandResult::err(
+                "device_config_unsupported",
+                format!("'{}' doesn't offer 32-bit float capture", name),
+            );
+        };
+        if config.channels != handle.capture.config.channels
+            || config.sample_rate != handle.capture.config.sample_rate
+        {
+            println!(
+                "[INFO] Converting {} Hz / {} ch from '{}' to the session's {} Hz / {} ch",
+                config.sample_rate.0,
+                config.channels,
+                name,
+                handle.capture.config.sample_rate.0,
+                handle.capture.config.channels
+            );
+        }
+
+        // Build the new stream before touching the old one, so a failure leaves
+        // the session recording on the current device.
+        let stream = match build_capture_stream(&device, &config, handle.capture.clone()) {
+            Ok(stream) => stream,
+            Err(e) => return CommandResult::err("recording_start_failed", e),
+        };
+        if !paused {
+            if let Err(e) = stream.play() {
+                return CommandResult::err(
+                    "recording_start_failed",
+                    format!("Failed to start audio stream: {}", e),
+                );
+            }
+        }
+
+        let _ = handle.stream.0.pause();
+        handle.stream = SendStream(stream);
+        // A lost-device error from the old stream no longer applies.
+        *handle.capture.device_lost.lock().unwrap() = None;
+        handle
+            .capture
+            .device_lost_reported
+            .store(false, Ordering::Relaxed);
+        println!("[INFO] Switched recording input to: {}", name);
+        CommandResult::ok(format!("Recording from {}", name))
+    })
+    .await
+    .map_err(|e| format!("switch_input_device task failed: {}", e))
+}
+
 #[tauri::command]
 pub async fn cancel_recording(state: State<'_, AudioState>) -> Result<CommandResult<()>, String> {
     *state.denoiser.lock().unwrap() = None;
     state.recording_paused.store(false, Ordering::Relaxed);
+    state.hotkey_recording_active.store(false, Ordering::SeqCst);
 
     let Some(recording) = state.recording_handle.lock().unwrap().take() else {
         return Ok(CommandResult::err("not_recording", "Not recording"));
@@ -945,7 +1379,7 @@ pub async fn cancel_recording(state: State<'_, AudioState>) -> Result<CommandRes
         teardown_recording(recording, 0);
         session_transcript.lock().unwrap().clear();
         if let Some(path) = last_recording_path {
-            let _ = std::fs::remove_file(path);
+            discard_recording(&path, false);
         }
         Ok::<CommandResult<()>, String>(CommandResult::ok(()))
     })
@@ -953,35 +1387,160 @@ pub async fn cancel_recording(state: State<'_, AudioState>) -> Result<CommandRes
     .map_err(|e| format!("cancel_recording task failed: {}", e))?
 }
 
+/// Report whether a recording is active and how much live audio has been dropped
+/// by the buffer-drop policy so far.
+#[tauri::command]
+pub fn get_recording_status(state: State<'_, AudioState>) -> RecordingStatus {
+    let sample_rate = state
+        .recording_handle
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|handle| handle.sample_rate);
+    RecordingStatus {
+        recording: sample_rate.is_some(),
+        paused: state.recording_paused.load(Ordering::Relaxed),
+        sample_rate,
+        dropped_samples: state.dropped_samples.load(Ordering::Relaxed),
+        buffer_drop_policy: *state.buffer_drop_policy.lock().unwrap(),
+    }
+}
+
+/// Return the last `n` live transcription chunks (oldest first) from the current
+/// or most recent recording session. Omit `n` to get everything buffered.
+#[tauri::command]
+pub fn get_recent_chunks(
+    state: State<'_, AudioState>,
+    n: Option<usize>,
+) -> Vec<TranscriptionChunk> {
+    let recent = state.recent_chunks.lock().unwrap();
+    let skip = n.map_or(0, |n| recent.len().saturating_sub(n));
+    recent.iter().skip(skip).cloned().collect()
+}
+
 /// COMMAND: Insert text into the focused application.
 /// macOS:         AXUIElement (kAXSelectedTextAttribute) — inserts at cursor, no clipboard touch
 ///                → fallback: clipboard + Cmd+V
 /// Windows/Linux: clipboard save → set text → Ctrl+V → restore clipboard
+/// Linux/Wayland: wtype / ydotool at the caret → fallback: clipboard + Ctrl+V
+/// `typing_mode` can force clipboard paste or per-character typing on any OS.
 /// Returns Err with a short error code on failure so the frontend can show
 /// a "couldn't paste" indicator without silently dropping the transcript.
 #[tauri::command]
-pub async fn type_text(text: String) -> Result<CommandResult<()>, String> {
+pub async fn type_text(
+    app: AppHandle,
+    state: State<'_, AudioState>,
+    text: String,
+) -> Result<CommandResult<()>, String> {
     if text.trim().is_empty() || text.trim() == "[silence]" {
         return Ok(CommandResult::ok(()));
     }
     let text_to_type = text.trim().to_string();
-    tauri::async_runtime::spawn_blocking(move || insert_text(&text_to_type))
+    let method = *state.typing_mode.lock().unwrap();
+    let timing = PasteTiming::from_state(&state);
+    tauri::async_runtime::spawn_blocking(move || insert_text(&text_to_type, method, timing))
         .await
-        .map(|result| match result {
-            Ok(()) => CommandResult::ok(()),
-            Err(message) => {
-                let code = match message.as_str() {
-                    "secure_input" => "paste_blocked_secure_input",
-                    "console" => "paste_blocked_console",
-                    _ => "paste_failed",
-                };
-                CommandResult::err(code, message)
+        .map(|result| {
+            if matches!(&result, Err(e) if e == "accessibility_denied") {
+                let _ = app.emit(
+                    "accessibility-permission-needed",
+                    ACCESSIBILITY_GUIDANCE.to_string(),
+                );
             }
+            insertion_result(result)
         })
         .map_err(|e| format!("thread_panic:{e:?}"))
 }
 
-fn insert_text(text: &str) -> Result<(), String> {
+/// COMMAND: Put `text` on the system clipboard without pasting it anywhere.
+#[tauri::command]
+pub async fn copy_to_clipboard(text: String) -> Result<CommandResult<()>, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let result = arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_text(text.trim()))
+            .map_err(|e| format!("clipboard_set:{e}"));
+        if let Err(e) = &result {
+            eprintln!("[INSERT] Failed to copy transcript to clipboard: {}", e);
+        }
+        insertion_result(result)
+    })
+    .await
+    .map_err(|e| format!("thread_panic:{e:?}"))
+}
+
+/// Shown when macOS blocks text insertion because the app isn't trusted for Accessibility.
+const ACCESSIBILITY_GUIDANCE: &str =
+    "Taurscribe needs Accessibility access to type into other apps. \
+Open System Settings → Privacy & Security → Accessibility, enable Taurscribe, then try again.";
+
+/// COMMAND: Type a known sample into whatever app is focused after `delay_ms`
+/// (default 3 s, time to click into an editor), using the same path as `type_text`.
+/// Lets users tell an insertion/permission problem apart from a transcription one.
+#[tauri::command]
+pub async fn test_insertion(
+    state: State<'_, AudioState>,
+    sample: String,
+    delay_ms: Option<u64>,
+) -> Result<CommandResult<()>, String> {
+    // Always true off macOS.
+    if !super::check_accessibility_permission() {
+        return Ok(CommandResult::err(
+            "accessibility_denied",
+            ACCESSIBILITY_GUIDANCE,
+        ));
+    }
+
+    let sample = if sample.trim().is_empty() {
+        "Taurscribe insertion test".to_string()
+    } else {
+        sample.trim().to_string()
+    };
+    let delay = std::time::Duration::from_millis(delay_ms.unwrap_or(3000));
+    let method = *state.typing_mode.lock().unwrap();
+    let timing = PasteTiming::from_state(&state);
+    tauri::async_runtime::spawn_blocking(move || {
+        std::thread::sleep(delay);
+        println!("[INSERT] Test insertion of {} chars", sample.len());
+        insert_text(&sample, method, timing)
+    })
+    .await
+    .map(insertion_result)
+    .map_err(|e| format!("thread_panic:{e:?}"))
+}
+
+/// Waits around the clipboard swap in `clipboard_paste`, from `paste_*_delay_ms`.
+#[derive(Clone, Copy)]
+struct PasteTiming {
+    set_ms: u64,
+    restore_ms: u64,
+}
+
+impl PasteTiming {
+    fn from_state(state: &AudioState) -> Self {
+        Self {
+            set_ms: state.paste_set_delay_ms.load(Ordering::Relaxed) as u64,
+            restore_ms: state.paste_delay_ms.load(Ordering::Relaxed) as u64,
+        }
+    }
+}
+
+/// Map an `insert_text` failure to the error codes the frontend understands.
+fn insertion_result(result: Result<(), String>) -> CommandResult<()> {
+    match result {
+        Ok(()) => CommandResult::ok(()),
+        Err(message) => {
+            let code = match message.as_str() {
+                "secure_input" => "paste_blocked_secure_input",
+                "console" => "paste_blocked_console",
+                "accessibility_denied" => "accessibility_denied",
+                _ => "paste_failed",
+            };
+            CommandResult::err(code, message)
+        }
+    }
+}
+
+fn insert_text(text: &str, method: TypingMode, timing: PasteTiming) -> Result<(), String> {
     #[cfg(target_os = "macos")]
     {
         // Bail early if the OS has locked keyboard injection (e.g. a password
@@ -992,9 +1551,22 @@ fn insert_text(text: &str) -> Result<(), String> {
             return Err("secure_input".to_string());
         }
 
+        // Without Accessibility trust both AXUIElement and CGEventPost are silently
+        // dropped, so the clipboard fallback would "succeed" while pasting nothing.
+        if !super::check_accessibility_permission() {
+            eprintln!("[INSERT] Accessibility permission not granted — cannot insert text");
+            return Err("accessibility_denied".to_string());
+        }
+
+        match method {
+            TypingMode::ClipboardPaste => return clipboard_paste(text, timing),
+            TypingMode::KeystrokeByKeystroke => return type_keystrokes(text),
+            TypingMode::Auto => {}
+        }
+
         if should_prefer_clipboard_paste() {
             println!("[INSERT] Browser/web app detected — using clipboard+Cmd+V directly");
-            return clipboard_paste(text);
+            return clipboard_paste(text, timing);
         }
 
         // macOS fix: After the hotkey is released, the OS needs a moment to
@@ -1013,7 +1585,117 @@ fn insert_text(text: &str) -> Result<(), String> {
         }
         eprintln!("[INSERT] AXUIElement failed after 3 attempts, falling back to clipboard+Cmd+V");
     }
-    clipboard_paste(text)
+    #[cfg(not(target_os = "macos"))]
+    if method == TypingMode::KeystrokeByKeystroke {
+        return type_keystrokes(text);
+    }
+    #[cfg(target_os = "linux")]
+    if method == TypingMode::Auto && wayland_type(text) {
+        return Ok(());
+    }
+    clipboard_paste(text, timing)
+}
+
+/// Linux/Wayland: type at the caret with `wtype` (wlroots compositors) or
+/// `ydotool` (uinput, any compositor) so the clipboard is left alone. Synthetic
+/// X11 Ctrl+V from enigo often never reaches native Wayland windows.
+/// Returns false when not on Wayland or neither tool is installed/working.
+#[cfg(target_os = "linux")]
+fn wayland_type(text: &str) -> bool {
+    if std::env::var_os("WAYLAND_DISPLAY").is_none() {
+        return false;
+    }
+    for (tool, args) in [("wtype", &["--"][..]), ("ydotool", &["type", "--"][..])] {
+        match std::process::Command::new(tool)
+            .args(args)
+            .arg(text)
+            .status()
+        {
+            Ok(status) if status.success() => {
+                println!(
+                    "[INSERT] Typed {} chars with {}",
+                    text.chars().count(),
+                    tool
+                );
+                return true;
+            }
+            Ok(status) => eprintln!("[INSERT] {} exited with {}", tool, status),
+            Err(_) => {} // not installed
+        }
+    }
+    eprintln!("[INSERT] No working wtype/ydotool, falling back to clipboard+Ctrl+V");
+    false
+}
+
+/// Type `text` one character at a time as `Key::Unicode` events so the clipboard
+/// is never touched. Slower than a paste for long transcripts, but works in apps
+/// that intercept or disable paste and is invisible to clipboard managers.
+#[cfg(not(target_os = "macos"))]
+fn type_keystrokes(text: &str) -> Result<(), String> {
+    use enigo::{Direction, Enigo, Key, Keyboard, Settings};
+    let mut enigo = match Enigo::new(&Settings::default()) {
+        Ok(e) => e,
+        Err(e) => {
+            eprintln!("[INSERT] Enigo init failed: {:?}", e);
+            return Err(format!("enigo_init:{e:?}"));
+        }
+    };
+    for c in text.chars() {
+        let key = match c {
+            '\r' => continue,
+            '\n' => Key::Return,
+            '\t' => Key::Tab,
+            c => Key::Unicode(c),
+        };
+        enigo.key(key, Direction::Click).map_err(|e| {
+            eprintln!("[INSERT] Keystroke typing failed at {:?}: {:?}", c, e);
+            format!("keystroke_type:{e:?}")
+        })?;
+    }
+    println!(
+        "[INSERT] Typed {} chars keystroke by keystroke",
+        text.chars().count()
+    );
+    Ok(())
+}
+
+/// macOS: per-character typing via CGEvent unicode strings (enigo isn't safe off
+/// the main thread here, see `simulate_cmd_v_cgevent`).
+#[cfg(target_os = "macos")]
+fn type_keystrokes(text: &str) -> Result<(), String> {
+    use core_graphics::event::{CGEvent, CGEventTapLocation, CGKeyCode};
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+
+    // kVK_Return / kVK_Tab; other characters ride on a placeholder key code.
+    const VK_RETURN: CGKeyCode = 0x24;
+    const VK_TAB: CGKeyCode = 0x30;
+
+    let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState).map_err(|_| {
+        eprintln!("[INSERT] CGEventSource creation failed");
+        "cgevent_source".to_string()
+    })?;
+    let mut buf = [0u8; 4];
+    for c in text.chars() {
+        let (key_code, unicode) = match c {
+            '\r' => continue,
+            '\n' => (VK_RETURN, None),
+            '\t' => (VK_TAB, None),
+            c => (0, Some(&*c.encode_utf8(&mut buf))),
+        };
+        for key_down in [true, false] {
+            let event = CGEvent::new_keyboard_event(source.clone(), key_code, key_down)
+                .map_err(|_| "cgevent_create".to_string())?;
+            if let Some(s) = unicode {
+                event.set_string(s);
+            }
+            event.post(CGEventTapLocation::AnnotatedSession);
+        }
+    }
+    println!(
+        "[INSERT] Typed {} chars keystroke by keystroke",
+        text.chars().count()
+    );
+    Ok(())
 }
 
 /// Returns true when the frontmost application is a browser, terminal, or Electron
@@ -1150,7 +1832,7 @@ fn should_prefer_clipboard_paste() -> bool {
 
 /// Clipboard + simulated paste keystroke (Cmd+V on macOS, Ctrl+V elsewhere).
 /// Saves and restores the previous clipboard content.
-fn clipboard_paste(text: &str) -> Result<(), String> {
+fn clipboard_paste(text: &str, timing: PasteTiming) -> Result<(), String> {
     use arboard::Clipboard;
 
     // Windows: classic cmd.exe console windows use a different paste path
@@ -1194,7 +1876,7 @@ fn clipboard_paste(text: &str) -> Result<(), String> {
     // Give the pasteboard server (pbs) time to propagate the write to other
     // processes. 10 ms was too tight for heavy apps (Word, Excel, Outlook)
     // that validate the pasteboard change count before reading on Cmd+V.
-    std::thread::sleep(std::time::Duration::from_millis(50));
+    std::thread::sleep(std::time::Duration::from_millis(timing.set_ms));
 
     #[cfg(target_os = "macos")]
     {
@@ -1229,8 +1911,9 @@ fn clipboard_paste(text: &str) -> Result<(), String> {
 
     // Wait for the target app to finish reading the clipboard before restoring.
     // 150 ms was too short for heavy apps (Word, LibreOffice) that process
-    // paste asynchronously through their own undo/format pipeline.
-    std::thread::sleep(std::time::Duration::from_millis(300));
+    // paste asynchronously through their own undo/format pipeline; remote
+    // desktops can need more, hence `paste_delay_ms`.
+    std::thread::sleep(std::time::Duration::from_millis(timing.restore_ms));
     match previous {
         SavedClipboard::Text(t) => {
             let _ = clipboard.set_text(t);
@@ -1423,22 +2106,264 @@ fn get_foreground_window_issue() -> Option<String> {
     }
 }
 
+/// Payload for the "final-pass-progress" event (per-segment Whisper final pass).
+#[derive(Clone, serde::Serialize)]
+pub struct FinalPassProgress {
+    pub segment: usize,
+    pub total_segments: usize,
+}
+
+/// Whisper needs at least ~1 s of input; shorter VAD segments are zero-padded.
+const MIN_SEGMENT_SAMPLES: usize = 16000;
+
+/// VAD segments longer than this are split so each stays inside Whisper's 30 s window.
+const MAX_SEGMENT_SECS: f32 = 25.0;
+/// Overlap between split sub-segments so words at the cut aren't lost.
+const SEGMENT_OVERLAP_SECS: f32 = 1.0;
+
+/// Drop the leading words of `next` that repeat the tail of `prev` — the audio
+/// overlap between split sub-segments is usually transcribed twice.
+fn strip_overlap_words(prev: &str, next: &str) -> String {
+    let normalize = |w: &str| {
+        w.trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase()
+    };
+    let prev_words: Vec<String> = prev.split_whitespace().map(normalize).collect();
+    let next_words: Vec<&str> = next.split_whitespace().collect();
+    let max_overlap = prev_words.len().min(next_words.len()).min(8);
+    for n in (1..=max_overlap).rev() {
+        let tail = &prev_words[prev_words.len() - n..];
+        if next_words[..n]
+            .iter()
+            .map(|w| normalize(w))
+            .eq(tail.iter().cloned())
+        {
+            return next_words[n..].join(" ");
+        }
+    }
+    next.to_string()
+}
+
+/// Transcribe each VAD segment on its own and rejoin the texts with single spaces,
+/// so pauses between sentences survive as boundaries. Each segment is prompted with
+/// the previous segment's text (or the active-app context for the first one).
+fn transcribe_segments(
+    whisper_arc: &Arc<std::sync::Mutex<crate::whisper::WhisperManager>>,
+    audio_data: &[f32],
+    timestamps: &[(f32, f32)],
+    app_context: Option<&str>,
+    app: &AppHandle,
+) -> Result<String, String> {
+    let total_segments = timestamps.len();
+    let mut texts: Vec<String> = Vec::with_capacity(total_segments);
+    let mut prev_end = 0.0_f32;
+    for (i, &(start, end)) in timestamps.iter().enumerate() {
+        let s = ((start * 16000.0) as usize).min(audio_data.len());
+        let e = ((end * 16000.0) as usize).min(audio_data.len());
+        let mut segment = audio_data[s..e].to_vec();
+        if segment.len() < MIN_SEGMENT_SAMPLES {
+            segment.resize(MIN_SEGMENT_SAMPLES, 0.0);
+        }
+
+        let prompt = texts.last().map(String::as_str).or(app_context);
+        let text = whisper_arc
+            .lock()
+            .unwrap()
+            .transcribe_audio_data(&segment, prompt)?;
+        println!(
+            "[PROCESSING] Segment {}/{} ({:.2}s–{:.2}s): \"{}\"",
+            i + 1,
+            total_segments,
+            start,
+            end,
+            text
+        );
+        let text = match texts.last() {
+            Some(prev) if start < prev_end => strip_overlap_words(prev, text.trim()),
+            _ => text.trim().to_string(),
+        };
+        prev_end = end;
+        if !text.is_empty() {
+            texts.push(text);
+        }
+        let _ = app.emit(
+            "final-pass-progress",
+            FinalPassProgress {
+                segment: i + 1,
+                total_segments,
+            },
+        );
+    }
+    Ok(texts.join(" "))
+}
+
+/// Snapshot of the user settings that shape what `stop_recording` does after teardown.
+#[derive(Clone, Copy)]
+struct StopSettings {
+    per_segment: bool,
+    final_pass_vad: bool,
+    parakeet_final_pass: bool,
+    merge_gap_ms: u32,
+    trim_silence: bool,
+    keep_recording: bool,
+}
+
+impl StopSettings {
+    fn from_state(state: &AudioState) -> Self {
+        Self {
+            per_segment: state.final_pass_per_segment.load(Ordering::Relaxed),
+            final_pass_vad: state.final_pass_vad.load(Ordering::Relaxed),
+            parakeet_final_pass: state.parakeet_final_pass.load(Ordering::Relaxed),
+            merge_gap_ms: state.vad_merge_gap_ms.load(Ordering::Relaxed),
+            trim_silence: state.trim_recording_silence.load(Ordering::Relaxed),
+            keep_recording: state.keep_recordings.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Padding (ms) left around speech when trimming a saved recording.
+const TRIM_PADDING_MS: usize = 300;
+
+/// Rewrite the saved WAV without its leading/trailing silence, keeping the original
+/// sample rate and channel layout. Recordings with no detected speech are left alone.
+fn trim_recording_silence(
+    path: &str,
+    whisper_arc: &Arc<std::sync::Mutex<crate::whisper::WhisperManager>>,
+    vad_arc: &Arc<std::sync::Mutex<crate::vad::VADManager>>,
+) -> Result<(), String> {
+    let audio_16k = whisper_arc.lock().unwrap().load_audio(path)?;
+    let mut vad = vad_arc.lock().unwrap();
+    let (onset, offset) = vad.hysteresis(false);
+    let timestamps =
+        vad.get_speech_timestamps_hysteresis(&audio_16k, TRIM_PADDING_MS, onset, offset)?;
+    drop(vad);
+    let (Some(first), Some(last)) = (timestamps.first(), timestamps.last()) else {
+        return Ok(());
+    };
+
+    let mut reader = hound::WavReader::open(path).map_err(|e| e.to_string())?;
+    let spec = reader.spec();
+    if spec.sample_format != hound::SampleFormat::Float {
+        return Err("only float WAV recordings can be trimmed".to_string());
+    }
+    let channels = spec.channels as usize;
+    let total = reader.len() as usize;
+    let to_sample = |secs: f32| (secs * spec.sample_rate as f32) as usize * channels;
+    let start = to_sample(first.0).min(total);
+    let end = to_sample(last.1).min(total);
+    if start == 0 && end == total {
+        return Ok(());
+    }
+
+    let trimmed_path = format!("{}.trimming", path);
+    {
+        let mut writer =
+            hound::WavWriter::create(&trimmed_path, spec).map_err(|e| e.to_string())?;
+        for sample in reader.samples::<f32>().skip(start).take(end - start) {
+            writer
+                .write_sample(sample.unwrap_or(0.0))
+                .map_err(|e| e.to_string())?;
+        }
+        writer.finalize().map_err(|e| e.to_string())?;
+    }
+    drop(reader);
+    std::fs::rename(&trimmed_path, path).map_err(|e| e.to_string())?;
+    println!(
+        "[INFO] Trimmed recording to {:.1}s–{:.1}s: {}",
+        first.0, last.1, path
+    );
+    Ok(())
+}
+
+/// Swap a transcript that is exactly a registered command phrase for a
+/// `VoiceCommand` outcome and announce it with a "voice-command" event.
+fn match_voice_command(
+    outcome: TranscriptionOutcome,
+    commands: &[VoiceCommand],
+    app: &AppHandle,
+) -> TranscriptionOutcome {
+    let TranscriptionOutcome::Text(text) = &outcome else {
+        return outcome;
+    };
+    match commands.iter().find(|c| c.matches(text)) {
+        Some(command) => {
+            println!(
+                "[INFO] Voice command \"{}\" → {}",
+                command.phrase, command.action
+            );
+            let _ = app.emit("voice-command", command.clone());
+            TranscriptionOutcome::VoiceCommand(command.clone())
+        }
+        None => outcome,
+    }
+}
+
+/// Delete a session's WAV, and its `_denoised` sibling if one was saved, unless the
+/// user opted to keep recordings. Called once `stop_recording` is done with it, and
+/// again when the next recording starts or the app exits in case a session ended
+/// without getting that far.
+fn discard_recording(path: &str, keep_recording: bool) {
+    if keep_recording {
+        println!("[INFO] Keeping recording: {}", path);
+    } else {
+        let _ = std::fs::remove_file(denoised_recording_path(std::path::Path::new(path)));
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// Re-transcribe a saved recording with Parakeet: decode to 16 kHz, keep only the
+/// speech (same energy VAD as file transcription) and run one clean pass over it.
+fn parakeet_final_pass(
+    path: &str,
+    whisper_arc: &Arc<std::sync::Mutex<crate::whisper::WhisperManager>>,
+    parakeet_arc: &Arc<std::sync::Mutex<crate::parakeet::ParakeetManager>>,
+) -> Result<String, String> {
+    let audio = whisper_arc.lock().unwrap().load_audio(path)?;
+    let mut speech = crate::vad::assemble_speech_audio(&audio, None)?;
+    if speech.is_empty() {
+        return Ok(String::new());
+    }
+    audio_preprocess::preprocess_assembled_speech_16k(&mut speech);
+    parakeet_arc.lock().unwrap().transcribe_audio_data(&speech)
+}
+
 /// macOS fix: Extracted the heavy blocking core of stop_recording into a
 /// separate function so it can be dispatched via spawn_blocking. This keeps
 /// the macOS AppKit main thread free during thread joins, VAD processing,
 /// and Whisper inference which would otherwise freeze the window.
+#[allow(clippy::too_many_arguments)]
 fn stop_recording_blocking(
     recording: crate::audio::RecordingHandle,
     active_engine: ASREngine,
     session_transcript: Arc<std::sync::Mutex<String>>,
     last_recording_path: Option<String>,
     whisper_arc: Arc<std::sync::Mutex<crate::whisper::WhisperManager>>,
+    parakeet_arc: Arc<std::sync::Mutex<crate::parakeet::ParakeetManager>>,
     vad_arc: Arc<std::sync::Mutex<crate::vad::VADManager>>,
-) -> Result<String, String> {
+    settings: StopSettings,
+    app: AppHandle,
+) -> Result<TranscriptionOutcome, String> {
     // Brief tail capture for OS audio scheduling; silence padding in the
     // transcriber thread handles the actual word-boundary safety margin.
     teardown_recording(recording, 80);
 
+    if active_engine == ASREngine::Parakeet && settings.parakeet_final_pass {
+        if let Some(path) = last_recording_path.as_ref() {
+            println!("[PROCESSING] Running Parakeet final pass on: {}", path);
+            match parakeet_final_pass(path, &whisper_arc, &parakeet_arc) {
+                Ok(text) => {
+                    let final_text = clean_transcript(&text);
+                    println!("[FINAL_TRANSCRIPT] (Raw)\n{}", final_text);
+                    return Ok(TranscriptionOutcome::from_text(&final_text));
+                }
+                Err(e) => {
+                    emit_transcription_error(&app, "Parakeet", "final", &e);
+                    println!("[INFO] Using the streamed transcript instead");
+                }
+            }
+        }
+    }
+
     if active_engine == ASREngine::Parakeet || active_engine == ASREngine::Cohere {
         let engine_name = if active_engine == ASREngine::Parakeet {
             "Parakeet"
@@ -1456,16 +2381,13 @@ fn stop_recording_blocking(
             clean_transcript(&transcript)
         };
         println!("[FINAL_TRANSCRIPT] (Raw)\n{}", final_text);
-        if let Some(path) = last_recording_path.as_ref() {
-            let _ = std::fs::remove_file(path);
-        }
-        return Ok(final_text);
+        return Ok(TranscriptionOutcome::from_text(&final_text));
     }
 
     if let Some(path) = last_recording_path {
         println!(
-            "[PROCESSING] Running final high-quality transcription with VAD on: {}",
-            path
+            "[PROCESSING] Running final high-quality transcription (VAD: {}) on: {}",
+            settings.final_pass_vad, path
         );
 
         // Snapshot active-app context BEFORE acquiring any locks
@@ -1483,64 +2405,172 @@ fn stop_recording_blocking(
         // Universal preprocess on the saved 16 kHz WAV (same chain as file speech assembly).
         audio_preprocess::preprocess_assembled_speech_16k(&mut audio_data);
 
-        println!("[PROCESSING] Applying VAD filtering for Whisper...");
-        let mut vad = vad_arc.lock().unwrap();
-        // For short recordings (< 4s, likely a single word or phrase), use a
-        // more permissive VAD threshold and wider padding so short utterances
-        // aren't accidentally filtered out.
-        let audio_duration_s = audio_data.len() as f32 / 16000.0;
-        let (vad_padding, vad_threshold) = if audio_duration_s < 4.0 {
+        // With final-pass VAD disabled, no segments are produced and the whole
+        // buffer goes to Whisper through the same path as a VAD miss.
+        let timestamps = if settings.final_pass_vad {
+            println!("[PROCESSING] Applying VAD filtering for Whisper...");
+            let mut vad = vad_arc.lock().unwrap();
+            // For short recordings (< 4s, likely a single word or phrase), use a
+            // more permissive VAD threshold and wider padding so short utterances
+            // aren't accidentally filtered out.
+            let audio_duration_s = audio_data.len() as f32 / 16000.0;
+            let short = audio_duration_s < 4.0;
+            if short {
+                println!(
+                    "[VAD] Short recording ({:.1}s) — using permissive threshold",
+                    audio_duration_s
+                );
+            }
+            let vad_padding = if short { 800 } else { 500 };
+            let (onset, offset) = vad.hysteresis(short);
+            let segments =
+                vad.get_speech_timestamps_hysteresis(&audio_data, vad_padding, onset, offset)?;
+            if settings.merge_gap_ms > 0 {
+                let merged = crate::vad::merge_segments_within(
+                    &segments,
+                    settings.merge_gap_ms as f32 / 1000.0,
+                );
+                println!(
+                    "[VAD] Merged {} segment(s) into {} (gap ≤ {} ms)",
+                    segments.len(),
+                    merged.len(),
+                    settings.merge_gap_ms
+                );
+                merged
+            } else {
+                segments
+            }
+        } else {
+            println!("[PROCESSING] Final-pass VAD disabled — transcribing the full recording");
+            Vec::new()
+        };
+
+        // Continuous speech with no pause comes back as one huge segment; split it
+        // and force the per-segment path so each piece fits Whisper's window.
+        let has_long_segment = timestamps
+            .iter()
+            .any(|&(start, end)| end - start > MAX_SEGMENT_SECS);
+        let timestamps = if has_long_segment {
+            let split = crate::vad::split_long_segments(
+                &timestamps,
+                MAX_SEGMENT_SECS,
+                SEGMENT_OVERLAP_SECS,
+            );
             println!(
-                "[VAD] Short recording ({:.1}s) — using permissive threshold",
-                audio_duration_s
+                "[VAD] Split segments longer than {:.0}s: {} → {} segment(s)",
+                MAX_SEGMENT_SECS,
+                timestamps.len(),
+                split.len()
             );
-            (800_usize, 0.2_f32)
+            split
         } else {
-            (500_usize, 0.35_f32)
+            timestamps
         };
-        let timestamps = vad.get_speech_timestamps_hysteresis(
-            &audio_data,
-            vad_padding,
-            vad_threshold,
-            vad_threshold * 0.5,
-        )?;
-
-        let mut clean = Vec::with_capacity(audio_data.len());
-        if timestamps.is_empty() {
-            // VAD found nothing — let Whisper decide rather than hard-failing
-            println!("[VAD] No speech segments found, passing full audio to Whisper as fallback");
-            clean.extend_from_slice(&audio_data);
-        }
-        for (start, end) in timestamps {
-            let s = (start * 16000.0) as usize;
-            let e = (end * 16000.0) as usize;
-            clean.extend_from_slice(&audio_data[s.min(audio_data.len())..e.min(audio_data.len())]);
-        }
-
-        // Release locks before transcription to avoid deadlock
+
+        // A single segment gains nothing from the per-segment path
+        let per_segment = (settings.per_segment || has_long_segment) && timestamps.len() > 1;
+
+        let mut clean = Vec::new();
+        if !per_segment {
+            clean.reserve(audio_data.len());
+            if timestamps.is_empty() {
+                // VAD found nothing — let Whisper decide rather than hard-failing
+                if settings.final_pass_vad {
+                    println!(
+                        "[VAD] No speech segments found, passing full audio to Whisper as fallback"
+                    );
+                }
+                clean.extend_from_slice(&audio_data);
+            }
+            for &(start, end) in &timestamps {
+                let s = (start * 16000.0) as usize;
+                let e = (end * 16000.0) as usize;
+                clean.extend_from_slice(
+                    &audio_data[s.min(audio_data.len())..e.min(audio_data.len())],
+                );
+            }
+        }
+
+        // Release lock before transcription to avoid deadlock
         drop(whisper);
-        drop(vad);
 
-        let result = {
+        let result = if per_segment {
+            println!(
+                "[PROCESSING] Transcribing {} VAD segments individually",
+                timestamps.len()
+            );
+            transcribe_segments(
+                &whisper_arc,
+                &audio_data,
+                &timestamps,
+                app_context.as_deref(),
+                &app,
+            )
+        } else {
             let mut whisper = whisper_arc.lock().unwrap();
             whisper.transcribe_audio_data(&clean, app_context.as_deref())
         };
 
-        let _ = std::fs::remove_file(&path);
-
         match result {
             Ok(raw_text) => {
                 println!("[FINAL_TRANSCRIPT] (Raw)\n{}", raw_text);
                 let final_text = clean_transcript(&raw_text);
-                Ok(final_text)
+                Ok(TranscriptionOutcome::from_text(&final_text))
             }
             Err(e) => {
-                eprintln!("[ERROR] Final transcription failed: {}", e);
-                Err(format!("Final transcription failed: {}", e))
+                emit_transcription_error(&app, "Whisper", "final", &e);
+                Ok(TranscriptionOutcome::Failed(format!(
+                    "Final transcription failed: {}",
+                    e
+                )))
             }
         }
     } else {
-        Ok("Recording saved.".to_string())
+        Ok(TranscriptionOutcome::SavedNoTranscript)
+    }
+}
+
+/// Run `stop_recording` for `session_path` from the backend. Returns None without
+/// stopping if that session already ended or was replaced by a new one.
+async fn stop_session(
+    app: &AppHandle,
+    session_path: &str,
+) -> Option<CommandResult<TranscriptionOutcome>> {
+    let state = app.state::<AudioState>();
+    let same_session = state.recording_handle.lock().unwrap().is_some()
+        && state.last_recording_path.lock().unwrap().as_deref() == Some(session_path);
+    if !same_session {
+        return None;
+    }
+    Some(match stop_recording(app.clone(), state).await {
+        Ok(result) => result,
+        Err(message) => CommandResult::err("recording_stop_failed", message),
+    })
+}
+
+/// Stop the session that hit the max-duration limit and report the result, so the
+/// recording is finalized and transcribed even if the webview is hidden or throttled.
+async fn auto_stop_recording(app: AppHandle, session_path: String, max_secs: u32) {
+    if let Some(result) = stop_session(&app, &session_path).await {
+        let _ = app.emit(
+            "recording-auto-stopped",
+            RecordingAutoStopped { max_secs, result },
+        );
+    }
+}
+
+/// Stop the session whose input device failed and report the result, so the backend
+/// tears the stream down and finalizes the WAV without waiting on the frontend.
+async fn device_lost_stop_recording(app: AppHandle, session_path: String, message: String) {
+    if let Some(result) = stop_session(&app, &session_path).await {
+        let _ = app.emit(
+            "recording-device-lost",
+            RecordingDeviceLost {
+                code: "audio_device_disconnected".to_string(),
+                message,
+                result,
+            },
+        );
     }
 }
 
@@ -1554,10 +2584,14 @@ fn stop_recording_blocking(
 /// On Windows/Linux synchronous commands already run on a thread pool so the
 /// original blocking behaviour is fine, but async is harmless there too.
 #[tauri::command]
-pub async fn stop_recording(state: State<'_, AudioState>) -> Result<CommandResult<String>, String> {
+pub async fn stop_recording(
+    app_handle: AppHandle,
+    state: State<'_, AudioState>,
+) -> Result<CommandResult<TranscriptionOutcome>, String> {
     // --- Quick state access (non-blocking, just mutex snapshots) ---
     *state.denoiser.lock().unwrap() = None;
     state.recording_paused.store(false, Ordering::Relaxed);
+    state.hotkey_recording_active.store(false, Ordering::SeqCst);
 
     let Some(recording) = state.recording_handle.lock().unwrap().take() else {
         return Ok(CommandResult::err("not_recording", "Not recording"));
@@ -1567,24 +2601,89 @@ pub async fn stop_recording(state: State<'_, AudioState>) -> Result<CommandResul
     let session_transcript = state.session_transcript.clone();
     let last_recording_path = state.last_recording_path.lock().unwrap().clone();
     let whisper_arc = state.whisper.clone();
+    let parakeet_arc = state.parakeet.clone();
     let vad_arc = state.vad.clone();
+    let settings = StopSettings::from_state(&state);
+    let voice_commands = state.voice_commands.lock().unwrap().clone();
+    let app = app_handle.clone();
 
     // --- Heavy work: dispatched off the main thread via spawn_blocking so the
     //     macOS AppKit event loop stays responsive (thread joins, VAD, Whisper). ---
     tauri::async_runtime::spawn_blocking(move || {
-        stop_recording_blocking(
+        let started = std::time::Instant::now();
+        let result = stop_recording_blocking(
             recording,
             active_engine,
             session_transcript,
-            last_recording_path,
-            whisper_arc,
-            vad_arc,
-        )
+            last_recording_path.clone(),
+            whisper_arc.clone(),
+            parakeet_arc,
+            vad_arc.clone(),
+            settings,
+            app_handle,
+        );
+        // Trim after transcription so the final pass still sees the untouched file.
+        if let (true, Some(path)) = (settings.trim_silence, last_recording_path.as_ref()) {
+            if let Err(e) = trim_recording_silence(path, &whisper_arc, &vad_arc) {
+                eprintln!("[WARN] Could not trim silence from {}: {}", path, e);
+            }
+        }
+        // Nothing reads an unkept WAV after the final pass, so it goes now rather
+        // than lingering until the next recording starts.
+        if let (false, Some(path)) = (settings.keep_recording, last_recording_path) {
+            discard_recording(&path, false);
+            super::persist_setting(&app, UNKEPT_RECORDING_KEY, None);
+        }
+        let result = result
+            .map(|outcome| match_voice_command(outcome, &voice_commands, &app))
+            .map(|outcome| match outcome {
+                TranscriptionOutcome::Text(text) => {
+                    TranscriptionOutcome::from_text(&crate::postprocess::correct(&app, &text))
+                }
+                other => other,
+            });
+        if let Ok(TranscriptionOutcome::Text(text)) = &result {
+            let _ = app.emit(
+                "transcription-final",
+                TranscriptionFinal {
+                    text: text.clone(),
+                    processing_time_ms: started.elapsed().as_millis() as u32,
+                    method: format!("{:?}", active_engine),
+                },
+            );
+        }
+        result
     })
     .await
     .map(|result| match result {
-        Ok(transcript) => CommandResult::ok(transcript),
+        Ok(outcome) => CommandResult::ok(outcome),
         Err(message) => CommandResult::err("recording_stop_failed", message),
     })
     .map_err(|e| format!("stop_recording task failed: {}", e))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlap_is_dropped_when_only_punctuation_and_case_differ() {
+        assert_eq!(
+            strip_overlap_words("and then we went home.", "Went home, and slept"),
+            "and slept"
+        );
+        assert_eq!(
+            strip_overlap_words("it's fine", "It's fine... really"),
+            "really"
+        );
+    }
+
+    #[test]
+    fn text_without_overlap_is_kept() {
+        assert_eq!(
+            strip_overlap_words("we went home", "then we slept"),
+            "then we slept"
+        );
+        assert_eq!(strip_overlap_words("", "hello there"), "hello there");
+    }
+}
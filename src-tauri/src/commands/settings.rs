@@ -1,8 +1,155 @@
+use crate::spellcheck::{SpellChecker, SpellcheckOptions};
 use crate::state::AudioState;
 use crate::tray;
-use crate::types::{ASREngine, AppState, EngineSelectionState, HotkeyBinding};
+use crate::types::{
+    ASREngine, AppState, BufferDropPolicy, EngineSelectionState, HotkeyBinding,
+    PostRecordingAction, TypingMode, VoiceCommand,
+};
+use crate::vad::{LiveVadFrameConfig, VadSensitivity};
+use crate::whisper::{TokenSuppression, WhisperWarmup};
 use std::sync::atomic::Ordering;
-use tauri::{AppHandle, State};
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_store::StoreExt;
+
+/// The frontend's persisted settings file (tauri-plugin-store).
+pub(crate) const SETTINGS_STORE: &str = "settings.json";
+/// Bumped when the export layout changes so older blobs can still be imported.
+const SETTINGS_EXPORT_VERSION: u32 = 1;
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SettingsExport {
+    version: u32,
+    settings: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Write one key to settings.json; failures are logged rather than failing the setter.
+///
+/// Each key has one writer. The backend owns the keys its commands persist here:
+/// `active_engine`, `whisper_model`,
+/// `parakeet_model`, `granite_model`, `hotkey_binding`, `input_device`,
+/// `close_behavior`, `hf_endpoint`, `post_recording_action`, the paste delays,
+/// `typing_mode` and the spellcheck keys. The frontend may read them but must go
+/// through the commands to change them. Everything else in settings.json (UI
+/// preferences, `asr_backend`, the dictionary and snippets) belongs to the frontend.
+pub(crate) fn persist_setting(app: &AppHandle, key: &str, value: Option<serde_json::Value>) {
+    let result = app.store(SETTINGS_STORE).and_then(|store| {
+        match value {
+            Some(value) => store.set(key, value),
+            None => {
+                store.delete(key);
+            }
+        }
+        store.save()
+    });
+    if let Err(e) = result {
+        eprintln!("[WARN] Failed to persist setting '{}': {}", key, e);
+    }
+}
+
+/// settings.json spelling of an engine (matches the frontend's `ASREngine` type).
+fn engine_setting_name(engine: ASREngine) -> &'static str {
+    match engine {
+        ASREngine::Whisper => "whisper",
+        ASREngine::Parakeet => "parakeet",
+        ASREngine::Cohere => "cohere",
+    }
+}
+
+/// Apply the persisted engine, hotkey, input device, close behavior and HuggingFace
+/// endpoint from settings.json during setup, so they hold before the frontend has
+/// loaded (e.g. when the app starts hidden in the tray).
+pub fn apply_persisted_settings(app: &AppHandle) {
+    let Ok(store) = app.store(SETTINGS_STORE) else {
+        return;
+    };
+    let state = app.state::<AudioState>();
+
+    if let Some(engine) = store.get("active_engine").and_then(|v| match v.as_str()? {
+        "whisper" => Some(ASREngine::Whisper),
+        "parakeet" => Some(ASREngine::Parakeet),
+        "cohere" => Some(ASREngine::Cohere),
+        _ => None,
+    }) {
+        *state.active_engine.lock().unwrap() = engine;
+    }
+    if let Some(binding) = store
+        .get("hotkey_binding")
+        .and_then(|v| serde_json::from_value::<HotkeyBinding>(v).ok())
+        .filter(HotkeyBinding::is_valid)
+    {
+        *state.hotkey_config.write().unwrap() = binding;
+    }
+    if let Some(device) = store
+        .get("input_device")
+        .and_then(|v| v.as_str().map(str::to_string))
+    {
+        *state.selected_input_device.lock().unwrap() = Some(device);
+    }
+    if let Some(behavior) = store
+        .get("close_behavior")
+        .and_then(|v| v.as_str().map(str::to_string))
+        .filter(|b| b == "tray" || b == "quit")
+    {
+        *state.close_behavior.lock().unwrap() = behavior;
+    }
+    if let Some(action) = store
+        .get("post_recording_action")
+        .and_then(|v| serde_json::from_value::<PostRecordingAction>(v).ok())
+    {
+        *state.post_recording_action.lock().unwrap() = action;
+    }
+    if let Some(ms) = store
+        .get("paste_set_delay_ms")
+        .and_then(|v| v.as_u64())
+        .filter(|&ms| ms <= 1000)
+    {
+        state.paste_set_delay_ms.store(ms as u32, Ordering::Relaxed);
+    }
+    if let Some(ms) = store
+        .get("paste_delay_ms")
+        .and_then(|v| v.as_u64())
+        .filter(|&ms| ms <= 5000)
+    {
+        state.paste_delay_ms.store(ms as u32, Ordering::Relaxed);
+    }
+    if let Some(mode) = store
+        .get("typing_mode")
+        .and_then(|v| serde_json::from_value::<TypingMode>(v).ok())
+    {
+        *state.typing_mode.lock().unwrap() = mode;
+    }
+    if let Some(options) = store
+        .get("spellcheck_options")
+        .and_then(|v| serde_json::from_value::<SpellcheckOptions>(v).ok())
+        .filter(|o| o.validate().is_ok())
+    {
+        *state.spellcheck_options.lock().unwrap() = options;
+    }
+    if store.get("spellcheck_enabled").and_then(|v| v.as_bool()) == Some(true) {
+        state.spellcheck_enabled.store(true, Ordering::Relaxed);
+        // The dictionary takes a moment to load; don't hold up startup for it.
+        let checker = state.spell_checker.clone();
+        let options = *state.spellcheck_options.lock().unwrap();
+        std::thread::spawn(move || match SpellChecker::new(options) {
+            Ok(loaded) => {
+                checker.lock().unwrap().get_or_insert(loaded);
+            }
+            Err(e) => eprintln!("[SPELL] {}", e),
+        });
+    }
+    if let Some(endpoint) = store
+        .get("hf_endpoint")
+        .and_then(|v| v.as_str().map(str::to_string))
+    {
+        if let Err(e) = super::downloader::set_hf_endpoint_override(Some(endpoint)) {
+            eprintln!("[WARN] Ignoring saved hf_endpoint: {}", e);
+        }
+    }
+    println!(
+        "[INFO] Restored settings: engine={:?}",
+        *state.active_engine.lock().unwrap()
+    );
+}
 
 /// Ask the backend what hardware is running the AI (CPU vs GPU)
 /// Returns the backend of whichever engine is currently active
@@ -10,20 +157,9 @@ use tauri::{AppHandle, State};
 pub fn get_backend_info(state: State<AudioState>) -> Result<String, String> {
     let active = *state.active_engine.lock().unwrap();
     match active {
-        ASREngine::Parakeet => {
-            let parakeet = state.parakeet.lock().unwrap();
-            let status = parakeet.get_status();
-            Ok(status.backend)
-        }
-        ASREngine::Whisper => {
-            let whisper = state.whisper.lock().unwrap();
-            Ok(format!("{}", whisper.get_backend()))
-        }
-        ASREngine::Cohere => {
-            let gs = state.cohere.lock().unwrap();
-            let status = gs.get_status();
-            Ok(status.backend)
-        }
+        ASREngine::Parakeet => Ok(state.parakeet_status().backend),
+        ASREngine::Whisper => Ok(state.whisper_status().backend),
+        ASREngine::Cohere => Ok(state.cohere_status().backend),
     }
 }
 
@@ -39,22 +175,18 @@ pub fn get_engine_selection_state(
     }
     .to_string();
 
-    let whisper_model = state.whisper.lock().unwrap().get_current_model().cloned();
-    let parakeet_status = state.parakeet.lock().unwrap().get_status();
-    let cohere_status = state.cohere.lock().unwrap().get_status();
+    let whisper_status = state.whisper_status();
+    let parakeet_status = state.parakeet_status();
+    let cohere_status = state.cohere_status();
 
     let (selected_model_id, loaded_engine, loaded_model_id, backend) = match active {
         ASREngine::Whisper => {
-            let loaded = whisper_model.clone();
-            let backend = {
-                let whisper = state.whisper.lock().unwrap();
-                format!("{}", whisper.get_backend())
-            };
+            let loaded = whisper_status.model.clone();
             (
-                whisper_model.clone(),
+                whisper_status.model,
                 loaded.as_ref().map(|_| "whisper".to_string()),
                 loaded,
-                backend,
+                whisper_status.backend,
             )
         }
         ASREngine::Parakeet => {
@@ -105,11 +237,16 @@ pub fn set_active_engine(
     let new_engine = match engine.to_lowercase().as_str() {
         "whisper" => ASREngine::Whisper,
         "parakeet" => ASREngine::Parakeet,
-        "granitespeech" | "granite_speech" | "granite-speech" => ASREngine::Cohere,
+        "cohere" | "granitespeech" | "granite_speech" | "granite-speech" => ASREngine::Cohere,
         _ => return Err(format!("Unknown engine: {}", engine)),
     };
 
     *state.active_engine.lock().unwrap() = new_engine;
+    persist_setting(
+        &app,
+        "active_engine",
+        Some(engine_setting_name(new_engine).into()),
+    );
     println!("[ENGINE] Active engine switched to: {:?}", new_engine);
     let loaded = state.model_loaded.load(Ordering::Relaxed);
     tray::update_tray_model_item(&app, loaded);
@@ -131,13 +268,18 @@ pub fn get_hotkey(state: State<AudioState>) -> HotkeyBinding {
 /// Update the hotkey binding — takes effect immediately (no restart needed).
 /// Rejects bindings that don't have exactly 2 keys.
 #[tauri::command]
-pub fn set_hotkey(state: State<AudioState>, binding: HotkeyBinding) -> Result<(), String> {
-    if binding.keys.len() != 2 {
+pub fn set_hotkey(
+    app: AppHandle,
+    state: State<AudioState>,
+    binding: HotkeyBinding,
+) -> Result<(), String> {
+    if !binding.is_valid() {
         return Err(format!(
-            "Hotkey must be exactly 2 keys, got {}",
-            binding.keys.len()
+            "Hotkey must be 2 keys or a single mouse button, got {:?}",
+            binding.keys
         ));
     }
+    persist_setting(&app, "hotkey_binding", serde_json::to_value(&binding).ok());
     *state.hotkey_config.write().unwrap() = binding;
     Ok(())
 }
@@ -152,7 +294,8 @@ pub fn set_hotkey_suppressed(state: State<AudioState>, suppressed: bool) {
 
 /// Set the preferred input device. Pass None to revert to the system default.
 #[tauri::command]
-pub fn set_input_device(state: State<AudioState>, name: Option<String>) {
+pub fn set_input_device(app: AppHandle, state: State<AudioState>, name: Option<String>) {
+    persist_setting(&app, "input_device", name.clone().map(Into::into));
     *state.selected_input_device.lock().unwrap() = name;
 }
 
@@ -164,9 +307,14 @@ pub fn get_close_behavior(state: State<AudioState>) -> String {
 
 /// Set the close-button behavior. "tray" hides to tray; "quit" exits the process.
 #[tauri::command]
-pub fn set_close_behavior(state: State<AudioState>, behavior: String) -> Result<(), String> {
+pub fn set_close_behavior(
+    app: AppHandle,
+    state: State<AudioState>,
+    behavior: String,
+) -> Result<(), String> {
     match behavior.as_str() {
         "tray" | "quit" => {
+            persist_setting(&app, "close_behavior", Some(behavior.clone().into()));
             *state.close_behavior.lock().unwrap() = behavior;
             Ok(())
         }
@@ -174,6 +322,564 @@ pub fn set_close_behavior(state: State<AudioState>, behavior: String) -> Result<
     }
 }
 
+/// Return whether recordings are kept on disk after transcription
+#[tauri::command]
+pub fn get_keep_recordings(state: State<AudioState>) -> bool {
+    state.keep_recordings.load(Ordering::Relaxed)
+}
+
+/// Keep (true) or delete (false) the temp WAV. When false it is deleted as soon as
+/// the final pass is done, so `retranscribe_last` and subtitle export need this on.
+#[tauri::command]
+pub fn set_keep_recordings(state: State<AudioState>, keep: bool) {
+    state.keep_recordings.store(keep, Ordering::Relaxed);
+}
+
+/// Return the recording length limit in seconds, or None when recordings are unlimited
+#[tauri::command]
+pub fn get_max_recording_secs(state: State<AudioState>) -> Option<u32> {
+    *state.max_recording_secs.lock().unwrap()
+}
+
+/// Auto-stop recordings after `secs` seconds (e.g. 300). None or 0 removes the limit.
+/// Applies from the next recording.
+#[tauri::command]
+pub fn set_max_recording_secs(state: State<AudioState>, secs: Option<u32>) {
+    *state.max_recording_secs.lock().unwrap() = secs.filter(|&s| s > 0);
+}
+
+/// Return the live-preview chunk length in seconds, or None for the engine default
+#[tauri::command]
+pub fn get_live_chunk_secs(state: State<AudioState>) -> Option<f32> {
+    *state.live_chunk_secs.lock().unwrap()
+}
+
+/// Set the live-preview chunk length for Whisper and Parakeet, clamped to 1–15 s.
+/// Shorter chunks show text sooner but give the model less context. None restores
+/// the defaults (6 s Whisper, 4 s Parakeet). Applies from the next recording.
+#[tauri::command]
+pub fn set_live_chunk_secs(state: State<AudioState>, secs: Option<f32>) {
+    *state.live_chunk_secs.lock().unwrap() = secs
+        .filter(|s| s.is_finite() && *s > 0.0)
+        .map(|s| s.clamp(1.0, 15.0));
+}
+
+/// Return whether the Whisper final pass transcribes VAD segments one at a time
+#[tauri::command]
+pub fn get_final_pass_per_segment(state: State<AudioState>) -> bool {
+    state.final_pass_per_segment.load(Ordering::Relaxed)
+}
+
+/// Transcribe each VAD segment separately in the final pass (true) or as one buffer (false).
+#[tauri::command]
+pub fn set_final_pass_per_segment(state: State<AudioState>, enabled: bool) {
+    state
+        .final_pass_per_segment
+        .store(enabled, Ordering::Relaxed);
+}
+
+/// Return whether the Whisper final pass filters the recording through VAD first
+#[tauri::command]
+pub fn get_final_pass_vad(state: State<AudioState>) -> bool {
+    state.final_pass_vad.load(Ordering::Relaxed)
+}
+
+/// Enable (default) or disable VAD filtering in the Whisper final pass.
+/// Disabling sends the whole recording to Whisper, which helps quiet, clean audio.
+#[tauri::command]
+pub fn set_final_pass_vad(state: State<AudioState>, enabled: bool) {
+    state.final_pass_vad.store(enabled, Ordering::Relaxed);
+}
+
+/// Return whether Parakeet recordings get a clean final pass over the saved audio
+#[tauri::command]
+pub fn get_parakeet_final_pass(state: State<AudioState>) -> bool {
+    state.parakeet_final_pass.load(Ordering::Relaxed)
+}
+
+/// Re-transcribe the VAD-filtered recording with Parakeet after stop (true) instead of
+/// using the streamed transcript (false, default). Slower, but free of streaming artifacts.
+#[tauri::command]
+pub fn set_parakeet_final_pass(state: State<AudioState>, enabled: bool) {
+    state.parakeet_final_pass.store(enabled, Ordering::Relaxed);
+}
+
+/// Return the final-pass VAD merge gap in milliseconds (0 = only touching segments merge)
+#[tauri::command]
+pub fn get_vad_merge_gap_ms(state: State<AudioState>) -> u32 {
+    state.vad_merge_gap_ms.load(Ordering::Relaxed)
+}
+
+/// Merge final-pass speech segments separated by pauses up to `ms` long (max 5000).
+/// Short pauses stay in the audio instead of being spliced out mid-sentence.
+#[tauri::command]
+pub fn set_vad_merge_gap_ms(state: State<AudioState>, ms: u32) -> Result<(), String> {
+    if ms > 5000 {
+        return Err(format!("Merge gap must be at most 5000 ms, got {}", ms));
+    }
+    state.vad_merge_gap_ms.store(ms, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Return whether saved recordings are trimmed of leading/trailing silence
+#[tauri::command]
+pub fn get_trim_recording_silence(state: State<AudioState>) -> bool {
+    state.trim_recording_silence.load(Ordering::Relaxed)
+}
+
+/// Trim dead air from the head and tail of the saved WAV after each recording.
+#[tauri::command]
+pub fn set_trim_recording_silence(state: State<AudioState>, enabled: bool) {
+    state
+        .trim_recording_silence
+        .store(enabled, Ordering::Relaxed);
+}
+
+/// Return which tokens Whisper suppresses while decoding
+#[tauri::command]
+pub fn get_whisper_token_suppression(state: State<AudioState>) -> TokenSuppression {
+    state.whisper.lock().unwrap().token_suppression()
+}
+
+/// Toggle Whisper's blank / non-speech token suppression (e.g. to drop "[music]" natively).
+#[tauri::command]
+pub fn set_whisper_token_suppression(state: State<AudioState>, suppression: TokenSuppression) {
+    state
+        .whisper
+        .lock()
+        .unwrap()
+        .set_token_suppression(suppression);
+}
+
+/// Return whether Whisper runs a warm-up pass after loading, and its length
+#[tauri::command]
+pub fn get_whisper_warmup(state: State<AudioState>) -> WhisperWarmup {
+    state.whisper.lock().unwrap().warmup()
+}
+
+/// Enable/disable the post-load warm-up and set its duration (0.1–10 s).
+/// Disabling it shortens model loads at the cost of a slower first chunk.
+#[tauri::command]
+pub fn set_whisper_warmup(state: State<AudioState>, warmup: WhisperWarmup) -> Result<(), String> {
+    state.whisper.lock().unwrap().set_warmup(warmup)
+}
+
+/// Return the Whisper language (None = auto-detect)
+#[tauri::command]
+pub fn get_whisper_language(state: State<AudioState>) -> Option<String> {
+    state.whisper.lock().unwrap().language().cloned()
+}
+
+/// Set the language Whisper transcribes in (ISO 639-1 such as "de"); None, "" or
+/// "auto" means auto-detect. Emits "model-mismatch" when the loaded model is English-only.
+#[tauri::command]
+pub fn set_whisper_language(
+    app: AppHandle,
+    state: State<AudioState>,
+    language: Option<String>,
+) -> Result<(), String> {
+    let language = language
+        .map(|l| l.trim().to_lowercase())
+        .filter(|l| !l.is_empty() && l != "auto");
+    if let Some(lang) = &language {
+        if lang.len() > 3 || !lang.chars().all(|c| c.is_ascii_lowercase()) {
+            return Err(format!("Invalid language code: {}", lang));
+        }
+    }
+    println!(
+        "[WHISPER] Language set to {}",
+        language.as_deref().unwrap_or("auto")
+    );
+    state.whisper.lock().unwrap().set_language(language.clone());
+    super::check_model_language(app, state, language.unwrap_or_else(|| "auto".to_string()));
+    Ok(())
+}
+
+/// Return whether Whisper translates speech into English
+#[tauri::command]
+pub fn get_whisper_translate(state: State<AudioState>) -> bool {
+    state.whisper.lock().unwrap().translate()
+}
+
+/// Enable/disable translate-to-English. Applies from the next chunk, including the
+/// live preview; English-only `.en` models can't translate and keep transcribing.
+#[tauri::command]
+pub fn set_whisper_translate(state: State<AudioState>, translate: bool) {
+    println!("[WHISPER] Translate to English: {}", translate);
+    state.whisper.lock().unwrap().set_translate(translate);
+}
+
+/// Return the Whisper model loaded at startup, if one has been chosen
+#[tauri::command]
+pub fn get_default_model(state: State<AudioState>) -> Option<String> {
+    state.whisper.lock().unwrap().default_model().cloned()
+}
+
+/// Choose the Whisper model to load at launch (None = tiny.en). Stored as
+/// `whisper_model`, the same key `switch_model` writes, so there's one source of truth.
+#[tauri::command]
+pub fn set_default_model(app: AppHandle, state: State<AudioState>, model_id: Option<String>) {
+    persist_setting(&app, "whisper_model", model_id.clone().map(Into::into));
+    state.whisper.lock().unwrap().set_default_model(model_id);
+}
+
+/// Read `whisper_model` from settings.json and, when the user's engine is Whisper,
+/// load it in the background so the first dictation doesn't wait on a model switch.
+pub fn load_default_model_on_startup(app: &AppHandle) {
+    let Ok(store) = app.store(SETTINGS_STORE) else {
+        return;
+    };
+    let Some(model_id) = store
+        .get("whisper_model")
+        .and_then(|v| v.as_str().map(str::to_string))
+    else {
+        return;
+    };
+    let engine = store
+        .get("active_engine")
+        .and_then(|v| v.as_str().map(str::to_string));
+    let force_cpu = store
+        .get("asr_backend")
+        .is_some_and(|v| v.as_str() == Some("cpu"));
+
+    let state = app.state::<AudioState>();
+    state
+        .whisper
+        .lock()
+        .unwrap()
+        .set_default_model(Some(model_id.clone()));
+    if engine.as_deref().is_some_and(|e| e != "whisper") {
+        return;
+    }
+    // Claim the loading slot so the UI's own restore waits instead of racing this load.
+    if state
+        .engine_loading
+        .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+        .is_err()
+    {
+        return;
+    }
+
+    println!("[INFO] Loading startup model '{}'", model_id);
+    let whisper = state.whisper.clone();
+    let model_loaded = state.model_loaded.clone();
+    let engine_loading = state.engine_loading.clone();
+    let app = app.clone();
+    std::thread::spawn(move || {
+        let result = whisper.lock().unwrap().initialize(None, force_cpu);
+        engine_loading.store(false, Ordering::Relaxed);
+        match result {
+            Ok(msg) => {
+                println!("[SUCCESS] Startup model loaded: {}", msg);
+                model_loaded.store(true, Ordering::Relaxed);
+                tray::update_tray_model_item(&app, true);
+            }
+            Err(e) => eprintln!("[WARN] Failed to load startup model: {}", e),
+        }
+    });
+}
+
+/// Return the live transcriber's buffer-drop policy
+#[tauri::command]
+pub fn get_buffer_drop_policy(state: State<AudioState>) -> BufferDropPolicy {
+    *state.buffer_drop_policy.lock().unwrap()
+}
+
+/// Set the buffer-drop policy. Takes effect on the next recording.
+#[tauri::command]
+pub fn set_buffer_drop_policy(state: State<AudioState>, policy: BufferDropPolicy) {
+    *state.buffer_drop_policy.lock().unwrap() = policy;
+}
+
+/// Return what happens to the transcript after a recording stops
+#[tauri::command]
+pub fn get_post_recording_action(state: State<AudioState>) -> PostRecordingAction {
+    *state.post_recording_action.lock().unwrap()
+}
+
+/// Paste the transcript ("paste", default), only copy it to the clipboard
+/// ("copy_only"), copy only after hotkey stops ("copy_on_hotkey"), or leave it in
+/// the UI for review ("nothing") after a recording stops.
+#[tauri::command]
+pub fn set_post_recording_action(
+    app: AppHandle,
+    state: State<AudioState>,
+    action: PostRecordingAction,
+) {
+    *state.post_recording_action.lock().unwrap() = action;
+    persist_setting(
+        &app,
+        "post_recording_action",
+        serde_json::to_value(action).ok(),
+    );
+}
+
+/// Return the clipboard paste delays in ms as (after set, before restore)
+#[tauri::command]
+pub fn get_paste_delays(state: State<AudioState>) -> (u32, u32) {
+    (
+        state.paste_set_delay_ms.load(Ordering::Relaxed),
+        state.paste_delay_ms.load(Ordering::Relaxed),
+    )
+}
+
+/// Set how long clipboard paste waits after putting the transcript on the clipboard
+/// (`set_ms`, default 50, max 1000) and before restoring the previous clipboard
+/// (`restore_ms`, default 300, max 5000). Raise `restore_ms` if the restored
+/// clipboard lands instead of the dictation (slow apps, remote desktops).
+#[tauri::command]
+pub fn set_paste_delays(
+    app: AppHandle,
+    state: State<AudioState>,
+    set_ms: Option<u32>,
+    restore_ms: Option<u32>,
+) -> Result<(), String> {
+    if let Some(ms) = set_ms {
+        if ms > 1000 {
+            return Err(format!("Set delay must be at most 1000 ms, got {}", ms));
+        }
+    }
+    if let Some(ms) = restore_ms {
+        if ms > 5000 {
+            return Err(format!("Restore delay must be at most 5000 ms, got {}", ms));
+        }
+    }
+    if let Some(ms) = set_ms {
+        state.paste_set_delay_ms.store(ms, Ordering::Relaxed);
+        persist_setting(&app, "paste_set_delay_ms", Some(ms.into()));
+    }
+    if let Some(ms) = restore_ms {
+        state.paste_delay_ms.store(ms, Ordering::Relaxed);
+        persist_setting(&app, "paste_delay_ms", Some(ms.into()));
+    }
+    Ok(())
+}
+
+/// Return whether transcripts are spellchecked before insertion
+#[tauri::command]
+pub fn get_spellcheck_enabled(state: State<AudioState>) -> bool {
+    state.spellcheck_enabled.load(Ordering::Relaxed)
+}
+
+/// Turn the SymSpell pass on or off. Enabling loads the frequency dictionary up
+/// front so a missing file is reported here rather than silently skipped later.
+#[tauri::command]
+pub async fn set_spellcheck_enabled(
+    app: AppHandle,
+    state: State<'_, AudioState>,
+    enabled: bool,
+) -> Result<(), String> {
+    if enabled {
+        let checker = state.spell_checker.clone();
+        let options = *state.spellcheck_options.lock().unwrap();
+        tauri::async_runtime::spawn_blocking(move || {
+            let mut checker = checker.lock().unwrap();
+            if checker.is_none() {
+                *checker = Some(SpellChecker::new(options).map_err(|e| e.to_string())?);
+            }
+            Ok::<_, String>(())
+        })
+        .await
+        .map_err(|e| format!("Spellcheck load task failed: {}", e))??;
+    } else {
+        // Free the dictionary; it is reloaded on the next enable.
+        *state.spell_checker.lock().unwrap() = None;
+    }
+    state.spellcheck_enabled.store(enabled, Ordering::Relaxed);
+    persist_setting(&app, "spellcheck_enabled", Some(enabled.into()));
+    Ok(())
+}
+
+/// Add words (jargon, names, identifiers) the spellchecker should keep as-is.
+/// They are saved to the user word list and taught to the loaded checker right
+/// away. Returns the size of the list afterwards.
+#[tauri::command]
+pub fn spellcheck_add_words(state: State<AudioState>, words: Vec<String>) -> Result<usize, String> {
+    let count = crate::spellcheck::save_user_words(&words)?;
+    if let Some(checker) = state.spell_checker.lock().unwrap().as_mut() {
+        for word in &words {
+            checker.add_word(word);
+        }
+    }
+    Ok(count)
+}
+
+/// Return the spellcheck edit distance, verbosity and skip rules
+#[tauri::command]
+pub fn get_spellcheck_options(state: State<AudioState>) -> SpellcheckOptions {
+    *state.spellcheck_options.lock().unwrap()
+}
+
+/// Set the spellcheck edit distance (0–2), verbosity and skip rules. Applies to the
+/// loaded checker immediately and to checkers loaded later.
+#[tauri::command]
+pub fn set_spellcheck_options(
+    app: AppHandle,
+    state: State<AudioState>,
+    options: SpellcheckOptions,
+) -> Result<(), String> {
+    options.validate()?;
+    *state.spellcheck_options.lock().unwrap() = options;
+    if let Some(checker) = state.spell_checker.lock().unwrap().as_mut() {
+        checker.set_options(options);
+    }
+    persist_setting(
+        &app,
+        "spellcheck_options",
+        serde_json::to_value(options).ok(),
+    );
+    Ok(())
+}
+
+/// Return how text is inserted into the focused app
+#[tauri::command]
+pub fn get_typing_mode(state: State<AudioState>) -> TypingMode {
+    *state.typing_mode.lock().unwrap()
+}
+
+/// Insert via the platform default ("auto": AXUIElement on macOS, clipboard paste
+/// elsewhere), always via clipboard + Cmd/Ctrl+V ("clipboard_paste"), or by
+/// typing one character at a time ("keystroke_by_keystroke").
+#[tauri::command]
+pub fn set_typing_mode(app: AppHandle, state: State<AudioState>, mode: TypingMode) {
+    *state.typing_mode.lock().unwrap() = mode;
+    persist_setting(&app, "typing_mode", serde_json::to_value(mode).ok());
+}
+
+/// Return whether the GPU keep-alive is enabled
+#[tauri::command]
+pub fn get_gpu_keepalive(state: State<AudioState>) -> bool {
+    state.gpu_keepalive.load(Ordering::Relaxed)
+}
+
+/// Enable/disable (default) a tiny silent inference every few seconds while the
+/// window is focused and a model is loaded, so the GPU doesn't clock down and
+/// spike the first chunk's latency.
+#[tauri::command]
+pub fn set_gpu_keepalive(state: State<AudioState>, enabled: bool) {
+    state.gpu_keepalive.store(enabled, Ordering::Relaxed);
+}
+
+/// Return the registered voice commands
+#[tauri::command]
+pub fn get_voice_commands(state: State<AudioState>) -> Vec<VoiceCommand> {
+    state.voice_commands.lock().unwrap().clone()
+}
+
+/// Replace the voice-command grammar. When a final transcript is exactly one of
+/// these phrases, stop_recording emits "voice-command" instead of returning text.
+#[tauri::command]
+pub fn set_voice_commands(
+    state: State<AudioState>,
+    commands: Vec<VoiceCommand>,
+) -> Result<(), String> {
+    if let Some(empty) = commands.iter().find(|c| c.phrase.trim().is_empty()) {
+        return Err(format!(
+            "Voice command for action '{}' has an empty phrase",
+            empty.action
+        ));
+    }
+    *state.voice_commands.lock().unwrap() = commands;
+    Ok(())
+}
+
+/// Return the window/hop used by the live VAD gate
+#[tauri::command]
+pub fn get_live_vad_frame(state: State<AudioState>) -> LiveVadFrameConfig {
+    state.vad.lock().unwrap().live_frame_config()
+}
+
+/// Set the live VAD gate's window and hop in milliseconds (frame 10–1000, hop ≤ frame).
+#[tauri::command]
+pub fn set_live_vad_frame(
+    state: State<AudioState>,
+    frame_ms: u32,
+    hop_ms: u32,
+) -> Result<(), String> {
+    state
+        .vad
+        .lock()
+        .unwrap()
+        .set_live_frame_config(LiveVadFrameConfig { frame_ms, hop_ms })
+}
+
+/// Return the VAD sensitivity and the thresholds it currently maps to
+#[tauri::command]
+pub fn get_vad_sensitivity(state: State<AudioState>) -> VadSensitivity {
+    state.vad.lock().unwrap().sensitivity()
+}
+
+/// Set VAD sensitivity (0.0 = only clear speech, 1.0 = pick up quiet voices; 0.5 =
+/// default). Applies to the live gate and every hysteresis pass (final pass,
+/// silence trimming, file transcription).
+/// Returns the effective thresholds so the slider can reflect them.
+#[tauri::command]
+pub fn set_vad_sensitivity(
+    state: State<AudioState>,
+    sensitivity: f32,
+) -> Result<VadSensitivity, String> {
+    let effective = state.vad.lock().unwrap().set_sensitivity(sensitivity)?;
+    println!(
+        "[VAD] Sensitivity {:.2} → energy threshold {:.4}, speech cutoff {:.2}",
+        effective.sensitivity, effective.energy_threshold, effective.speech_cutoff
+    );
+    Ok(effective)
+}
+
+/// Return the Parakeet Nemotron streaming sub-chunk size in milliseconds
+#[tauri::command]
+pub fn get_nemotron_subchunk_ms(state: State<AudioState>) -> u32 {
+    state.parakeet.lock().unwrap().nemotron_subchunk_ms()
+}
+
+/// Set the Parakeet Nemotron streaming sub-chunk size (80–560 ms, multiple of 10).
+/// Applies to the next live chunk; no model reload needed.
+#[tauri::command]
+pub fn set_nemotron_subchunk_ms(state: State<AudioState>, ms: u32) -> Result<(), String> {
+    state.parakeet.lock().unwrap().set_nemotron_subchunk_ms(ms)
+}
+
+/// Export every persisted setting (engine, model, hotkey, device, thresholds,
+/// replacements, …) as a JSON string for backup or moving to another machine.
+#[tauri::command]
+pub fn export_settings(app: AppHandle) -> Result<String, String> {
+    let store = app
+        .store(SETTINGS_STORE)
+        .map_err(|e| format!("Failed to open settings: {}", e))?;
+    let export = SettingsExport {
+        version: SETTINGS_EXPORT_VERSION,
+        settings: store.entries().into_iter().collect(),
+    };
+    serde_json::to_string_pretty(&export).map_err(|e| format!("Failed to export settings: {}", e))
+}
+
+/// Write settings produced by `export_settings` into settings.json. Keys missing
+/// from the blob are left untouched. Returns how many keys were written; the
+/// frontend restores settings at startup, so relaunch to apply them.
+#[tauri::command]
+pub fn import_settings(app: AppHandle, json: String) -> Result<usize, String> {
+    let export: SettingsExport =
+        serde_json::from_str(&json).map_err(|e| format!("Invalid settings file: {}", e))?;
+    if export.version > SETTINGS_EXPORT_VERSION {
+        return Err(format!(
+            "Settings were exported by a newer version of Taurscribe (format {})",
+            export.version
+        ));
+    }
+    let store = app
+        .store(SETTINGS_STORE)
+        .map_err(|e| format!("Failed to open settings: {}", e))?;
+    let count = export.settings.len();
+    for (key, value) in export.settings {
+        store.set(key, value);
+    }
+    store
+        .save()
+        .map_err(|e| format!("Failed to save settings: {}", e))?;
+    println!("[INFO] Imported {} settings", count);
+    Ok(count)
+}
+
 /// Update the system tray icon manually from the frontend
 #[tauri::command]
 pub fn set_tray_state(
@@ -186,6 +892,7 @@ pub fn set_tray_state(
         "ready" => AppState::Ready,
         "recording" => AppState::Recording,
         "processing" => AppState::Processing,
+        "paused" => AppState::Paused,
         _ => return Err(format!("Unknown state: {}", new_state)),
     };
 
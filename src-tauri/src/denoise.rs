@@ -11,6 +11,11 @@ pub struct Denoiser {
     state: Box<DenoiseState<'static>>,
     /// Leftover samples from the previous `process` call that didn't fill a full frame.
     remainder: Vec<f32>,
+    /// Wet/dry mix: 1.0 = fully denoised (default), 0.0 = original signal.
+    strength: f32,
+    /// The previous input frame. RNNoise output lags its input by one frame, so
+    /// the dry signal is blended from here to stay aligned (no comb filtering).
+    dry_delay: [f32; FRAME_SIZE],
 }
 
 impl Denoiser {
@@ -18,10 +23,23 @@ impl Denoiser {
         Self {
             state: DenoiseState::new(),
             remainder: Vec::with_capacity(FRAME_SIZE),
+            strength: 1.0,
+            dry_delay: [0.0; FRAME_SIZE],
         }
     }
 
+    /// Set how much of the denoised signal is kept (clamped to 0.0–1.0). Lower values
+    /// blend the original back in when suppression eats soft consonants.
+    pub fn set_strength(&mut self, strength: f32) {
+        self.strength = if strength.is_finite() {
+            strength.clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+    }
+
     /// Denoise an arbitrarily-sized chunk of mono f32 audio at 48 kHz.
+    /// Other rates must be resampled first (or skipped): RNNoise has no rate parameter.
     ///
     /// Buffers leftover samples between calls so callers don't need to worry
     /// about frame alignment. Returns all complete denoised frames; any
@@ -43,6 +61,12 @@ impl Denoiser {
                 src = &src[need..];
 
                 self.state.process_frame(&mut out_frame, &self.remainder);
+                blend_dry(
+                    self.strength,
+                    &mut self.dry_delay,
+                    &mut out_frame,
+                    &self.remainder,
+                );
                 output.extend_from_slice(&out_frame);
                 self.remainder.clear();
             } else {
@@ -54,6 +78,12 @@ impl Denoiser {
         // Process as many full frames as possible from the remaining input.
         while src.len() >= FRAME_SIZE {
             self.state.process_frame(&mut out_frame, &src[..FRAME_SIZE]);
+            blend_dry(
+                self.strength,
+                &mut self.dry_delay,
+                &mut out_frame,
+                &src[..FRAME_SIZE],
+            );
             output.extend_from_slice(&out_frame);
             src = &src[FRAME_SIZE..];
         }
@@ -66,3 +96,14 @@ impl Denoiser {
         output
     }
 }
+
+/// Mix the dry signal back into a denoised frame according to `strength`, using the
+/// delayed frame that lines up with `out`, then queue `dry` as the next delayed frame.
+fn blend_dry(strength: f32, delayed: &mut [f32; FRAME_SIZE], out: &mut [f32], dry: &[f32]) {
+    if strength < 1.0 {
+        for (o, &d) in out.iter_mut().zip(delayed.iter()) {
+            *o = strength * *o + (1.0 - strength) * d;
+        }
+    }
+    delayed.copy_from_slice(dry);
+}
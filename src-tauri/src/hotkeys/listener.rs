@@ -1,5 +1,5 @@
 use crate::types::{HotkeyBinding, RecordingMode};
-use rdev::{listen, Event, EventType, Key};
+use rdev::{listen, Button, Event, EventType, Key};
 use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc, Mutex, RwLock,
@@ -37,12 +37,37 @@ fn key_to_code(key: &Key) -> Option<&'static str> {
     }
 }
 
-/// Start the global keyboard listener. Reads hotkey_config on every event so
+/// True when every key of a non-empty combo is currently held.
+fn combo_held(keys: &[String], held: &[&str]) -> bool {
+    !keys.is_empty() && keys.iter().all(|k| held.iter().any(|h| k == h))
+}
+
+/// Map an rdev mouse button to a `MOUSE_BUTTON_CODES` name. Left/right clicks are never
+/// hotkeys. rdev reports side buttons as `Unknown(n)` with a platform-specific number.
+fn button_to_code(button: &Button) -> Option<&'static str> {
+    #[cfg(target_os = "windows")]
+    const SIDE_BUTTONS: (u8, u8) = (1, 2); // XBUTTON1 / XBUTTON2
+    #[cfg(target_os = "macos")]
+    const SIDE_BUTTONS: (u8, u8) = (3, 4); // CGEvent button numbers
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    const SIDE_BUTTONS: (u8, u8) = (8, 9); // X11 buttons 8 / 9
+
+    match button {
+        Button::Middle => Some("MouseMiddle"),
+        Button::Unknown(n) if *n == SIDE_BUTTONS.0 => Some("MouseBack"),
+        Button::Unknown(n) if *n == SIDE_BUTTONS.1 => Some("MouseForward"),
+        _ => None,
+    }
+}
+
+/// Start the global keyboard and mouse-button listener. Reads hotkey_config on every event so
 /// changes take effect immediately without restarting the thread.
+/// `recording_active` is shared with AudioState so sessions ended elsewhere reset it.
 pub fn start_hotkey_listener(
     app_handle: tauri::AppHandle,
     hotkey_config: Arc<RwLock<HotkeyBinding>>,
     hotkey_suppressed: Arc<AtomicBool>,
+    recording_active: Arc<AtomicBool>,
 ) {
     use tauri::Emitter;
 
@@ -72,12 +97,14 @@ pub fn start_hotkey_listener(
         }
     }
 
-    let recording_active = Arc::new(AtomicBool::new(false));
     // &'static str: key_to_code() returns static strings — no String allocation per keypress
     let held_keys: Arc<Mutex<Vec<&'static str>>> =
         Arc::new(Mutex::new(Vec::with_capacity(MAX_HOTKEY_KEYS)));
     // Prevents keyboard auto-repeat from firing the action multiple times per physical press.
     let combo_triggered = Arc::new(AtomicBool::new(false));
+    // Same, per combo, when separate start/stop combos are configured.
+    let start_triggered = AtomicBool::new(false);
+    let stop_triggered = AtomicBool::new(false);
 
     let recording_active_c = recording_active.clone();
     let held_keys_c = held_keys.clone();
@@ -94,68 +121,93 @@ pub fn start_hotkey_listener(
 
         let config = config_c.read().unwrap().clone();
 
-        if config.keys.len() != MAX_HOTKEY_KEYS {
+        if !config.is_valid() {
             return;
         }
 
-        match event.event_type {
-            EventType::KeyPress(key) => {
-                if let Some(code) = key_to_code(&key) {
-                    let mut held = held_keys_c.lock().unwrap();
-                    if config.keys.iter().any(|k| k == code) && !held.contains(&code) {
-                        held.push(code);
-                    }
-                    let all_held = config.keys.iter().all(|k| held.iter().any(|h| k == h));
-                    if all_held
-                        && !config.keys.is_empty()
-                        && !combo_triggered_c.load(Ordering::SeqCst)
-                    {
-                        combo_triggered_c.store(true, Ordering::SeqCst);
-                        drop(held);
-                        match config.mode {
-                            RecordingMode::Hold => {
-                                if !recording_active_c.load(Ordering::SeqCst) {
-                                    recording_active_c.store(true, Ordering::SeqCst);
-                                    println!("[HOTKEY] Hold — starting recording");
-                                    let _ = app_c.emit("hotkey-start-recording", ());
-                                }
-                            }
-                            RecordingMode::Toggle => {
-                                if recording_active_c.load(Ordering::SeqCst) {
-                                    recording_active_c.store(false, Ordering::SeqCst);
-                                    println!("[HOTKEY] Toggle — stopping recording");
-                                    let _ = app_c.emit("hotkey-stop-recording", ());
-                                } else {
-                                    recording_active_c.store(true, Ordering::SeqCst);
-                                    println!("[HOTKEY] Toggle — starting recording");
-                                    let _ = app_c.emit("hotkey-start-recording", ());
-                                }
-                            }
-                        }
-                    }
+        let (code, pressed) = match event.event_type {
+            EventType::KeyPress(key) => (key_to_code(&key), true),
+            EventType::KeyRelease(key) => (key_to_code(&key), false),
+            EventType::ButtonPress(button) => (button_to_code(&button), true),
+            EventType::ButtonRelease(button) => (button_to_code(&button), false),
+            _ => return,
+        };
+        let Some(code) = code else {
+            return;
+        };
+
+        // Separate start/stop combos: each fires only its own event; `mode` doesn't apply.
+        if let Some((start_keys, stop_keys)) = config.split_combos() {
+            let mut held = held_keys_c.lock().unwrap();
+            if pressed {
+                if start_keys.iter().chain(stop_keys).any(|k| k == code) && !held.contains(&code) {
+                    held.push(code);
+                }
+                if combo_held(start_keys, &held) && !start_triggered.swap(true, Ordering::SeqCst) {
+                    recording_active_c.store(true, Ordering::SeqCst);
+                    println!("[HOTKEY] Start combo — starting recording");
+                    let _ = app_c.emit("hotkey-start-recording", ());
+                } else if combo_held(stop_keys, &held)
+                    && !stop_triggered.swap(true, Ordering::SeqCst)
+                {
+                    recording_active_c.store(false, Ordering::SeqCst);
+                    println!("[HOTKEY] Stop combo — stopping recording");
+                    let _ = app_c.emit("hotkey-stop-recording", ());
+                }
+            } else {
+                held.retain(|k| *k != code);
+                if start_keys.iter().any(|k| k == code) {
+                    start_triggered.store(false, Ordering::SeqCst);
+                }
+                if stop_keys.iter().any(|k| k == code) {
+                    stop_triggered.store(false, Ordering::SeqCst);
                 }
             }
+            return;
+        }
 
-            EventType::KeyRelease(key) => {
-                if let Some(code) = key_to_code(&key) {
-                    held_keys_c.lock().unwrap().retain(|k| *k != code);
-                    if config.keys.iter().any(|k| k == code) {
-                        // Reset so the next physical key press can trigger the combo again.
-                        combo_triggered_c.store(false, Ordering::SeqCst);
-                        // Hold mode: releasing any combo key stops recording.
-                        // Toggle mode: key releases have no effect on recording state.
-                        if config.mode == RecordingMode::Hold
-                            && recording_active_c.load(Ordering::SeqCst)
-                        {
+        if pressed {
+            let mut held = held_keys_c.lock().unwrap();
+            if config.keys.iter().any(|k| k == code) && !held.contains(&code) {
+                held.push(code);
+            }
+            if combo_held(&config.keys, &held) && !combo_triggered_c.load(Ordering::SeqCst) {
+                combo_triggered_c.store(true, Ordering::SeqCst);
+                drop(held);
+                match config.mode {
+                    RecordingMode::Hold => {
+                        if !recording_active_c.load(Ordering::SeqCst) {
+                            recording_active_c.store(true, Ordering::SeqCst);
+                            println!("[HOTKEY] Hold — starting recording");
+                            let _ = app_c.emit("hotkey-start-recording", ());
+                        }
+                    }
+                    RecordingMode::Toggle => {
+                        if recording_active_c.load(Ordering::SeqCst) {
                             recording_active_c.store(false, Ordering::SeqCst);
-                            println!("[HOTKEY] Hold — stopping recording");
+                            println!("[HOTKEY] Toggle — stopping recording");
                             let _ = app_c.emit("hotkey-stop-recording", ());
+                        } else {
+                            recording_active_c.store(true, Ordering::SeqCst);
+                            println!("[HOTKEY] Toggle — starting recording");
+                            let _ = app_c.emit("hotkey-start-recording", ());
                         }
                     }
                 }
             }
-
-            _ => {}
+        } else {
+            held_keys_c.lock().unwrap().retain(|k| *k != code);
+            if config.keys.iter().any(|k| k == code) {
+                // Reset so the next physical key press can trigger the combo again.
+                combo_triggered_c.store(false, Ordering::SeqCst);
+                // Hold mode: releasing any combo key stops recording.
+                // Toggle mode: key releases have no effect on recording state.
+                if config.mode == RecordingMode::Hold && recording_active_c.load(Ordering::SeqCst) {
+                    recording_active_c.store(false, Ordering::SeqCst);
+                    println!("[HOTKEY] Hold — stopping recording");
+                    let _ = app_c.emit("hotkey-stop-recording", ());
+                }
+            }
         }
     };
 
@@ -1,4 +1,5 @@
 // Module declarations
+mod asr;
 mod audio;
 pub mod audio_decode;
 pub mod audio_preprocess;
@@ -16,7 +17,10 @@ mod overlay;
 pub mod parakeet;
 pub mod parakeet_loaders;
 mod parakeet_runtime;
+mod postprocess;
+mod spellcheck;
 mod state;
+mod subtitles;
 mod system_audio;
 mod tray;
 mod types;
@@ -49,6 +53,7 @@ fn cleanup_before_exit(app_handle: &tauri::AppHandle) {
     // Metal resource sets, causing ggml_abort → SIGABRT on quit.
     println!("[EXIT] App exiting — cleaning up AI engine resources...");
     if let Some(state) = app_handle.try_state::<AudioState>() {
+        commands::stop_recording_for_exit(app_handle, &state);
         if let Ok(mut whisper) = state.whisper.lock() {
             whisper.unload();
         }
@@ -85,7 +90,7 @@ pub fn run() {
     println!("[INFO] Initializing Whisper transcription engine manager...");
     let whisper = WhisperManager::new();
     let whisper_loaded_at_startup = false;
-    println!("[INFO] Whisper startup load disabled; model will load on demand");
+    println!("[INFO] Whisper model loads on demand (or from whisper_model once the app is up)");
 
     // 2. Initialize VAD
     println!("[INFO] Initializing Voice Activity Detection...");
@@ -152,17 +157,34 @@ pub fn run() {
                 tray::update_tray_model_item(app.handle(), false);
             }
 
+            // Restore engine / hotkey / input device before the hotkey listener starts.
+            commands::apply_persisted_settings(app.handle());
+            commands::sweep_unkept_recording(app.handle());
+
+            // Load the user's preferred Whisper model (settings.json `whisper_model`).
+            commands::load_default_model_on_startup(app.handle());
+
             // Start Hotkey Listener in Background Thread
             // Clone the hotkey_config Arc so the listener reacts to config changes immediately.
             let hotkey_config = app.state::<AudioState>().hotkey_config.clone();
             let hotkey_suppressed = app.state::<AudioState>().hotkey_suppressed.clone();
+            let hotkey_recording_active = app.state::<AudioState>().hotkey_recording_active.clone();
             let app_handle = app.handle().clone();
             std::thread::spawn(move || {
-                hotkeys::start_hotkey_listener(app_handle, hotkey_config, hotkey_suppressed);
+                hotkeys::start_hotkey_listener(
+                    app_handle,
+                    hotkey_config,
+                    hotkey_suppressed,
+                    hotkey_recording_active,
+                );
             });
 
             println!("[INFO] Global hotkey listener started (configurable hotkey)");
 
+            // Idle until the user enables the GPU keep-alive setting.
+            let keepalive_state = (*app.state::<AudioState>()).clone();
+            std::thread::spawn(move || commands::run_gpu_keepalive(keepalive_state));
+
             // Start File Watcher for Models Directory
             let watcher_handle = app.handle().clone();
             if let Err(e) = watcher::start_models_watcher(watcher_handle) {
@@ -172,6 +194,16 @@ pub fn run() {
             Ok(())
         })
         .on_window_event(|window, event| {
+            if let tauri::WindowEvent::Focused(focused) = event {
+                if window.label() == "main" {
+                    use std::sync::atomic::Ordering;
+                    window
+                        .app_handle()
+                        .state::<AudioState>()
+                        .window_focused
+                        .store(*focused, Ordering::Relaxed);
+                }
+            }
             if let tauri::WindowEvent::CloseRequested { api, .. } = event {
                 // Check the user's preferred close behavior (persisted in settings.json
                 // and applied to AudioState at startup via set_close_behavior command).
@@ -202,16 +234,23 @@ pub fn run() {
             commands::show_main_window,
             commands::get_system_info,
             commands::get_process_memory_stats,
+            commands::preview_cleanup,
+            commands::expand_snippets,
             commands::start_recording,
             commands::stop_recording,
+            commands::quick_note,
             commands::get_backend_info,
             commands::get_engine_selection_state,
             commands::list_models,
             commands::get_current_model,
+            commands::check_model_language,
             commands::switch_model,
             commands::list_parakeet_models,
             commands::init_parakeet,
+            commands::switch_parakeet_model,
             commands::get_parakeet_status,
+            commands::warmup_engines,
+            commands::preload_engine,
             commands::set_active_engine,
             commands::get_active_engine,
             commands::set_tray_state,
@@ -220,21 +259,34 @@ pub fn run() {
             commands::unload_llm,
             commands::run_llm_inference,
             commands::check_llm_status,
+            commands::get_llm_gpu_layers,
             commands::correct_text,
+            commands::correct_text_deferred,
+            commands::correct_text_streaming,
+            commands::cancel_llm,
             commands::type_text,
+            commands::test_insertion,
+            commands::copy_to_clipboard,
             commands::save_transcript_history,
             commands::list_transcript_history,
             commands::delete_transcript_history,
             commands::download_model,
             commands::cancel_download,
+            commands::get_hf_endpoint,
+            commands::set_hf_endpoint,
             commands::get_download_status,
+            commands::get_storage_summary,
             commands::delete_model,
+            commands::clear_verification,
             commands::get_platform,
             commands::is_apple_silicon,
             commands::get_hotkey,
             commands::set_hotkey,
             commands::set_hotkey_suppressed,
             commands::list_input_devices,
+            commands::has_input_device,
+            commands::get_device_capabilities,
+            commands::list_loopback_devices,
             commands::get_active_input_device,
             commands::set_input_device,
             commands::show_overlay,
@@ -258,14 +310,77 @@ pub fn run() {
             commands::factory_reset_app_data,
             commands::get_close_behavior,
             commands::set_close_behavior,
+            commands::get_keep_recordings,
+            commands::set_keep_recordings,
+            commands::get_max_recording_secs,
+            commands::set_max_recording_secs,
+            commands::get_live_chunk_secs,
+            commands::set_live_chunk_secs,
+            commands::get_final_pass_per_segment,
+            commands::set_final_pass_per_segment,
+            commands::get_final_pass_vad,
+            commands::set_final_pass_vad,
+            commands::get_parakeet_final_pass,
+            commands::set_parakeet_final_pass,
+            commands::get_vad_merge_gap_ms,
+            commands::set_vad_merge_gap_ms,
+            commands::get_trim_recording_silence,
+            commands::set_trim_recording_silence,
+            commands::get_whisper_token_suppression,
+            commands::set_whisper_token_suppression,
+            commands::get_whisper_warmup,
+            commands::set_whisper_warmup,
+            commands::get_whisper_language,
+            commands::set_whisper_language,
+            commands::get_whisper_translate,
+            commands::set_whisper_translate,
+            commands::get_default_model,
+            commands::set_default_model,
+            commands::get_buffer_drop_policy,
+            commands::set_buffer_drop_policy,
+            commands::get_post_recording_action,
+            commands::set_post_recording_action,
+            commands::get_paste_delays,
+            commands::set_paste_delays,
+            commands::get_spellcheck_enabled,
+            commands::set_spellcheck_enabled,
+            commands::spellcheck_add_words,
+            commands::get_spellcheck_options,
+            commands::set_spellcheck_options,
+            commands::get_typing_mode,
+            commands::set_typing_mode,
+            commands::get_gpu_keepalive,
+            commands::set_gpu_keepalive,
+            commands::get_voice_commands,
+            commands::set_voice_commands,
+            commands::export_settings,
+            commands::import_settings,
+            commands::get_live_vad_frame,
+            commands::set_live_vad_frame,
+            commands::get_vad_sensitivity,
+            commands::set_vad_sensitivity,
+            commands::get_nemotron_subchunk_ms,
+            commands::set_nemotron_subchunk_ms,
             commands::init_cohere,
             commands::get_cohere_status,
             commands::list_cohere_models,
             commands::pause_recording,
             commands::resume_recording,
+            commands::switch_input_device,
             commands::cancel_recording,
+            commands::get_recent_chunks,
+            commands::get_recording_status,
             commands::transcribe_file,
-            commands::cancel_file_transcription
+            commands::transcribe_files,
+            commands::cancel_file_transcription,
+            commands::transcribe_nbest,
+            commands::transcribe_file_timestamps,
+            commands::export_subtitles,
+            commands::get_waveform,
+            commands::export_recording,
+            commands::retranscribe_last,
+            commands::benchmark_suite,
+            commands::measure_rtf
         ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
@@ -1,6 +1,7 @@
 //! LLM engine for transcript grammar correction.
 //! Loads FlowScribe Qwen 2.5 0.5B (GGUF Q4_K_M) from %LOCALAPPDATA%\Taurscribe\models\qwen_finetuned_gguf.
-//! n_gpu_layers=0 forces CPU; change to -1 or layer count for GPU.
+//! `LLMEngine::new(false, ..)` keeps the model fully on CPU: 0 GPU layers and no KV-cache offload,
+//! so it never competes with Whisper/Parakeet for VRAM.
 
 use anyhow::{Error, Result};
 use llama_cpp_2::llama_backend::LlamaBackend;
@@ -9,14 +10,23 @@ use llama_cpp_2::model::params::LlamaModelParams;
 use llama_cpp_2::model::{AddBos, LlamaModel};
 use llama_cpp_2::sampling::LlamaSampler;
 use llama_cpp_2::token::LlamaToken;
+use std::num::NonZeroU32;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
 
-const GGUF_FILENAME: &str = "model_q4_k_m.gguf";
+pub(crate) const GGUF_FILENAME: &str = "model_q4_k_m.gguf";
+
+/// Downloader subfolder for the fine-tuned FlowScribe model.
+const FINETUNED_DIR: &str = "qwen_finetuned_gguf";
+/// Folder name used when the stock Qwen2.5 instruct GGUF is placed in models/ instead.
+const INSTRUCT_DIR: &str = "Qwen2.5-0.5B-Instruct";
 
 /// Global backend instance (initialized once)
 static BACKEND: OnceLock<Arc<LlamaBackend>> = OnceLock::new();
 
-/// Grammar LLM model path: GRAMMAR_LLM_DIR env override, or AppData default.
+/// Grammar LLM model folder, first one containing `GGUF_FILENAME` of:
+/// GRAMMAR_LLM_DIR env override, models/qwen_finetuned_gguf, models/Qwen2.5-0.5B-Instruct.
+/// Falls back to models/qwen_finetuned_gguf so "not found" errors name the download location.
 pub fn get_grammar_llm_dir() -> Result<std::path::PathBuf, String> {
     // 1. Explicit path from env override
     if let Ok(dir) = std::env::var("GRAMMAR_LLM_DIR") {
@@ -25,11 +35,19 @@ pub fn get_grammar_llm_dir() -> Result<std::path::PathBuf, String> {
             return Ok(path);
         }
     }
-    // 2. AppData/Taurscribe/models/qwen_finetuned_gguf
+    // 2./3. AppData/Taurscribe/models/<subfolder>
     let models_dir = crate::utils::get_models_dir()?;
-    Ok(models_dir.join("qwen_finetuned_gguf"))
+    let default_dir = models_dir.join(FINETUNED_DIR);
+    Ok([default_dir.clone(), models_dir.join(INSTRUCT_DIR)]
+        .into_iter()
+        .find(|dir| dir.join(GGUF_FILENAME).exists())
+        .unwrap_or(default_dir))
 }
 
+/// Context window used when `init_llm` isn't given one. Fits a ~2000-word transcript
+/// plus its correction; llama.cpp's default is far smaller.
+pub const DEFAULT_N_CTX: u32 = 8192;
+
 // Internal structure that holds model and context together
 struct ModelContext {
     model: LlamaModel,
@@ -45,18 +63,33 @@ pub struct LLMEngine {
     model_context: Mutex<ModelContext>,
     eos_token_id: LlamaToken,
     eos_im_end_id: LlamaToken,
+    gpu_layers: u32,
+    n_ctx: u32,
+    /// Set from outside (see `cancel_llm`) to stop the current generation early.
+    cancel: Arc<AtomicBool>,
 }
 
 impl LLMEngine {
-    /// Create LLM from taurscribe-runtime/models/qwen_finetuned_gguf (or AppData fallback).
+    /// Create LLM from the folder chosen by `get_grammar_llm_dir`.
     /// Uses CUDA when available (via llama-cpp-2 features) and use_gpu is true.
-    pub fn new(use_gpu: bool) -> Result<Self> {
+    /// `n_ctx` is the context window (prompt + output tokens) and also the decode batch size,
+    /// so a whole prompt is prefilled at once. `n_threads` None keeps llama.cpp's default.
+    /// Setting `cancel` stops a running generation; it is cleared at the start of each run.
+    pub fn new(
+        use_gpu: bool,
+        n_ctx: u32,
+        n_threads: Option<u32>,
+        cancel: Arc<AtomicBool>,
+    ) -> Result<Self> {
+        let n_ctx = NonZeroU32::new(n_ctx)
+            .ok_or_else(|| Error::msg("LLM context size must be greater than 0"))?;
         let base_path = get_grammar_llm_dir().map_err(Error::msg)?;
         let model_path = base_path.join(GGUF_FILENAME);
 
         if !model_path.exists() {
             return Err(Error::msg(format!(
-                "Grammar LLM model not found. Expected at: {:?}\nDownload FlowScribe Qwen 2.5 0.5B via the Downloads tab.",
+                "Grammar LLM model not found. Expected {} at: {:?}\nDownload FlowScribe Qwen 2.5 0.5B via the Downloads tab.",
+                GGUF_FILENAME,
                 model_path
             )));
         }
@@ -87,6 +120,8 @@ impl LLMEngine {
             use_gpu, requested_layers
         );
 
+        // Always set the layer count explicitly — llama.cpp's default offloads every layer
+        // on GPU-enabled builds, so relying on the default would put a "CPU" model in VRAM.
         let model_params = LlamaModelParams::default().with_n_gpu_layers(requested_layers);
 
         let (model, loaded_layers) =
@@ -110,10 +145,14 @@ impl LLMEngine {
                 }
             };
 
-        println!(
-            "[LLM] Model loaded successfully. GPU Layers: {}",
-            loaded_layers
-        );
+        if loaded_layers == 0 {
+            println!("[LLM] Model loaded successfully. GPU Layers: 0 (CPU only)");
+        } else {
+            println!(
+                "[LLM] Model loaded successfully. GPU Layers: {}",
+                loaded_layers
+            );
+        }
 
         // Get EOS tokens
         let eos_token_id = model.token_eos();
@@ -140,8 +179,22 @@ impl LLMEngine {
             eos_token_id, eos_im_end_id
         );
 
-        // Create context with default params
-        let context_params = llama_cpp_2::context::params::LlamaContextParams::default();
+        // Create context with default params. On CPU, also keep the KV cache in system RAM:
+        // offload_kqv defaults to true and would still allocate it on the GPU.
+        let mut context_params = llama_cpp_2::context::params::LlamaContextParams::default()
+            .with_offload_kqv(loaded_layers > 0)
+            .with_n_ctx(Some(n_ctx))
+            .with_n_batch(n_ctx.get());
+        if let Some(threads) = n_threads.filter(|&t| t > 0) {
+            context_params = context_params
+                .with_n_threads(threads as i32)
+                .with_n_threads_batch(threads as i32);
+        }
+        println!(
+            "[LLM] Context: n_ctx={}, threads={}",
+            n_ctx,
+            n_threads.map_or("default".to_string(), |t| t.to_string())
+        );
         let context = model
             .new_context(&backend, context_params)
             .map_err(|e| Error::msg(format!("Failed to create context: {}", e)))?;
@@ -155,9 +208,27 @@ impl LLMEngine {
             model_context: Mutex::new(model_context),
             eos_token_id,
             eos_im_end_id,
+            gpu_layers: loaded_layers,
+            n_ctx: n_ctx.get(),
+            cancel,
         })
     }
 
+    /// True when the last generation was stopped through the cancel flag (its output is partial).
+    pub fn was_cancelled(&self) -> bool {
+        self.cancel.load(Ordering::Relaxed)
+    }
+
+    /// Context window the model was loaded with.
+    pub fn n_ctx(&self) -> u32 {
+        self.n_ctx
+    }
+
+    /// Number of model layers offloaded to the GPU (0 = CPU only).
+    pub fn gpu_layers(&self) -> u32 {
+        self.gpu_layers
+    }
+
     /// Run generation. `max_gen_tokens` caps output length; lower = faster for short tasks.
     /// `temperature` 0.0–1.0; lower = more deterministic, often stops sooner (e.g. 0.3 for correction).
     pub fn run_with_options(
@@ -165,6 +236,19 @@ impl LLMEngine {
         prompt: &str,
         max_gen_tokens: usize,
         temperature: f64,
+    ) -> Result<String> {
+        self.run_streaming(prompt, max_gen_tokens, temperature, |_| {})
+    }
+
+    /// Same as `run_with_options`, but calls `emit` with each decoded piece as it is generated.
+    /// Pieces are raw (untrimmed, EOS excluded); the returned string is the cleaned full output,
+    /// or whatever was generated before the cancel flag was set.
+    pub fn run_streaming(
+        &mut self,
+        prompt: &str,
+        max_gen_tokens: usize,
+        temperature: f64,
+        mut emit: impl FnMut(&str),
     ) -> Result<String> {
         use std::io::Write;
 
@@ -175,6 +259,7 @@ impl LLMEngine {
         // CRITICAL: Clear KV cache to ensure fresh context for every request
         // This prevents "inconsistent sequence positions" errors on subsequent runs.
         mc.context.clear_kv_cache();
+        self.cancel.store(false, Ordering::Relaxed);
 
         // Encode prompt using model's built-in tokenizer
         let prompt_tokens = mc
@@ -185,6 +270,23 @@ impl LLMEngine {
 
         println!("[LLM] Prompt tokens: {}", prompt_tokens_len);
 
+        // Prompt and output share the context window; fail loudly instead of truncating.
+        let n_ctx = self.n_ctx as usize;
+        if prompt_tokens_len >= n_ctx {
+            return Err(Error::msg(format!(
+                "Prompt is {} tokens but the LLM context holds {}. Reload the LLM with a larger n_ctx.",
+                prompt_tokens_len, n_ctx
+            )));
+        }
+        if max_gen_tokens > n_ctx - prompt_tokens_len {
+            println!(
+                "[LLM] Capping output at {} tokens (context {})",
+                n_ctx - prompt_tokens_len,
+                n_ctx
+            );
+        }
+        let max_gen_tokens = max_gen_tokens.min(n_ctx - prompt_tokens_len);
+
         // Create sampler chain: temperature -> top_p -> greedy
         let mut sampler = LlamaSampler::chain_simple([
             LlamaSampler::temp(temperature as f32),
@@ -195,9 +297,9 @@ impl LLMEngine {
         // UTF-8 decoder for token_to_piece
         let mut decoder = encoding_rs::UTF_8.new_decoder();
 
-        // Prefill: process all prompt tokens at once
+        // Prefill: process all prompt tokens at once (n_batch == n_ctx, so any prompt that fits does)
         let prefill_start = std::time::Instant::now();
-        let mut batch = LlamaBatch::new(prompt_tokens_len.max(512), 1);
+        let mut batch = LlamaBatch::new(n_ctx, 1);
 
         // Add all prompt tokens to batch (pos is i32)
         let last_index = prompt_tokens_len as i32 - 1;
@@ -212,11 +314,32 @@ impl LLMEngine {
             .decode(&mut batch)
             .map_err(|e| Error::msg(format!("Failed to decode prompt: {}", e)))?;
 
+        let is_eos = |mc: &ModelContext, token: LlamaToken| {
+            token == self.eos_token_id
+                || token == self.eos_im_end_id
+                || mc.model.is_eog_token(token)
+        };
+        // Decode pieces as they arrive (token_to_piece, non-deprecated API) so `emit` sees them live.
+        let mut decoded = String::new();
+        let mut push_piece = |mc: &ModelContext, token: LlamaToken| {
+            if is_eos(mc, token) {
+                return;
+            }
+            // skip undecodable tokens
+            if let Ok(piece) = mc.model.token_to_piece(token, &mut decoder, true, None) {
+                if !piece.is_empty() {
+                    emit(&piece);
+                    decoded.push_str(&piece);
+                }
+            }
+        };
+
         // Sample first token
         let mut next_token = sampler.sample(&mc.context, batch.n_tokens() - 1);
         sampler.accept(next_token);
+        push_piece(&mc, next_token);
 
-        let mut generated_tokens = vec![next_token];
+        let mut gen_tokens = 1;
         let prefill_time = prefill_start.elapsed();
         let mut n_cur = batch.n_tokens();
 
@@ -230,13 +353,14 @@ impl LLMEngine {
         // Decode loop: generate one token at a time
         let gen_start = std::time::Instant::now();
         for i in 0..max_gen_tokens {
-            if next_token == self.eos_token_id
-                || next_token == self.eos_im_end_id
-                || mc.model.is_eog_token(next_token)
-            {
+            if is_eos(&mc, next_token) {
                 println!(" [EOS at token {}]", i);
                 break;
             }
+            if self.cancel.load(Ordering::Relaxed) {
+                println!(" [cancelled at token {}]", i);
+                break;
+            }
             if i % 10 == 0 {
                 print!(".");
                 std::io::stdout().flush().ok();
@@ -257,28 +381,19 @@ impl LLMEngine {
             next_token = sampler.sample(&mc.context, batch.n_tokens() - 1);
             sampler.accept(next_token);
 
-            generated_tokens.push(next_token);
+            push_piece(&mc, next_token);
+            gen_tokens += 1;
             n_cur += 1;
         }
         let gen_time = gen_start.elapsed();
         println!();
 
-        // Decode tokens back to string using token_to_piece (non-deprecated API)
-        let mut decoded = String::new();
-        for &tok in &generated_tokens {
-            match mc.model.token_to_piece(tok, &mut decoder, true, None) {
-                Ok(piece) => decoded.push_str(&piece),
-                Err(_) => {} // skip undecodable tokens
-            }
-        }
-
         let cleaned = decoded
             .replace("<|endoftext|>", "")
             .replace("<|im_end|>", "")
             .trim()
             .to_string();
 
-        let gen_tokens = generated_tokens.len();
         let tokens_per_sec = if gen_time.as_secs_f64() > 0.0 {
             gen_tokens as f64 / gen_time.as_secs_f64()
         } else {
@@ -304,6 +419,16 @@ impl LLMEngine {
     /// acts only as a copy editor (no chat, no greeting, no continuation).
     /// Format transcript with a specific style.
     pub fn format_transcript(&mut self, text: &str, style: Option<&str>) -> Result<String> {
+        self.format_transcript_streaming(text, style, |_| {})
+    }
+
+    /// `format_transcript` that streams the corrected text piece by piece through `emit`.
+    pub fn format_transcript_streaming(
+        &mut self,
+        text: &str,
+        style: Option<&str>,
+        emit: impl FnMut(&str),
+    ) -> Result<String> {
         let text = text.trim();
         if text.is_empty() {
             return Ok(String::new());
@@ -331,6 +456,6 @@ Rules:
         // Correction output is usually close to input length, but we give it room to breathe.
         let max_tokens = (text.len() / 2) + 128;
         let temperature = 0.3; // more deterministic, model tends to EOS sooner
-        self.run_with_options(&prompt, max_tokens, temperature)
+        self.run_streaming(&prompt, max_tokens, temperature, emit)
     }
 }
@@ -1,4 +1,6 @@
-use parakeet_rs::{Nemotron, Parakeet, ParakeetEOU, ParakeetTDT, TimestampMode, Transcriber};
+use parakeet_rs::{
+    Nemotron, Parakeet, ParakeetEOU, ParakeetTDT, TimedToken, TimestampMode, Transcriber,
+};
 use rubato::{
     Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction,
 };
@@ -51,6 +53,11 @@ pub struct ParakeetStatus {
     pub model_type: Option<String>,
     pub backend: String,
     pub load_path: String,
+    /// Language code of the loaded model ("en", or "multilingual" for TDT v3); None when unloaded.
+    pub language: Option<String>,
+    /// True for cache-aware streaming models (Nemotron, EOU) that keep state across
+    /// chunks; CTC/TDT decode each chunk independently.
+    pub supports_streaming: bool,
 }
 
 /// The Manager that controls the Parakeet ASR
@@ -61,8 +68,14 @@ pub struct ParakeetManager {
     load_path: ParakeetLoadPath,
     resampler: Option<(u32, usize, Box<SincFixedIn<f32>>)>, // (Sample Rate, Input Size, Resampler)
     next_runtime_generation: u64,
+    nemotron_subchunk_samples: usize, // 16 kHz samples fed per Nemotron streaming call
 }
 
+/// Nemotron's encoder consumes 56 mel frames (560 ms) per step; feeding more than that per
+/// call would let its internal buffer fall behind, so this is also the upper bound.
+const NEMOTRON_MAX_SUBCHUNK_MS: u32 = 560;
+const NEMOTRON_MIN_SUBCHUNK_MS: u32 = 80;
+
 impl ParakeetManager {
     /// Create a new Parakeet Manager (Constructor)
     pub fn new() -> Self {
@@ -73,9 +86,29 @@ impl ParakeetManager {
             load_path: ParakeetLoadPath::FallbackGpu,
             resampler: None,
             next_runtime_generation: 1,
+            nemotron_subchunk_samples: NEMOTRON_MAX_SUBCHUNK_MS as usize * 16,
         }
     }
 
+    /// Current Nemotron streaming sub-chunk size in milliseconds.
+    pub fn nemotron_subchunk_ms(&self) -> u32 {
+        (self.nemotron_subchunk_samples / 16) as u32
+    }
+
+    /// Set the Nemotron streaming sub-chunk size (80–560 ms, multiple of the 10 ms mel hop).
+    /// Smaller sub-chunks let text surface as soon as each encoder step has enough audio
+    /// and reduce the zero padding added to the tail of every live chunk.
+    pub fn set_nemotron_subchunk_ms(&mut self, ms: u32) -> Result<(), String> {
+        if !(NEMOTRON_MIN_SUBCHUNK_MS..=NEMOTRON_MAX_SUBCHUNK_MS).contains(&ms) || ms % 10 != 0 {
+            return Err(format!(
+                "Nemotron sub-chunk must be a multiple of 10 ms between {} and {} ms, got {}",
+                NEMOTRON_MIN_SUBCHUNK_MS, NEMOTRON_MAX_SUBCHUNK_MS, ms
+            ));
+        }
+        self.nemotron_subchunk_samples = ms as usize * 16;
+        Ok(())
+    }
+
     /// Helper: Find the folder where Parakeet models are stored
     fn get_models_dir() -> Result<PathBuf, String> {
         crate::utils::get_models_dir()
@@ -170,12 +203,31 @@ impl ParakeetManager {
             LoadedModel::Tdt(_) => "TDT".to_string(),
         });
 
+        let supports_streaming = matches!(
+            self.runtime.as_ref().map(|slot| &slot.model),
+            Some(LoadedModel::Nemotron(_)) | Some(LoadedModel::Eou(_))
+        );
+        let language = self.runtime.as_ref().map(|slot| match &slot.model {
+            // parakeet-tdt-0.6b-v3 covers 25 European languages; v2 and the rest are English-only
+            LoadedModel::Tdt(_)
+                if self
+                    .model_name
+                    .as_deref()
+                    .is_some_and(|name| name.to_lowercase().contains("v3")) =>
+            {
+                "multilingual".to_string()
+            }
+            _ => "en".to_string(),
+        });
+
         ParakeetStatus {
             loaded: self.runtime.is_some(),
             model_id: self.model_name.clone(),
             model_type,
             backend: self.backend.to_string(),
             load_path: self.load_path.to_string(),
+            language,
+            supports_streaming,
         }
     }
 
@@ -319,13 +371,76 @@ impl ParakeetManager {
         Ok(format!("Loaded {} ({})", info.display_name, backend))
     }
 
-    /// Clear the internal context/state of the model (reset for new recording)
+    /// Clear the internal context/state of the model (reset for new recording).
+    /// Nemotron and EOU carry encoder cache and buffered audio between
+    /// `transcribe_chunk` calls; the resampler keeps the previous session's tail.
     pub fn clear_context(&mut self) {
         if let Some(slot) = &mut self.runtime {
-            if let LoadedModel::Nemotron(m) = &mut slot.model {
-                m.reset();
+            match &mut slot.model {
+                LoadedModel::Nemotron(m) => m.reset(),
+                LoadedModel::Eou(m) => m.reset(),
+                LoadedModel::Ctc(_) | LoadedModel::Tdt(_) => {}
             }
         }
+        self.resampler = None;
+    }
+
+    /// Run 1 second of silence through the loaded model to wake up the ONNX session,
+    /// then reset streaming state so the next recording starts clean.
+    pub fn warm_up(&mut self) -> Result<(), String> {
+        let warmup_audio = vec![0.0_f32; 16000];
+        let result = self.transcribe_chunk(&warmup_audio, 16000).map(|_| ());
+        self.clear_context();
+        result
+    }
+
+    /// One clean pass over in-memory 16 kHz mono audio (e.g. VAD-filtered speech from a
+    /// finished recording), independent of any live streaming state. Audio is fed in 15 s
+    /// windows to bound TDT/CTC memory; streaming state is reset before and after.
+    pub fn transcribe_audio_data(&mut self, audio: &[f32]) -> Result<String, String> {
+        const WINDOW_SAMPLES: usize = 16000 * 15;
+        self.clear_context();
+        let mut parts: Vec<String> = Vec::new();
+        for window in audio.chunks(WINDOW_SAMPLES) {
+            match self.transcribe_chunk(window, 16000) {
+                Ok(text) if !text.trim().is_empty() => parts.push(text.trim().to_string()),
+                Ok(_) => {}
+                Err(e) => {
+                    self.clear_context();
+                    return Err(e);
+                }
+            }
+        }
+        self.clear_context();
+        Ok(parts.join(" "))
+    }
+
+    /// Like `transcribe_audio_data`, but returns `(start_sec, end_sec, text)` spans:
+    /// words for CTC, sentences for TDT. Nemotron/EOU stream without usable timings
+    /// and return no spans.
+    pub fn transcribe_with_timestamps(
+        &mut self,
+        audio: &[f32],
+    ) -> Result<Vec<(f32, f32, String)>, String> {
+        const WINDOW_SAMPLES: usize = 16000 * 15;
+        self.clear_context();
+        let mut spans = Vec::new();
+        for (i, window) in audio.chunks(WINDOW_SAMPLES).enumerate() {
+            let offset = (i * WINDOW_SAMPLES) as f32 / 16000.0;
+            match self.transcribe_chunk_timestamped(window, 16000) {
+                Ok((_, timestamps)) => spans.extend(
+                    timestamps
+                        .into_iter()
+                        .map(|(start, end, text)| (start + offset, end + offset, text)),
+                ),
+                Err(e) => {
+                    self.clear_context();
+                    return Err(e);
+                }
+            }
+        }
+        self.clear_context();
+        Ok(spans)
     }
 
     /// Transcribe a chunk of audio
@@ -334,6 +449,18 @@ impl ParakeetManager {
         samples: &[f32],
         sample_rate: u32,
     ) -> Result<String, String> {
+        self.transcribe_chunk_timestamped(samples, sample_rate)
+            .map(|(text, _)| text)
+    }
+
+    /// Transcribe a chunk of audio, also returning `(start_sec, end_sec, text)` spans
+    /// relative to the chunk start (words for CTC, sentences for TDT, none for the
+    /// streaming Nemotron/EOU models).
+    pub fn transcribe_chunk_timestamped(
+        &mut self,
+        samples: &[f32],
+        sample_rate: u32,
+    ) -> Result<(String, Vec<(f32, f32, String)>), String> {
         crate::memory::maybe_log_process_memory_with_sizes(
             "parakeet before transcribe_chunk",
             &[
@@ -397,13 +524,13 @@ impl ParakeetManager {
         );
 
         // 2. Transcribe
+        let nemotron_subchunk = self.nemotron_subchunk_samples;
         if let Some(slot) = &mut self.runtime {
             let result = match &mut slot.model {
                 LoadedModel::Nemotron(m) => {
                     let mut transcript = String::new();
-                    const CHUNK_SIZE: usize = 8960; // 560 ms at 16 kHz
-                    let total_subchunks = audio.chunks(CHUNK_SIZE).len();
-                    for (idx, chunk) in audio.chunks(CHUNK_SIZE).enumerate() {
+                    let total_subchunks = audio.chunks(nemotron_subchunk).len();
+                    for (idx, chunk) in audio.chunks(nemotron_subchunk).enumerate() {
                         crate::memory::maybe_log_process_memory_with_sizes(
                             &format!(
                                 "parakeet nemotron subchunk {}/{} start",
@@ -420,8 +547,8 @@ impl ParakeetManager {
                             ],
                         );
                         let mut chunk_vec = chunk.to_vec();
-                        if chunk_vec.len() < CHUNK_SIZE {
-                            chunk_vec.resize(CHUNK_SIZE, 0.0);
+                        if chunk_vec.len() < nemotron_subchunk {
+                            chunk_vec.resize(nemotron_subchunk, 0.0);
                         }
                         transcript.push_str(&m.transcribe_chunk(&chunk_vec).unwrap_or_default());
                         crate::memory::maybe_log_process_memory_with_sizes(
@@ -436,7 +563,7 @@ impl ParakeetManager {
                             ],
                         );
                     }
-                    Ok(transcript)
+                    Ok((transcript, Vec::new()))
                 }
                 LoadedModel::Ctc(m) => {
                     crate::memory::maybe_log_process_memory_with_sizes(
@@ -457,7 +584,7 @@ impl ParakeetManager {
                             ("transcript_chars", result.text.len()),
                         ],
                     );
-                    Ok(result.text)
+                    Ok((result.text, timed_spans(result.tokens)))
                 }
                 LoadedModel::Eou(m) => {
                     let mut full_text = String::new();
@@ -486,7 +613,7 @@ impl ParakeetManager {
                         );
                     }
                     println!("[PARAKEET EOU] {}", full_text.trim());
-                    Ok(full_text)
+                    Ok((full_text, Vec::new()))
                 }
                 LoadedModel::Tdt(m) => {
                     crate::memory::maybe_log_process_memory_with_sizes(
@@ -507,10 +634,10 @@ impl ParakeetManager {
                             ("transcript_chars", result.text.len()),
                         ],
                     );
-                    Ok(result.text)
+                    Ok((result.text, timed_spans(result.tokens)))
                 }
             };
-            if let Ok(ref transcript) = result {
+            if let Ok((ref transcript, _)) = result {
                 crate::memory::maybe_log_process_memory_with_sizes(
                     "parakeet after transcribe_chunk",
                     &[
@@ -539,3 +666,14 @@ impl ParakeetManager {
         }
     }
 }
+
+/// `(start_sec, end_sec, text)` spans from parakeet-rs timed tokens, skipping blanks.
+fn timed_spans(tokens: Vec<TimedToken>) -> Vec<(f32, f32, String)> {
+    tokens
+        .into_iter()
+        .filter_map(|t| {
+            let text = t.text.trim();
+            (!text.is_empty()).then(|| (t.start, t.end, text.to_string()))
+        })
+        .collect()
+}
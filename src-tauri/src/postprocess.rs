@@ -0,0 +1,101 @@
+//! Text post-processing for a finished transcript, after `clean_transcript`:
+//! the user's custom dictionary, the optional SymSpell pass and, after the optional
+//! grammar LLM, snippet expansion. Both lists are read from settings.json (`custom_dictionary`,
+//! `snippets`), which the Text settings tab writes. `stop_recording` and
+//! `preview_cleanup` share these functions so a preview matches what gets inserted.
+
+use crate::spellcheck::SpellChecker;
+use crate::state::AudioState;
+use regex::{NoExpand, RegexBuilder};
+use serde::de::DeserializeOwned;
+use std::sync::atomic::Ordering;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+/// One custom dictionary entry: `sounds_like` is replaced by `correct`.
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DictEntry {
+    sounds_like: String,
+    correct: String,
+}
+
+/// One snippet: a spoken `trigger` expands to `expansion`.
+#[derive(serde::Deserialize)]
+struct SnippetEntry {
+    trigger: String,
+    expansion: String,
+}
+
+/// Read a persisted list from settings.json; missing or malformed lists are empty.
+fn load_list<T: DeserializeOwned>(app: &AppHandle, key: &str) -> Vec<T> {
+    app.store(crate::commands::SETTINGS_STORE)
+        .ok()
+        .and_then(|store| store.get(key))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+/// Replace every whole-word, case-insensitive occurrence of `from` with `to`.
+fn replace_whole_word(text: &str, from: &str, to: &str) -> String {
+    let (from, to) = (from.trim(), to.trim());
+    if from.is_empty() || to.is_empty() {
+        return text.to_string();
+    }
+    match RegexBuilder::new(&format!(r"\b{}\b", regex::escape(from)))
+        .case_insensitive(true)
+        .build()
+    {
+        Ok(re) => re.replace_all(text, NoExpand(to)).into_owned(),
+        Err(_) => text.to_string(),
+    }
+}
+
+/// Everything that runs before the grammar LLM: the custom dictionary (so proper
+/// nouns are fixed before the LLM sees them), then spellcheck when enabled.
+pub fn correct(app: &AppHandle, text: &str) -> String {
+    let dictionary = load_list::<DictEntry>(app, "custom_dictionary");
+    let text = dictionary.iter().fold(text.to_string(), |text, entry| {
+        replace_whole_word(&text, &entry.sounds_like, &entry.correct)
+    });
+    correct_spelling(app, &text, &dictionary)
+}
+
+/// Run SymSpell over `text` when spellcheck is enabled, loading the checker on
+/// first use. Dictionary corrections are taught to the checker first so it doesn't
+/// "fix" them straight back. Without a dictionary file the text passes through.
+fn correct_spelling(app: &AppHandle, text: &str, dictionary: &[DictEntry]) -> String {
+    let state = app.state::<AudioState>();
+    if !state.spellcheck_enabled.load(Ordering::Relaxed) {
+        return text.to_string();
+    }
+    let mut checker = state.spell_checker.lock().unwrap();
+    if checker.is_none() {
+        match SpellChecker::new(*state.spellcheck_options.lock().unwrap()) {
+            Ok(loaded) => *checker = Some(loaded),
+            Err(e) => {
+                eprintln!("[SPELL] Skipping spellcheck: {}", e);
+                return text.to_string();
+            }
+        }
+    }
+    let Some(checker) = checker.as_mut() else {
+        return text.to_string();
+    };
+    for word in dictionary
+        .iter()
+        .flat_map(|entry| entry.correct.split_whitespace())
+    {
+        checker.add_word(word);
+    }
+    checker.correct(text)
+}
+
+/// Expand snippets. Runs last so the LLM doesn't mangle the expanded text.
+pub fn expand_snippets(app: &AppHandle, text: &str) -> String {
+    load_list::<SnippetEntry>(app, "snippets")
+        .iter()
+        .fold(text.to_string(), |text, snippet| {
+            replace_whole_word(&text, &snippet.trigger, &snippet.expansion)
+        })
+}
@@ -2,20 +2,98 @@ use anyhow::{Error, Result};
 use std::time::Instant;
 use symspell::{SymSpell, Verbosity, UnicodeStringStrategy};
 
+/// SymSpell English frequency dictionary (82,765 words).
+const DICTIONARY_FILE: &str = "frequency_dictionary_en_82_765.txt";
+
+/// User word list (one word per line), stored next to the app's other data so it
+/// survives dictionary re-downloads.
+const USER_WORDS_FILE: &str = "spellcheck_words.txt";
+
+/// Frequency given to user words: above any dictionary entry, so a user word wins
+/// ties against a dictionary word at the same edit distance.
+const USER_WORD_COUNT: i64 = 10_000_000_000;
+
+/// The SymSpell default dictionary is built for edit distance 2; `lookup` panics
+/// when asked for more.
+const MAX_DICTIONARY_EDIT_DISTANCE: i64 = 2;
+
+/// How many suggestions SymSpell considers per word.
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SpellcheckVerbosity {
+    /// The most frequent suggestion at the smallest distance found.
+    Top,
+    /// All suggestions at the smallest distance, most frequent first.
+    Closest,
+    /// All suggestions within the max distance.
+    All,
+}
+
+impl From<SpellcheckVerbosity> for Verbosity {
+    fn from(v: SpellcheckVerbosity) -> Self {
+        match v {
+            SpellcheckVerbosity::Top => Verbosity::Top,
+            SpellcheckVerbosity::Closest => Verbosity::Closest,
+            SpellcheckVerbosity::All => Verbosity::All,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct SpellcheckOptions {
+    /// 0–2; 1 is much less eager to rewrite short words.
+    pub max_edit_distance: i64,
+    pub verbosity: SpellcheckVerbosity,
+    /// Leave tokens with two or more capitals alone (acronyms like "GPU", "NVMe").
+    pub skip_uppercase: bool,
+    /// Leave tokens containing digits alone ("mp3", "x86", "h264").
+    pub skip_with_digits: bool,
+}
+
+impl SpellcheckOptions {
+    /// Reject an edit distance the dictionary wasn't built for.
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0..=MAX_DICTIONARY_EDIT_DISTANCE).contains(&self.max_edit_distance) {
+            return Err(format!(
+                "max_edit_distance must be 0–{}, got {}",
+                MAX_DICTIONARY_EDIT_DISTANCE, self.max_edit_distance
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for SpellcheckOptions {
+    fn default() -> Self {
+        Self {
+            max_edit_distance: MAX_DICTIONARY_EDIT_DISTANCE,
+            verbosity: SpellcheckVerbosity::Closest,
+            skip_uppercase: true,
+            skip_with_digits: true,
+        }
+    }
+}
+
 pub struct SpellChecker {
     symspell: SymSpell<UnicodeStringStrategy>,
+    options: SpellcheckOptions,
 }
 
 impl SpellChecker {
-    pub fn new() -> Result<Self> {
+    pub fn new(options: SpellcheckOptions) -> Result<Self> {
         let start = Instant::now();
         println!("[SPELL] Initializing SymSpell spell checker...");
 
-        // Get the models directory dynamically (same location as downloader)
-        let models_dir = crate::utils::get_models_dir()
-            .map_err(|e| Error::msg(format!("Failed to get models directory: {}", e)))?;
-        
-        let dict_path = models_dir.join("frequency_dictionary_en_82_765.txt");
+        // SPELLCHECK_DICT overrides the dictionary file; otherwise use the models
+        // directory. The download manager has no SymSpell entry, so the file is
+        // placed there by hand and the error below says so.
+        let dict_path = match std::env::var_os("SPELLCHECK_DICT") {
+            Some(path) => std::path::PathBuf::from(path),
+            None => crate::utils::get_models_dir()
+                .map_err(|e| Error::msg(format!("Failed to get models directory: {}", e)))?
+                .join(DICTIONARY_FILE),
+        };
 
         let mut symspell: SymSpell<UnicodeStringStrategy> = SymSpell::default();
 
@@ -30,14 +108,42 @@ impl SpellChecker {
             println!("[SPELL] Dictionary loaded in {:?}", start.elapsed());
         } else {
             println!("[SPELL] Warning: Dictionary not found at {:?}", dict_path);
-            println!("[SPELL] Download from Settings > Download Manager");
+            println!("[SPELL] Place {} in the models folder or set SPELLCHECK_DICT", DICTIONARY_FILE);
             return Err(Error::msg(format!(
-                "Dictionary not found. Please download 'English Dictionary (SymSpell)' from Settings > Download Manager.\nExpected location: {:?}",
-                dict_path
+                "Spellcheck dictionary not found. Place {} in the models folder (or point SPELLCHECK_DICT at it).\nExpected location: {:?}",
+                DICTIONARY_FILE, dict_path
             )));
         }
 
-        Ok(Self { symspell })
+        let mut checker = Self { symspell, options };
+        checker.set_options(options);
+        let user_words = load_user_words();
+        for word in &user_words {
+            checker.add_word(word);
+        }
+        if !user_words.is_empty() {
+            println!("[SPELL] Added {} user words", user_words.len());
+        }
+        Ok(checker)
+    }
+
+    /// Teach the checker a word (jargon, a name, a code identifier) so it is kept
+    /// as-is instead of being "corrected" into a dictionary word.
+    pub fn add_word(&mut self, word: &str) {
+        let word = word.trim();
+        if !word.is_empty() {
+            self.symspell
+                .create_dictionary_entry(&word.to_lowercase(), USER_WORD_COUNT);
+        }
+    }
+
+    pub fn set_options(&mut self, options: SpellcheckOptions) {
+        self.options = SpellcheckOptions {
+            max_edit_distance: options
+                .max_edit_distance
+                .clamp(0, MAX_DICTIONARY_EDIT_DISTANCE),
+            ..options
+        };
     }
 
     /// Correct spelling in text (word by word)
@@ -57,7 +163,11 @@ impl SpellChecker {
             // Strip punctuation for lookup
             let (prefix, clean_word, suffix) = strip_punctuation(word);
             
-            if clean_word.is_empty() {
+            if clean_word.is_empty()
+                || (self.options.skip_uppercase
+                    && clean_word.chars().filter(|c| c.is_uppercase()).count() >= 2)
+                || (self.options.skip_with_digits && clean_word.chars().any(|c| c.is_numeric()))
+            {
                 corrected_words.push(word.to_string());
                 continue;
             }
@@ -65,8 +175,8 @@ impl SpellChecker {
             // Look up the word
             let suggestions = self.symspell.lookup(
                 &clean_word.to_lowercase(),
-                Verbosity::Closest,
-                2 // max edit distance
+                self.options.verbosity.into(),
+                self.options.max_edit_distance,
             );
 
             if let Some(suggestion) = suggestions.first() {
@@ -137,3 +247,46 @@ fn match_case(suggestion: &str, original: &str) -> String {
         suggestion.to_lowercase()
     }
 }
+
+fn user_words_path() -> Result<std::path::PathBuf, String> {
+    let app_data = dirs::data_local_dir().ok_or("Could not find AppData directory")?;
+    Ok(app_data.join("Taurscribe").join(USER_WORDS_FILE))
+}
+
+/// Read the persisted user word list (empty if there isn't one yet).
+fn load_user_words() -> Vec<String> {
+    user_words_path()
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .map(|contents| {
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|w| !w.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Merge `words` into the persisted user word list (case-insensitively deduped).
+/// `SpellChecker::new` injects the list into the dictionary. Returns the size of
+/// the list afterwards.
+pub fn save_user_words(words: &[String]) -> Result<usize, String> {
+    let mut user_words = load_user_words();
+    for word in words {
+        let word = word.trim();
+        if !word.is_empty() && !user_words.iter().any(|w| w.eq_ignore_ascii_case(word)) {
+            user_words.push(word.to_string());
+        }
+    }
+    let path = user_words_path()?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create data directory: {}", e))?;
+    }
+    std::fs::write(&path, user_words.join("\n") + "\n")
+        .map_err(|e| format!("Failed to save spellcheck words: {}", e))?;
+    println!("[SPELL] User word list now has {} words", user_words.len());
+    Ok(user_words.len())
+}
@@ -1,11 +1,57 @@
 use crate::audio::RecordingHandle;
-use crate::cohere::CohereManager;
+use crate::cohere::{CohereManager, CohereStatus};
 use crate::denoise::Denoiser;
-use crate::parakeet::ParakeetManager;
-use crate::types::{ASREngine, AppState, HotkeyBinding};
+use crate::parakeet::{ParakeetManager, ParakeetStatus};
+use crate::spellcheck::{SpellChecker, SpellcheckOptions};
+use crate::types::{
+    ASREngine, AppState, BufferDropPolicy, HotkeyBinding, PostRecordingAction, TranscriptionChunk,
+    TypingMode, VoiceCommand,
+};
 use crate::vad::VADManager;
 use crate::whisper::WhisperManager;
-use std::sync::{atomic::AtomicBool, Arc, Mutex, RwLock};
+use std::collections::VecDeque;
+use std::sync::{
+    atomic::{AtomicBool, AtomicU32, AtomicU64},
+    Arc, Mutex, RwLock,
+};
+
+/// Whisper's loaded model and compute backend, as reported by the status commands.
+#[derive(Debug, Clone)]
+pub struct WhisperStatus {
+    pub model: Option<String>,
+    pub backend: String,
+}
+
+/// Last engine metadata read by the status commands. Final passes and LLM generation
+/// hold the engine mutexes for seconds; status reads answer from here meanwhile
+/// instead of queueing behind them, so the UI's indicators keep updating.
+#[derive(Default)]
+pub struct StatusCache {
+    whisper: Mutex<Option<WhisperStatus>>,
+    parakeet: Mutex<Option<ParakeetStatus>>,
+    cohere: Mutex<Option<CohereStatus>>,
+    llm_gpu_layers: Mutex<Option<Option<u32>>>,
+}
+
+/// Read from `engine` if its lock is free and remember the result. While another
+/// thread holds it, return the cached value (only blocking if nothing is cached yet).
+fn read_or_cached<E, T: Clone>(
+    engine: &Mutex<E>,
+    cache: &Mutex<Option<T>>,
+    read: impl Fn(&E) -> T,
+) -> T {
+    let value = match engine.try_lock() {
+        Ok(guard) => read(&guard),
+        Err(_) => {
+            if let Some(cached) = cache.lock().unwrap().clone() {
+                return cached;
+            }
+            read(&engine.lock().unwrap())
+        }
+    };
+    *cache.lock().unwrap() = Some(value.clone());
+    value
+}
 
 /// The Global "Brain" of the application.
 /// This struct holds all the data that needs to live as long as the app runs.
@@ -41,9 +87,17 @@ pub struct AudioState {
     // Accumulates the full transcript during a recording session (for Parakeet streaming reuse)
     pub session_transcript: Arc<Mutex<String>>,
 
+    // Rolling buffer of the most recent live chunks (oldest first). Lets the frontend
+    // catch up on `transcription-chunk` events it missed while the window was hidden.
+    pub recent_chunks: Arc<Mutex<VecDeque<TranscriptionChunk>>>,
+
     // The Gemma LLM engine (optional, loaded on demand)
     pub llm: Arc<Mutex<Option<crate::llm::LLMEngine>>>,
 
+    // Shared with the loaded LLMEngine; cancel_llm sets it to stop a running generation
+    // without waiting for the llm lock.
+    pub llm_cancel: Arc<AtomicBool>,
+
     // The user-configured global hotkey binding (keyboard combo or mouse button).
     // Shared with the hotkey listener thread so changes take effect immediately.
     // RwLock: the listener reads on every key event; writes are rare (user reconfigures hotkey).
@@ -68,6 +122,11 @@ pub struct AudioState {
     // the hotkey inside the Settings modal.
     pub hotkey_suppressed: Arc<AtomicBool>,
 
+    // The hotkey listener's view of whether it started a recording. Cleared whenever a
+    // session ends (UI stop, cancel, auto-stop, failed start) so toggle mode's next
+    // press starts a new recording instead of stopping one that no longer exists.
+    pub hotkey_recording_active: Arc<AtomicBool>,
+
     // Tracks whether the current recording stream is temporarily paused.
     pub recording_paused: Arc<AtomicBool>,
 
@@ -77,6 +136,72 @@ pub struct AudioState {
 
     // True while an ASR engine is actively loading (blocks unload attempts).
     pub engine_loading: Arc<AtomicBool>,
+
+    // When false (default) a temp WAV is deleted once stop_recording has finished
+    // with it. When true they all stay (retranscribe_last and subtitles need this).
+    pub keep_recordings: Arc<AtomicBool>,
+
+    // Recording length (seconds) after which the mic callback stops capturing and the
+    // backend stops the session ("recording-auto-stopped"). None = no limit (default).
+    pub max_recording_secs: Arc<Mutex<Option<u32>>>,
+
+    // Live-preview chunk length (seconds, 1–15) for Whisper and Parakeet.
+    // None = engine defaults (6 s Whisper, 4 s Parakeet).
+    pub live_chunk_secs: Arc<Mutex<Option<f32>>>,
+
+    // When true the Whisper final pass transcribes each VAD segment separately
+    // (emitting "final-pass-progress") instead of one concatenated buffer.
+    pub final_pass_per_segment: Arc<AtomicBool>,
+
+    // When false the Whisper final pass skips VAD and transcribes the whole recording.
+    pub final_pass_vad: Arc<AtomicBool>,
+
+    // When true stop_recording re-transcribes the saved WAV with Parakeet in one clean
+    // pass instead of returning the accumulated streaming transcript.
+    pub parakeet_final_pass: Arc<AtomicBool>,
+
+    // Final-pass VAD segments closer than this (ms) are merged, pause included. 0 = off.
+    pub vad_merge_gap_ms: Arc<AtomicU32>,
+
+    // When true the saved WAV has its leading/trailing silence cut after stop_recording.
+    pub trim_recording_silence: Arc<AtomicBool>,
+
+    // How the live transcriber sheds load when it falls behind (read at recording start).
+    pub buffer_drop_policy: Arc<Mutex<BufferDropPolicy>>,
+
+    // Mono samples discarded by the live pipeline in the current session.
+    pub dropped_samples: Arc<AtomicU64>,
+
+    // Paste, copy only (always or after hotkey stops), or leave the transcript in
+    // the UI for review after a stop.
+    pub post_recording_action: Arc<Mutex<PostRecordingAction>>,
+
+    // clipboard_paste waits: after setting the transcript, and before restoring the
+    // previous clipboard. Slow apps / remote desktops need a longer restore delay.
+    pub paste_set_delay_ms: Arc<AtomicU32>,
+    pub paste_delay_ms: Arc<AtomicU32>,
+
+    // AX / clipboard paste / keystroke typing for type_text.
+    pub typing_mode: Arc<Mutex<TypingMode>>,
+
+    // Phrases that, when they make up the whole transcript, trigger an action
+    // ("voice-command" event) instead of being inserted.
+    pub voice_commands: Arc<Mutex<Vec<VoiceCommand>>>,
+
+    // Optional SymSpell pass over finished transcripts; the checker (~80k-word
+    // dictionary) is loaded when spellcheck is enabled and dropped when disabled.
+    pub spellcheck_enabled: Arc<AtomicBool>,
+    pub spell_checker: Arc<Mutex<Option<SpellChecker>>>,
+    pub spellcheck_options: Arc<Mutex<SpellcheckOptions>>,
+
+    // Periodic silent inference that keeps the GPU clocked up between dictations.
+    pub gpu_keepalive: Arc<AtomicBool>,
+
+    // Whether the main window has focus (the keep-alive only runs while it does).
+    pub window_focused: Arc<AtomicBool>,
+
+    // Last-known engine metadata so status commands never wait on a busy engine.
+    pub status_cache: Arc<StatusCache>,
 }
 
 impl AudioState {
@@ -95,19 +220,69 @@ impl AudioState {
             current_app_state: Arc::new(Mutex::new(AppState::Ready)),
             active_engine: Arc::new(Mutex::new(ASREngine::Whisper)),
             session_transcript: Arc::new(Mutex::new(String::new())),
+            recent_chunks: Arc::new(Mutex::new(VecDeque::new())),
             llm: Arc::new(Mutex::new(None)),
+            llm_cancel: Arc::new(AtomicBool::new(false)),
             hotkey_config: Arc::new(RwLock::new(HotkeyBinding::default())),
             selected_input_device: Arc::new(Mutex::new(None)),
             denoiser: Arc::new(Mutex::new(None)),
             close_behavior: Arc::new(Mutex::new("tray".to_string())),
             cohere: Arc::new(Mutex::new(cohere)),
             hotkey_suppressed: Arc::new(AtomicBool::new(false)),
+            hotkey_recording_active: Arc::new(AtomicBool::new(false)),
             recording_paused: Arc::new(AtomicBool::new(false)),
             model_loaded: Arc::new(AtomicBool::new(false)),
             engine_loading: Arc::new(AtomicBool::new(false)),
+            keep_recordings: Arc::new(AtomicBool::new(false)),
+            max_recording_secs: Arc::new(Mutex::new(None)),
+            live_chunk_secs: Arc::new(Mutex::new(None)),
+            final_pass_per_segment: Arc::new(AtomicBool::new(false)),
+            final_pass_vad: Arc::new(AtomicBool::new(true)),
+            parakeet_final_pass: Arc::new(AtomicBool::new(false)),
+            vad_merge_gap_ms: Arc::new(AtomicU32::new(0)),
+            trim_recording_silence: Arc::new(AtomicBool::new(false)),
+            buffer_drop_policy: Arc::new(Mutex::new(BufferDropPolicy::default())),
+            dropped_samples: Arc::new(AtomicU64::new(0)),
+            post_recording_action: Arc::new(Mutex::new(PostRecordingAction::default())),
+            paste_set_delay_ms: Arc::new(AtomicU32::new(50)),
+            paste_delay_ms: Arc::new(AtomicU32::new(300)),
+            typing_mode: Arc::new(Mutex::new(TypingMode::default())),
+            voice_commands: Arc::new(Mutex::new(Vec::new())),
+            spellcheck_enabled: Arc::new(AtomicBool::new(false)),
+            spell_checker: Arc::new(Mutex::new(None)),
+            spellcheck_options: Arc::new(Mutex::new(SpellcheckOptions::default())),
+            gpu_keepalive: Arc::new(AtomicBool::new(false)),
+            window_focused: Arc::new(AtomicBool::new(false)),
+            status_cache: Arc::new(StatusCache::default()),
         }
     }
 
+    pub fn whisper_status(&self) -> WhisperStatus {
+        read_or_cached(&self.whisper, &self.status_cache.whisper, |w| {
+            WhisperStatus {
+                model: w.get_current_model().cloned(),
+                backend: w.get_backend().to_string(),
+            }
+        })
+    }
+
+    pub fn parakeet_status(&self) -> ParakeetStatus {
+        read_or_cached(&self.parakeet, &self.status_cache.parakeet, |p| {
+            p.get_status()
+        })
+    }
+
+    pub fn cohere_status(&self) -> CohereStatus {
+        read_or_cached(&self.cohere, &self.status_cache.cohere, |c| c.get_status())
+    }
+
+    /// GPU layers of the loaded LLM, or None when no LLM is loaded.
+    pub fn llm_gpu_layers(&self) -> Option<u32> {
+        read_or_cached(&self.llm, &self.status_cache.llm_gpu_layers, |llm| {
+            llm.as_ref().map(|engine| engine.gpu_layers())
+        })
+    }
+
     /// True when at least one ASR bundle exists on disk for the currently selected engine.
     /// Used by the tray menu to distinguish "Load Model" from "No model found".
     pub fn active_engine_has_downloaded_model(&self) -> bool {
@@ -0,0 +1,89 @@
+//! SRT / WebVTT formatting for timestamped transcripts.
+//! Cue text goes through `clean_transcript`; cues left blank are dropped and the
+//! remaining ones are numbered consecutively.
+
+use crate::types::Segment;
+use crate::utils::clean_transcript;
+
+/// `hh:mm:ss<sep>mmm`, rounded to the nearest millisecond (negative clamps to 0).
+fn format_timestamp(secs: f32, ms_separator: char) -> String {
+    let total_ms = (secs.max(0.0) as f64 * 1000.0).round() as u64;
+    let (hours, rest) = (total_ms / 3_600_000, total_ms % 3_600_000);
+    let (minutes, rest) = (rest / 60_000, rest % 60_000);
+    let (seconds, millis) = (rest / 1000, rest % 1000);
+    format!("{hours:02}:{minutes:02}:{seconds:02}{ms_separator}{millis:03}")
+}
+
+/// Segments with non-blank cleaned text, as (start, end, text). `end` never precedes `start`.
+fn cues(segments: &[Segment]) -> impl Iterator<Item = (f32, f32, String)> + '_ {
+    segments.iter().filter_map(|s| {
+        let text = clean_transcript(&s.text);
+        (!text.trim().is_empty()).then(|| (s.start_secs, s.end_secs.max(s.start_secs), text))
+    })
+}
+
+pub fn to_srt(segments: &[Segment]) -> String {
+    let mut out = String::new();
+    for (i, (start, end, text)) in cues(segments).enumerate() {
+        out.push_str(&format!(
+            "{}\n{} --> {}\n{}\n\n",
+            i + 1,
+            format_timestamp(start, ','),
+            format_timestamp(end, ','),
+            text.trim()
+        ));
+    }
+    out
+}
+
+pub fn to_vtt(segments: &[Segment]) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for (start, end, text) in cues(segments) {
+        out.push_str(&format!(
+            "{} --> {}\n{}\n\n",
+            format_timestamp(start, '.'),
+            format_timestamp(end, '.'),
+            text.trim()
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seg(start_secs: f32, end_secs: f32, text: &str) -> Segment {
+        Segment {
+            start_secs,
+            end_secs,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn timestamps_round_to_nearest_millisecond() {
+        assert_eq!(format_timestamp(0.0, ','), "00:00:00,000");
+        assert_eq!(format_timestamp(1.2346, ','), "00:00:01,235");
+        assert_eq!(format_timestamp(59.9996, '.'), "00:01:00.000");
+        assert_eq!(format_timestamp(3723.5, ','), "01:02:03,500");
+        assert_eq!(format_timestamp(-0.2, ','), "00:00:00,000");
+    }
+
+    #[test]
+    fn blank_segments_are_skipped_and_numbering_stays_consecutive() {
+        let segments = [
+            seg(0.0, 1.5, "Hello there."),
+            seg(1.5, 2.0, "   "),
+            seg(2.0, 3.25, "General Kenobi."),
+        ];
+        let srt = to_srt(&segments);
+        assert!(srt.starts_with("1\n00:00:00,000 --> 00:00:01,500\n"));
+        assert!(srt.contains("2\n00:00:02,000 --> 00:00:03,250\n"));
+        assert!(!srt.contains("3\n"));
+
+        let vtt = to_vtt(&segments);
+        assert!(vtt.starts_with("WEBVTT\n\n00:00:00.000 --> 00:00:01.500\n"));
+        assert_eq!(vtt.matches(" --> ").count(), 2);
+    }
+}
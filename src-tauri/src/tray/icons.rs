@@ -12,7 +12,8 @@ use tauri::{AppHandle, Manager};
 // with black-on-transparent artwork.
 //
 // Windows / Linux tray icons can be full-colour.  We use 32×32 coloured
-// circle PNGs (green = ready, red = recording, yellow = processing).
+// circle PNGs (green = ready, red = recording, yellow = processing,
+// red with pause bars = paused).
 // ---------------------------------------------------------------------------
 
 // ── Ready (green / hollow circle) ──────────────────────────────────────────
@@ -57,6 +58,20 @@ macro_rules! tray_icon_processing {
     }};
 }
 
+// ── Paused (recording circle with pause bars cut out) ──────────────────────
+macro_rules! tray_icon_paused {
+    () => {{
+        #[cfg(target_os = "macos")]
+        {
+            tauri::include_image!("icons/tray-pausedTemplate@2x.png")
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            tauri::include_image!("icons/tray-paused.png")
+        }
+    }};
+}
+
 /// Helper function to physically change the tray icon
 pub fn update_tray_icon(app: &AppHandle, state: AppState) -> Result<(), String> {
     // Pick the right image macro based on state
@@ -64,6 +79,7 @@ pub fn update_tray_icon(app: &AppHandle, state: AppState) -> Result<(), String>
         AppState::Ready => tray_icon_ready!(),
         AppState::Recording => tray_icon_recording!(),
         AppState::Processing => tray_icon_processing!(),
+        AppState::Paused => tray_icon_paused!(),
     };
 
     // Pick the right hover text.
@@ -85,6 +101,7 @@ pub fn update_tray_icon(app: &AppHandle, state: AppState) -> Result<(), String>
             .unwrap_or("Taurscribe - Ready"),
         AppState::Recording => "Taurscribe - Recording...",
         AppState::Processing => "Taurscribe - Processing...",
+        AppState::Paused => "Taurscribe - Recording paused",
     };
 
     // Find the tray item by ID and apply changes
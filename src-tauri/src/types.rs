@@ -5,6 +5,7 @@ pub enum AppState {
     Ready,      // Green: Waiting for user input
     Recording,  // Red: Mic is active, recording audio
     Processing, // Yellow: Computing/Transcribing
+    Paused,     // Red with pause bars: Recording session open, mic stream paused
 }
 
 /// The possible ASR engines we support
@@ -29,12 +30,92 @@ impl Default for RecordingMode {
     }
 }
 
-/// Hotkey binding — up to 2 keyboard keys held simultaneously.
+/// What the live pipeline does when the transcriber can't keep up with the mic.
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BufferDropPolicy {
+    /// Discard the oldest queued audio so the live preview stays current.
+    DropOldest,
+    /// Discard incoming audio until the transcriber catches up.
+    DropNewest,
+    /// Never discard; the live preview lags instead.
+    Block,
+}
+
+impl Default for BufferDropPolicy {
+    fn default() -> Self {
+        BufferDropPolicy::DropOldest
+    }
+}
+
+/// What happens to the transcript after a recording stops.
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PostRecordingAction {
+    /// Insert into the focused app via `type_text`.
+    Paste,
+    /// Put it on the clipboard via `copy_to_clipboard` without pasting.
+    CopyOnly,
+    /// Paste after button stops, copy only after hotkey stops.
+    CopyOnHotkey,
+    /// Leave it in the UI only.
+    Nothing,
+}
+
+impl Default for PostRecordingAction {
+    fn default() -> Self {
+        PostRecordingAction::Paste
+    }
+}
+
+/// How `type_text` gets the transcript into the focused app.
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TypingMode {
+    /// AXUIElement with a clipboard fallback on macOS, clipboard paste elsewhere.
+    Auto,
+    /// Swap the clipboard, send Cmd/Ctrl+V, then restore the previous clipboard.
+    ClipboardPaste,
+    /// Type each character as a key event; leaves the clipboard (and its history)
+    /// alone and works where paste is intercepted or disabled (terminals, some forms).
+    #[serde(alias = "direct_type")]
+    KeystrokeByKeystroke,
+}
+
+impl Default for TypingMode {
+    fn default() -> Self {
+        TypingMode::Auto
+    }
+}
+
+/// Snapshot returned by `get_recording_status`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RecordingStatus {
+    pub recording: bool,
+    pub paused: bool,
+    /// Native sample rate of the active recording (None when idle).
+    pub sample_rate: Option<u32>,
+    /// Mono samples discarded by the live transcriber this session, at `sample_rate`.
+    pub dropped_samples: u64,
+    pub buffer_drop_policy: BufferDropPolicy,
+}
+
+/// Mouse buttons a hotkey can use, as stored in `HotkeyBinding::keys`.
+pub const MOUSE_BUTTON_CODES: [&str; 3] = ["MouseMiddle", "MouseBack", "MouseForward"];
+
+/// Hotkey binding — 2 keys and/or mouse buttons held simultaneously (e.g. Ctrl + MouseBack),
+/// or a single mouse button.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
 pub struct HotkeyBinding {
     pub keys: Vec<String>,
     #[serde(default)]
     pub mode: RecordingMode,
+    /// Optional distinct combos: when both are set, `start_keys` only starts and
+    /// `stop_keys` only stops recording (`keys` and `mode` are then unused by the listener).
+    #[serde(default)]
+    pub start_keys: Option<Vec<String>>,
+    #[serde(default)]
+    pub stop_keys: Option<Vec<String>>,
 }
 
 impl Default for HotkeyBinding {
@@ -48,16 +129,146 @@ impl Default for HotkeyBinding {
         HotkeyBinding {
             keys,
             mode: RecordingMode::default(),
+            start_keys: None,
+            stop_keys: None,
         }
     }
 }
 
+impl HotkeyBinding {
+    /// Two keys/buttons, or one mouse button on its own (a lone keyboard key would fire
+    /// while typing).
+    fn combo_is_valid(keys: &[String]) -> bool {
+        match keys {
+            [_, _] => true,
+            [only] => MOUSE_BUTTON_CODES.contains(&only.as_str()),
+            _ => false,
+        }
+    }
+
+    /// `keys` must be a valid combo; start/stop combos must be set together, each valid,
+    /// and not the same keys.
+    pub fn is_valid(&self) -> bool {
+        if !Self::combo_is_valid(&self.keys) {
+            return false;
+        }
+        match (&self.start_keys, &self.stop_keys) {
+            (None, None) => true,
+            (Some(start), Some(stop)) => {
+                let same = start.len() == stop.len() && start.iter().all(|k| stop.contains(k));
+                Self::combo_is_valid(start) && Self::combo_is_valid(stop) && !same
+            }
+            _ => false,
+        }
+    }
+
+    /// The distinct (start, stop) combos, when configured.
+    pub fn split_combos(&self) -> Option<(&[String], &[String])> {
+        Some((self.start_keys.as_deref()?, self.stop_keys.as_deref()?))
+    }
+}
+
+/// What `stop_recording` produced, so the UI doesn't have to string-match sentinels.
+/// Serialized as `{ "kind": "text", "data": "..." }`, `{ "kind": "silence" }`, etc.
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+pub enum TranscriptionOutcome {
+    /// Trimmed, non-empty transcript.
+    Text(String),
+    /// Nothing intelligible was heard.
+    Silence,
+    /// Audio was captured but there was no recording file to transcribe.
+    SavedNoTranscript,
+    /// The final transcription pass failed; carries the error message.
+    Failed(String),
+    /// The transcript matched a registered voice command; nothing should be inserted.
+    VoiceCommand(VoiceCommand),
+}
+
+impl TranscriptionOutcome {
+    /// Classify raw engine output: surrounding whitespace is trimmed, and empty
+    /// or `[silence]` results become `Silence`.
+    pub fn from_text(text: &str) -> Self {
+        let text = text.trim();
+        if text.is_empty() || text == "[silence]" {
+            TranscriptionOutcome::Silence
+        } else {
+            TranscriptionOutcome::Text(text.to_string())
+        }
+    }
+}
+
+/// A spoken phrase that triggers an action instead of being inserted as text.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct VoiceCommand {
+    /// Phrase to listen for, e.g. "new paragraph".
+    pub phrase: String,
+    /// Action id handed to the frontend, e.g. "new_paragraph".
+    pub action: String,
+}
+
+impl VoiceCommand {
+    /// Whole-transcript match, ignoring case, punctuation and extra whitespace,
+    /// so Whisper's "New paragraph." still matches "new paragraph".
+    pub fn matches(&self, transcript: &str) -> bool {
+        let normalize = |s: &str| {
+            s.split_whitespace()
+                .map(|w| {
+                    w.trim_matches(|c: char| !c.is_alphanumeric())
+                        .to_lowercase()
+                })
+                .filter(|w| !w.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let phrase = normalize(&self.phrase);
+        !phrase.is_empty() && phrase == normalize(transcript)
+    }
+}
+
+/// A span of transcribed audio with its position in the recording.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Segment {
+    pub start_secs: f32,
+    pub end_secs: f32,
+    pub text: String,
+}
+
 /// Structured payload for live transcription chunks
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct TranscriptionChunk {
     pub text: String,
     pub processing_time_ms: u32,
     pub method: String,
+    /// Wall-clock time the chunk was produced (Unix epoch, milliseconds).
+    pub timestamp_ms: i64,
+    /// Peak VAD speech probability (0.0–1.0) of the chunk; `None` when VAD was bypassed.
+    pub vad_probability: Option<f32>,
+    /// Mean decoder token probability (0.0–1.0); only Whisper reports this.
+    pub confidence: Option<f32>,
+}
+
+impl TranscriptionChunk {
+    pub fn new(
+        text: impl Into<String>,
+        processing_time_ms: u32,
+        method: impl Into<String>,
+    ) -> Self {
+        Self {
+            text: text.into(),
+            processing_time_ms,
+            method: method.into(),
+            timestamp_ms: chrono::Utc::now().timestamp_millis(),
+            vad_probability: None,
+            confidence: None,
+        }
+    }
+
+    pub fn with_quality(mut self, vad_probability: Option<f32>, confidence: Option<f32>) -> Self {
+        self.vad_probability = vad_probability;
+        self.confidence = confidence;
+        self
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -115,3 +326,60 @@ pub struct EngineSelectionState {
     pub backend: String,
     pub engine_loading: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(names: &[&str]) -> Vec<String> {
+        names.iter().map(|k| k.to_string()).collect()
+    }
+
+    fn binding(start: Option<&[&str]>, stop: Option<&[&str]>) -> HotkeyBinding {
+        HotkeyBinding {
+            keys: keys(&["ControlLeft", "MetaLeft"]),
+            mode: RecordingMode::default(),
+            start_keys: start.map(keys),
+            stop_keys: stop.map(keys),
+        }
+    }
+
+    #[test]
+    fn combos_need_two_keys_or_a_lone_mouse_button() {
+        assert!(HotkeyBinding::default().is_valid());
+
+        let mut b = binding(None, None);
+        b.keys = keys(&["MouseBack"]);
+        assert!(b.is_valid());
+        b.keys = keys(&["KeyA"]);
+        assert!(!b.is_valid());
+        b.keys = keys(&["ControlLeft", "ShiftLeft", "KeyA"]);
+        assert!(!b.is_valid());
+        b.keys = Vec::new();
+        assert!(!b.is_valid());
+    }
+
+    #[test]
+    fn start_and_stop_combos_must_be_set_together_and_differ() {
+        let start: &[&str] = &["ControlLeft", "KeyR"];
+        let stop: &[&str] = &["ControlLeft", "KeyS"];
+        assert!(binding(Some(start), Some(stop)).is_valid());
+        assert!(!binding(Some(start), None).is_valid());
+        assert!(!binding(None, Some(stop)).is_valid());
+        assert!(!binding(Some(start), Some(&["KeyR", "ControlLeft"])).is_valid());
+        assert!(!binding(Some(start), Some(&["KeyS"])).is_valid());
+    }
+
+    #[test]
+    fn split_combos_only_when_both_are_set() {
+        let start: &[&str] = &["ControlLeft", "KeyR"];
+        let stop: &[&str] = &["MouseForward"];
+        let b = binding(Some(start), Some(stop));
+        let (s, t) = b.split_combos().unwrap();
+        assert_eq!(s, keys(start).as_slice());
+        assert_eq!(t, keys(stop).as_slice());
+
+        assert!(binding(Some(start), None).split_combos().is_none());
+        assert!(binding(None, None).split_combos().is_none());
+    }
+}
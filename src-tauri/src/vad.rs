@@ -1,37 +1,309 @@
 /// VAD (Voice Activity Detection) Manager
 ///
-/// Pure energy-based VAD: RMS threshold per 50ms frame, with hysteresis-based
+/// Silero VAD (ONNX) when `silero_vad.onnx` is in the models folder, otherwise an
+/// energy-based fallback (RMS threshold per 50ms frame). Both feed hysteresis-based
 /// segment detection for file transcription and a simple gate for live recording.
+use ort::session::Session;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 /// Frame size for energy VAD (50ms at 16kHz).
 const CHUNK_SIZE: usize = 800;
 
-pub struct VADManager;
+/// Energy-VAD RMS floor (~-46 dBFS) and live-gate probability cutoff at sensitivity 0.5.
+const DEFAULT_ENERGY_THRESHOLD: f32 = 0.005;
+const DEFAULT_SPEECH_CUTOFF: f32 = 0.25;
+
+/// Hysteresis onsets at sensitivity 0.5 as (normal, permissive for short clips).
+/// Energy probabilities jump from 0 to ~1, so its offset is half the onset; Silero's
+/// are calibrated, so it uses the recommended 0.5 with the offset 0.15 below.
+const ENERGY_ONSETS: (f32, f32) = (0.35, 0.2);
+const SILERO_ONSETS: (f32, f32) = (0.5, 0.35);
+const SILERO_OFFSET_GAP: f32 = 0.15;
+
+/// Silero v5 at 16 kHz: 512-sample frames, each prefixed with the previous frame's
+/// last 64 samples, and a [2, 1, 128] recurrent state carried between calls.
+const SILERO_FRAME: usize = 512;
+const SILERO_CONTEXT: usize = 64;
+const SILERO_STATE_LEN: usize = 2 * 128;
+const SILERO_MODEL_FILE: &str = "silero_vad.onnx";
+
+/// Threshold multiplier for a 0.0–1.0 sensitivity: ×4 at 0.0, ×1 at 0.5, ÷4 at 1.0.
+fn sensitivity_scale(sensitivity: f32) -> f32 {
+    4.0_f32.powf(1.0 - 2.0 * sensitivity)
+}
+
+/// Effective thresholds for a 0.0–1.0 sensitivity, returned to the UI.
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub struct VadSensitivity {
+    pub sensitivity: f32,
+    /// RMS below which the energy VAD reports silence.
+    pub energy_threshold: f32,
+    /// Peak speech probability a live chunk must exceed to be transcribed.
+    pub speech_cutoff: f32,
+}
+
+struct SileroVad {
+    session: Session,
+    state: Vec<f32>,
+    context: Vec<f32>,
+}
+
+impl SileroVad {
+    fn load(path: &std::path::Path) -> Result<Self, String> {
+        let builder = Session::builder()
+            .map_err(|e| format!("ORT builder: {}", e))?
+            .with_optimization_level(ort::session::builder::GraphOptimizationLevel::Level3)
+            .map_err(|e| format!("Silero opt level: {}", e))?;
+        let builder = crate::ort_session::configure_low_ram_session_builder(builder, "silero")?;
+        let session = builder
+            .commit_from_file(path)
+            .map_err(|e| format!("Silero load {}: {}", path.display(), e))?;
+        Ok(Self {
+            session,
+            state: vec![0.0; SILERO_STATE_LEN],
+            context: vec![0.0; SILERO_CONTEXT],
+        })
+    }
+
+    fn reset(&mut self) {
+        self.state.fill(0.0);
+        self.context.fill(0.0);
+    }
+
+    /// Speech probability of one frame (zero-padded to 512 samples).
+    fn frame_prob(&mut self, frame: &[f32]) -> Result<f32, String> {
+        let mut input = Vec::with_capacity(SILERO_CONTEXT + SILERO_FRAME);
+        input.extend_from_slice(&self.context);
+        input.extend_from_slice(&frame[..frame.len().min(SILERO_FRAME)]);
+        input.resize(SILERO_CONTEXT + SILERO_FRAME, 0.0);
+        self.context
+            .copy_from_slice(&input[input.len() - SILERO_CONTEXT..]);
+
+        let tensor = |shape: Vec<usize>, data: Vec<f32>| {
+            ort::value::Value::from_array((shape, data))
+                .map(|t| t.into_dyn())
+                .map_err(|e| format!("Tensor creation error: {}", e))
+        };
+        let input = tensor(vec![1, SILERO_CONTEXT + SILERO_FRAME], input)?;
+        let state = tensor(vec![2, 1, 128], self.state.clone())?;
+        let sr = ort::value::Value::from_array((Vec::<usize>::new(), vec![16000_i64]))
+            .map_err(|e| format!("Tensor creation error: {}", e))?;
+
+        let outputs = self
+            .session
+            .run(ort::inputs!["input" => input, "state" => state, "sr" => sr])
+            .map_err(|e| format!("Silero run: {}", e))?;
+        let (_, prob) = outputs
+            .get("output")
+            .ok_or("No 'output' from Silero")?
+            .try_extract_tensor::<f32>()
+            .map_err(|e| format!("Extract Silero output: {}", e))?;
+        let prob = prob.first().copied().unwrap_or(0.0);
+        let (_, next_state) = outputs
+            .get("stateN")
+            .ok_or("No 'stateN' from Silero")?
+            .try_extract_tensor::<f32>()
+            .map_err(|e| format!("Extract Silero state: {}", e))?;
+        if next_state.len() == SILERO_STATE_LEN {
+            self.state.copy_from_slice(next_state);
+        }
+        Ok(prob)
+    }
+}
+
+/// Window and hop (in ms) used by the live recording gate in `max_speech_prob`.
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct LiveVadFrameConfig {
+    pub frame_ms: u32,
+    pub hop_ms: u32,
+}
+
+pub struct VADManager {
+    live_frame: usize,         // samples at 16 kHz (energy fallback only)
+    live_hop: usize,           // samples at 16 kHz (energy fallback only)
+    silero: Option<SileroVad>, // None → energy VAD
+    sensitivity: f32,          // 0.0 (strict) – 1.0 (lenient); 0.5 = defaults
+    threshold: f32,            // energy RMS threshold
+    speech_cutoff: f32,        // live-gate probability cutoff
+}
 
 impl VADManager {
     pub fn new() -> Result<Self, String> {
-        Ok(Self)
+        let silero = match crate::utils::get_models_dir() {
+            Ok(dir) if dir.join(SILERO_MODEL_FILE).exists() => {
+                match SileroVad::load(&dir.join(SILERO_MODEL_FILE)) {
+                    Ok(model) => {
+                        println!("[VAD] Silero VAD loaded");
+                        Some(model)
+                    }
+                    Err(e) => {
+                        eprintln!("[VAD] Failed to load Silero ({}); using energy VAD", e);
+                        None
+                    }
+                }
+            }
+            _ => {
+                println!("[VAD] {} not found; using energy VAD", SILERO_MODEL_FILE);
+                None
+            }
+        };
+        Ok(Self {
+            live_frame: CHUNK_SIZE,
+            live_hop: CHUNK_SIZE,
+            silero,
+            sensitivity: 0.5,
+            threshold: DEFAULT_ENERGY_THRESHOLD,
+            speech_cutoff: DEFAULT_SPEECH_CUTOFF,
+        })
+    }
+
+    pub fn sensitivity(&self) -> VadSensitivity {
+        VadSensitivity {
+            sensitivity: self.sensitivity,
+            energy_threshold: self.threshold,
+            speech_cutoff: self.speech_cutoff,
+        }
+    }
+
+    /// Map 0.0–1.0 onto both thresholds, each scaled ×4 / ÷4 around the defaults at
+    /// the ends: loud rooms turn it down, quiet mics turn it up.
+    pub fn set_sensitivity(&mut self, sensitivity: f32) -> Result<VadSensitivity, String> {
+        if !(0.0..=1.0).contains(&sensitivity) {
+            return Err(format!(
+                "VAD sensitivity must be between 0 and 1, got {}",
+                sensitivity
+            ));
+        }
+        let scale = sensitivity_scale(sensitivity);
+        self.sensitivity = sensitivity;
+        self.set_threshold(DEFAULT_ENERGY_THRESHOLD * scale);
+        self.speech_cutoff = (DEFAULT_SPEECH_CUTOFF * scale.sqrt()).min(0.5);
+        Ok(self.sensitivity())
+    }
+
+    /// (onset, offset) for `get_speech_timestamps_hysteresis`: the active backend's
+    /// defaults (`permissive` for short recordings), scaled by the stored sensitivity
+    /// the same way the live cutoff is.
+    pub fn hysteresis(&self, permissive: bool) -> (f32, f32) {
+        let scale = sensitivity_scale(self.sensitivity).sqrt();
+        let pick = |(normal, short): (f32, f32)| if permissive { short } else { normal };
+        if self.silero.is_some() {
+            let onset = (pick(SILERO_ONSETS) * scale).clamp(0.05, 0.9);
+            (onset, (onset - SILERO_OFFSET_GAP).max(0.01))
+        } else {
+            let onset = (pick(ENERGY_ONSETS) * scale).clamp(0.05, 0.9);
+            (onset, onset * 0.5)
+        }
+    }
+
+    /// Set the raw energy-VAD RMS threshold.
+    pub fn set_threshold(&mut self, threshold: f32) {
+        self.threshold = threshold.max(f32::EPSILON);
+    }
+
+    pub fn speech_cutoff(&self) -> f32 {
+        self.speech_cutoff
+    }
+
+    pub fn is_silero(&self) -> bool {
+        self.silero.is_some()
+    }
+
+    /// Samples per probability frame for the active backend.
+    fn frame_len(&self) -> usize {
+        if self.silero.is_some() {
+            SILERO_FRAME
+        } else {
+            CHUNK_SIZE
+        }
+    }
+
+    /// Speech probability of one frame. A Silero failure is logged once and switches
+    /// to energy VAD for the rest of the session, rather than failing (and logging)
+    /// every frame.
+    fn frame_prob(&mut self, frame: &[f32]) -> f32 {
+        if let Some(silero) = self.silero.as_mut() {
+            match silero.frame_prob(frame) {
+                Ok(prob) => return prob,
+                Err(e) => {
+                    eprintln!(
+                        "[VAD] Silero failed ({}); using energy VAD for this session",
+                        e
+                    );
+                    self.silero = None;
+                }
+            }
+        }
+        Self::energy_vad(frame, self.threshold)
+    }
+
+    /// Current live-gate window/hop.
+    pub fn live_frame_config(&self) -> LiveVadFrameConfig {
+        LiveVadFrameConfig {
+            frame_ms: (self.live_frame / 16) as u32,
+            hop_ms: (self.live_hop / 16) as u32,
+        }
+    }
+
+    /// Change the live-gate window/hop (energy VAD; Silero always uses 32 ms frames). Smaller windows catch brief interjections in
+    /// otherwise quiet chunks; a hop shorter than the window makes frames overlap so
+    /// speech straddling a frame boundary isn't split across two quiet halves.
+    pub fn set_live_frame_config(&mut self, config: LiveVadFrameConfig) -> Result<(), String> {
+        if !(10..=1000).contains(&config.frame_ms) {
+            return Err(format!(
+                "VAD frame must be 10–1000 ms, got {}",
+                config.frame_ms
+            ));
+        }
+        if config.hop_ms == 0 || config.hop_ms > config.frame_ms {
+            return Err(format!(
+                "VAD hop must be 1–{} ms, got {}",
+                config.frame_ms, config.hop_ms
+            ));
+        }
+        self.live_frame = config.frame_ms as usize * 16;
+        self.live_hop = config.hop_ms as usize * 16;
+        Ok(())
     }
 
-    /// No-op — kept for call-site compatibility with the live recording path.
-    pub fn reset_state(&mut self) {}
+    /// Clear Silero's recurrent state so a new recording doesn't inherit the last one.
+    pub fn reset_state(&mut self) {
+        if let Some(silero) = self.silero.as_mut() {
+            silero.reset();
+        }
+    }
 
     /// Return a speech probability for `audio` (0.0 = silence, 1.0 = speech).
+    /// With Silero this is the peak over its 512-sample frames.
     pub fn is_speech(&mut self, audio: &[f32]) -> Result<f32, String> {
-        Ok(Self::energy_vad(audio))
+        let Some(silero) = self.silero.as_mut() else {
+            return Ok(Self::energy_vad(audio, self.threshold));
+        };
+        let mut peak: f32 = 0.0;
+        for frame in audio.chunks(SILERO_FRAME) {
+            peak = peak.max(silero.frame_prob(frame)?);
+        }
+        Ok(peak)
     }
 
-    /// Scan `audio` in CHUNK_SIZE frames and return the peak speech probability.
+    /// Scan 16 kHz `audio` in live-gate frames (see `set_live_frame_config`) and return
+    /// the peak speech probability, so one short burst of speech keeps the whole chunk.
     /// Stops early once a frame exceeds 0.5 (short-circuit: unambiguous speech found).
     pub fn max_speech_prob(&mut self, audio: &[f32], max_frames: usize) -> f32 {
         if audio.is_empty() || max_frames == 0 {
             return 0.0;
         }
+        // Silero is recurrent, so its frames must be contiguous (hop == frame).
+        let (frame_len, hop) = if self.silero.is_some() {
+            (SILERO_FRAME.min(audio.len()), SILERO_FRAME)
+        } else {
+            (self.live_frame.min(audio.len()), self.live_hop)
+        };
+        let starts = (0..=audio.len() - frame_len).step_by(hop);
         let mut peak: f32 = 0.0;
-        for frame in audio.chunks(CHUNK_SIZE).take(max_frames) {
-            let prob = Self::energy_vad(frame);
+        for start in starts.take(max_frames) {
+            let prob = self.frame_prob(&audio[start..start + frame_len]);
             if prob > peak {
                 peak = prob;
             }
@@ -43,15 +315,14 @@ impl VADManager {
     }
 
     /// Energy-based speech probability.
-    /// RMS < 0.005 (~-46 dBFS) → 0.0 (silence).
-    /// RMS > 0.025 (~-32 dBFS) → 1.0 (speech).
+    /// RMS < threshold (default 0.005, ~-46 dBFS) → 0.0 (silence).
+    /// RMS > 5 × threshold (default ~-32 dBFS) → 1.0 (speech).
     /// Linear ramp between.
-    fn energy_vad(audio: &[f32]) -> f32 {
+    fn energy_vad(audio: &[f32], threshold: f32) -> f32 {
         if audio.is_empty() {
             return 0.0;
         }
         let rms = (audio.iter().map(|&x| x * x).sum::<f32>() / audio.len() as f32).sqrt();
-        let threshold = 0.005_f32;
         if rms < threshold {
             0.0
         } else if rms > threshold * 5.0 {
@@ -64,8 +335,9 @@ impl VADManager {
     /// Hysteresis-based segment finder used by file transcription.
     ///
     /// A segment STARTS when `prob > onset` and ENDS when `prob stays below offset`
-    /// for longer than `padding_ms`. Callers pass in (onset, offset) pairs; for energy
-    /// VAD, onset=0.5 / offset=0.2 works well (speech is ~1.0, silence is 0.0).
+    /// for longer than `padding_ms`. Callers pass in (onset, offset) pairs, normally
+    /// from `hysteresis()`, which knows the active backend's defaults. Resets
+    /// Silero's state first, since the audio is unrelated to the live stream.
     pub fn get_speech_timestamps_hysteresis(
         &mut self,
         audio: &[f32],
@@ -76,8 +348,10 @@ impl VADManager {
         const SAMPLE_RATE: f32 = 16000.0;
         const MIN_SPEECH_FRAMES: usize = 2;
 
-        let frame_ms = (CHUNK_SIZE as f32 / SAMPLE_RATE * 1000.0) as usize;
+        let frame_size = self.frame_len();
+        let frame_ms = (frame_size as f32 / SAMPLE_RATE * 1000.0) as usize;
         let padding_frames = padding_ms / frame_ms.max(1);
+        self.reset_state();
 
         let mut segments = Vec::new();
         let mut speech_start: Option<usize> = None;
@@ -86,8 +360,8 @@ impl VADManager {
         let mut max_prob: f32 = 0.0;
         let mut frame_count: usize = 0;
 
-        for (i, chunk) in audio.chunks(CHUNK_SIZE).enumerate() {
-            let prob = Self::energy_vad(chunk);
+        for (i, chunk) in audio.chunks(frame_size).enumerate() {
+            let prob = self.frame_prob(chunk);
             max_prob = max_prob.max(prob);
             frame_count += 1;
 
@@ -111,8 +385,8 @@ impl VADManager {
                                     speech_start.unwrap().saturating_sub(padding_frames);
                                 let end_idx = i;
                                 segments.push((
-                                    (start_idx * CHUNK_SIZE) as f32 / SAMPLE_RATE,
-                                    (end_idx * CHUNK_SIZE) as f32 / SAMPLE_RATE,
+                                    (start_idx * frame_size) as f32 / SAMPLE_RATE,
+                                    (end_idx * frame_size) as f32 / SAMPLE_RATE,
                                 ));
                             }
                             speech_start = None;
@@ -128,7 +402,7 @@ impl VADManager {
             if consecutive_speech >= MIN_SPEECH_FRAMES {
                 let start_idx = start_idx.saturating_sub(padding_frames);
                 segments.push((
-                    (start_idx * CHUNK_SIZE) as f32 / SAMPLE_RATE,
+                    (start_idx * frame_size) as f32 / SAMPLE_RATE,
                     audio.len() as f32 / SAMPLE_RATE,
                 ));
             }
@@ -158,6 +432,73 @@ impl VADManager {
     }
 }
 
+/// Merge speech segments separated by at most `max_gap_secs` of silence, keeping the pause
+/// itself inside the merged segment. Longer pauses still split. Input must be sorted.
+pub fn merge_segments_within(segments: &[(f32, f32)], max_gap_secs: f32) -> Vec<(f32, f32)> {
+    let mut merged: Vec<(f32, f32)> = Vec::with_capacity(segments.len());
+    for &(start, end) in segments {
+        match merged.last_mut() {
+            Some(last) if start - last.1 <= max_gap_secs => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Split segments longer than `max_secs` into windows of `max_secs` that overlap by
+/// `overlap_secs`, so one long stretch of continuous speech never exceeds a decoder
+/// window. Shorter segments pass through unchanged.
+pub fn split_long_segments(
+    segments: &[(f32, f32)],
+    max_secs: f32,
+    overlap_secs: f32,
+) -> Vec<(f32, f32)> {
+    let step = (max_secs - overlap_secs).max(1.0);
+    let mut split = Vec::with_capacity(segments.len());
+    for &(start, end) in segments {
+        let mut s = start;
+        while end - s > max_secs {
+            split.push((s, s + max_secs));
+            s += step;
+        }
+        split.push((s, end));
+    }
+    split
+}
+
+/// Find a natural pause to cut a live streaming chunk at.
+///
+/// Scans 50 ms frames at `sample_rate` and returns the sample index in the middle of the
+/// first run of at least `min_pause_ms` silence that follows speech and lands at or after
+/// `min_len` samples. Returns `None` if no such pause exists yet.
+pub fn find_pause_cut(
+    audio: &[f32],
+    sample_rate: u32,
+    min_len: usize,
+    min_pause_ms: u32,
+) -> Option<usize> {
+    let frame = (sample_rate as usize / 20).max(1);
+    let pause_frames = (min_pause_ms as usize * sample_rate as usize / 1000 / frame).max(1);
+    let mut silent_run = 0usize;
+    let mut heard_speech = false;
+
+    for (i, chunk) in audio.chunks_exact(frame).enumerate() {
+        if VADManager::energy_vad(chunk, DEFAULT_ENERGY_THRESHOLD) < 0.1 {
+            silent_run += 1;
+            if heard_speech && silent_run >= pause_frames {
+                let cut = (i + 1) * frame - silent_run * frame / 2;
+                if cut >= min_len {
+                    return Some(cut);
+                }
+            }
+        } else {
+            silent_run = 0;
+            heard_speech = true;
+        }
+    }
+    None
+}
+
 /// Run **energy-based** VAD on the full audio, collect speech-only segments, and concatenate
 /// them into a single buffer for the ASR. Silent sections are omitted.
 pub fn assemble_speech_audio(
@@ -282,3 +623,49 @@ pub fn assemble_speech_audio(
 
     Ok(assembled)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segment_of_exactly_max_length_is_not_split() {
+        assert_eq!(
+            split_long_segments(&[(2.0, 27.0)], 25.0, 1.0),
+            vec![(2.0, 27.0)]
+        );
+    }
+
+    #[test]
+    fn segment_just_over_max_length_splits_with_overlap() {
+        assert_eq!(
+            split_long_segments(&[(0.0, 25.5)], 25.0, 1.0),
+            vec![(0.0, 25.0), (24.0, 25.5)]
+        );
+    }
+
+    /// 16 kHz audio from (seconds, is_speech) runs: a loud constant for speech, zeros for silence.
+    fn audio(runs: &[(f32, bool)]) -> Vec<f32> {
+        runs.iter()
+            .flat_map(|&(secs, speech)| {
+                std::iter::repeat(if speech { 0.1 } else { 0.0 }).take((secs * 16000.0) as usize)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pause_before_min_len_is_not_a_cut() {
+        let early_pause = audio(&[(0.5, true), (0.5, false), (1.0, true)]);
+        assert_eq!(find_pause_cut(&early_pause, 16000, 16000, 300), None);
+
+        let with_late_pause = audio(&[(0.5, true), (0.5, false), (1.0, true), (0.5, false)]);
+        let cut = find_pause_cut(&with_late_pause, 16000, 16000, 300).unwrap();
+        assert!((32000..40000).contains(&cut), "cut at {}", cut);
+    }
+
+    #[test]
+    fn leading_silence_is_not_a_pause() {
+        let quiet_start = audio(&[(1.0, false), (1.0, true)]);
+        assert_eq!(find_pause_cut(&quiet_start, 16000, 0, 300), None);
+    }
+}
@@ -6,7 +6,7 @@ use std::ffi::c_void; // Import raw pointer types for interacting with C code
 use std::os::raw::c_char; // Import C-style character types
 use whisper_rs::{
     print_system_info, set_log_callback, FullParams, SamplingStrategy, WhisperContext,
-    WhisperContextParameters,
+    WhisperContextParameters, WhisperState,
 }; // Import the Whisper AI library functions
 
 /// whisper.cpp exposes GGML capability flags. Older builds used `CUDA = 1`; newer builds
@@ -22,7 +22,7 @@ fn infer_whisper_backend_from_system_info(info: &str) -> Option<GpuBackend> {
         return Some(GpuBackend::CoreML);
     }
     if info.contains("METAL = 1") {
-        return Some(GpuBackend::CoreML);
+        return Some(GpuBackend::Metal);
     }
     if info.contains("VULKAN = 1") {
         return Some(GpuBackend::Vulkan);
@@ -62,6 +62,50 @@ fn warn_whisper_backend_mismatch(info: &str, backend: &GpuBackend) {
     }
 }
 
+/// GPU backend compiled into this whisper.cpp build, read from its system info.
+/// Says what the binary can use, not whether the loaded model is using it.
+pub fn compiled_gpu_backend() -> Option<GpuBackend> {
+    infer_whisper_backend_from_system_info(print_system_info())
+}
+
+/// Append one segment's raw bytes to `buf`. Multilingual models can split a
+/// multi-byte character across segments, so the separator is only inserted
+/// when `buf` ends on a character boundary (otherwise it would corrupt it).
+fn push_segment_bytes(buf: &mut Vec<u8>, segment: &[u8], separator: &[u8]) {
+    if !buf.is_empty() && !ends_mid_char(buf) {
+        buf.extend_from_slice(separator);
+    }
+    buf.extend_from_slice(segment);
+}
+
+/// True when the trailing bytes are the start of an incomplete UTF-8 sequence.
+fn ends_mid_char(bytes: &[u8]) -> bool {
+    let start = bytes.len().saturating_sub(4);
+    for (i, &b) in bytes[start..].iter().enumerate().rev() {
+        if b & 0xC0 == 0x80 {
+            continue; // continuation byte — keep looking for the lead byte
+        }
+        let len = match b {
+            0xF0..=0xFF => 4,
+            0xE0..=0xEF => 3,
+            0xC0..=0xDF => 2,
+            _ => 1,
+        };
+        return start + i + len > bytes.len();
+    }
+    false
+}
+
+/// Decode the joined segment bytes in one pass so characters split between
+/// segments come out whole; anything still invalid becomes U+FFFD.
+fn decode_segment_bytes(buf: &[u8]) -> String {
+    let (text, had_errors) = encoding_rs::UTF_8.decode_without_bom_handling(buf);
+    if had_errors {
+        eprintln!("[WHISPER] ⚠ Segment text contained invalid UTF-8; replaced with U+FFFD");
+    }
+    text.into_owned()
+}
+
 // Note: We don't embed the model in the binary because it's too big (hundreds of MBs)
 // const MODEL_BYTES: &[u8] = ...;
 
@@ -70,7 +114,8 @@ fn warn_whisper_backend_mismatch(info: &str, backend: &GpuBackend) {
 #[derive(Debug, Clone)]
 pub enum GpuBackend {
     Cuda,   // NVIDIA GPUs (Very Fast)
-    CoreML, // macOS Apple Silicon / Neural Engine
+    CoreML, // macOS Apple Silicon / Neural Engine (encoder only, needs .mlmodelc)
+    Metal,  // macOS Apple GPU (whisper.cpp's default GPU path on Mac)
     Vulkan, // AMD/Intel/Other GPUs (Fast)
     Cpu,    // Processor (Slow fallback)
 }
@@ -81,6 +126,7 @@ impl std::fmt::Display for GpuBackend {
         match self {
             GpuBackend::Cuda => write!(f, "CUDA"),
             GpuBackend::CoreML => write!(f, "CoreML"),
+            GpuBackend::Metal => write!(f, "Metal"),
             GpuBackend::Vulkan => write!(f, "Vulkan"),
             GpuBackend::Cpu => write!(f, "CPU"),
         }
@@ -101,10 +147,53 @@ pub struct ModelInfo {
 /// The Manager that controls the Whisper AI
 pub struct WhisperManager {
     context: Option<WhisperContext>, // The loaded AI brain (can be None if not loaded yet)
+    live_state: Option<WhisperState>, // Decoder state reused by live chunks until clear_context
     last_transcript: String,         // Memorizes what was said previously (context)
+    last_chunk_confidence: Option<f32>, // Mean token probability of the last live chunk
     backend: GpuBackend,             // Current hardware being used (CPU/GPU)
     current_model: Option<String>,   // Name of the currently loaded model
     resampler: Option<(u32, usize, Box<SincFixedIn<f32>>)>, // (Sample Rate, Chunk Size, Resampler)
+    token_suppression: TokenSuppression, // Decoder token masks applied to every pass
+    warmup: WhisperWarmup,           // Silence pass run after a model loads
+    default_model: Option<String>,   // Model `initialize(None)` prefers (user's startup pick)
+    language: Option<String>,        // Spoken language (ISO 639-1); None = auto-detect
+    translate: bool,                 // Translate speech to English text while decoding
+}
+
+/// Whether `initialize` runs a silent warm-up pass, and how long it is.
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct WhisperWarmup {
+    /// Skip this to shave load time on CPU, where the first real chunk pays the cost anyway.
+    pub enabled: bool,
+    /// Seconds of silence fed through the model (0.1–10).
+    pub secs: f32,
+}
+
+impl Default for WhisperWarmup {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            secs: 1.0,
+        }
+    }
+}
+
+/// Which tokens whisper.cpp masks out while decoding.
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct TokenSuppression {
+    /// Don't let a segment start with a blank/space token.
+    pub suppress_blank: bool,
+    /// Mask non-speech "event" tokens such as [music] or (laughs).
+    pub suppress_non_speech: bool,
+}
+
+impl Default for TokenSuppression {
+    fn default() -> Self {
+        Self {
+            suppress_blank: true,
+            suppress_non_speech: true,
+        }
+    }
 }
 
 // Suppress noisy C++ logs from whisper.cpp.
@@ -138,12 +227,72 @@ impl WhisperManager {
     /// Create a new Whisper Manager (Constructor)
     pub fn new() -> Self {
         Self {
-            context: None,                  // Start with no model loaded
+            context: None, // Start with no model loaded
+            live_state: None,
             last_transcript: String::new(), // Start with empty memory
-            backend: GpuBackend::Cpu,       // Assume CPU until we prove otherwise
-            current_model: None,            // No model selected yet
+            last_chunk_confidence: None,
+            backend: GpuBackend::Cpu, // Assume CPU until we prove otherwise
+            current_model: None,      // No model selected yet
             resampler: None,
+            token_suppression: TokenSuppression::default(),
+            warmup: WhisperWarmup::default(),
+            default_model: None,
+            language: Some("en".to_string()),
+            translate: false,
+        }
+    }
+
+    pub fn token_suppression(&self) -> TokenSuppression {
+        self.token_suppression
+    }
+
+    /// Takes effect on the next decode; no model reload needed.
+    pub fn set_token_suppression(&mut self, suppression: TokenSuppression) {
+        self.token_suppression = suppression;
+    }
+
+    pub fn warmup(&self) -> WhisperWarmup {
+        self.warmup
+    }
+
+    pub fn default_model(&self) -> Option<&String> {
+        self.default_model.as_ref()
+    }
+
+    /// Model loaded when `initialize` is called without an id (falls back to tiny.en).
+    pub fn set_default_model(&mut self, model_id: Option<String>) {
+        self.default_model = model_id;
+    }
+
+    pub fn language(&self) -> Option<&String> {
+        self.language.as_ref()
+    }
+
+    /// Language passed to every decode (live chunks, final pass, warm-up). `None`
+    /// lets Whisper auto-detect; English-only `.en` models ignore anything but "en".
+    pub fn set_language(&mut self, language: Option<String>) {
+        self.language = language;
+    }
+
+    pub fn translate(&self) -> bool {
+        self.translate
+    }
+
+    /// Emit English text for non-English speech in the same pass (all decode paths).
+    pub fn set_translate(&mut self, translate: bool) {
+        self.translate = translate;
+    }
+
+    /// Applies from the next `initialize`.
+    pub fn set_warmup(&mut self, warmup: WhisperWarmup) -> Result<(), String> {
+        if !(0.1..=10.0).contains(&warmup.secs) {
+            return Err(format!(
+                "Warm-up duration must be between 0.1 and 10 seconds (got {})",
+                warmup.secs
+            ));
         }
+        self.warmup = warmup;
+        Ok(())
     }
 
     /// Helper: Find the folder where models are stored (AppData/Local/Taurscribe/models)
@@ -273,10 +422,17 @@ impl WhisperManager {
         &self.backend
     }
 
+    /// Mean token probability (0.0–1.0) of the most recent `transcribe_chunk` call,
+    /// or `None` if that chunk produced no text tokens.
+    pub fn last_chunk_confidence(&self) -> Option<f32> {
+        self.last_chunk_confidence
+    }
+
     /// Wipe the "memory" of the conversation (clear context)
     /// Used when starting a completely new recording session
     pub fn clear_context(&mut self) {
         self.last_transcript.clear();
+        self.live_state = None;
         println!("[INFO] Context cleared - starting fresh");
     }
 
@@ -296,6 +452,7 @@ impl WhisperManager {
                     ("resampler_input_samples", resampler_buffer_len),
                 ],
             );
+            self.live_state = None;
             self.context = None;
             self.current_model = None;
             self.backend = GpuBackend::Cpu;
@@ -336,7 +493,7 @@ impl WhisperManager {
         let target_model = match model_id {
             Some(id) => id.to_string(),
             None => {
-                let default_id = "tiny.en-q5_1";
+                let default_id = self.default_model.as_deref().unwrap_or("tiny.en-q5_1");
                 if models_dir.join(format!("ggml-{}.bin", default_id)).exists() {
                     default_id.to_string()
                 } else {
@@ -390,7 +547,8 @@ impl WhisperManager {
                 .or_else(|_| self.try_cpu(&absolute_path))?
         };
 
-        // Save the loaded state
+        // Save the loaded state (a cached live state belongs to the old model)
+        self.live_state = None;
         self.context = Some(ctx);
         self.backend = backend.clone();
         self.current_model = Some(target_model.to_string());
@@ -403,19 +561,30 @@ impl WhisperManager {
         );
         println!("[INFO] Model loaded: {}", target_model);
 
-        println!("[INFO] Warming up {} compute backend...", backend);
-        println!("[DEBUG] Creating warmup audio buffer...");
-        let warmup_audio = vec![0.0_f32; 16000]; // Create 1 second of silence
-        println!("[DEBUG] Starting transcribe_chunk for warmup...");
-        match self.transcribe_chunk(&warmup_audio, 16000) {
-            Ok(_) => println!("[INFO] {} warm-up complete", backend),
-            Err(e) => println!("[WARN] Warm-up failed (not critical): {}", e),
+        if self.warmup.enabled {
+            println!("[INFO] Warming up {} compute backend...", backend);
+            match self.warm_up() {
+                Ok(()) => println!("[INFO] {} warm-up complete", backend),
+                Err(e) => println!("[WARN] Warm-up failed (not critical): {}", e),
+            }
+        } else {
+            println!("[INFO] Warm-up disabled; first chunk will pay the backend start-up cost");
         }
         println!("[DEBUG] Initialization sequence finished.");
 
         Ok(backend_msg)
     }
 
+    /// Run `warmup.secs` of silence through the loaded model so GPU kernels and weights are
+    /// resident before real audio arrives. The session context is left untouched.
+    pub fn warm_up(&mut self) -> Result<(), String> {
+        let saved_context = std::mem::take(&mut self.last_transcript);
+        let warmup_audio = vec![0.0_f32; (self.warmup.secs * 16000.0) as usize];
+        let result = self.transcribe_chunk(&warmup_audio, 16000).map(|_| ());
+        self.last_transcript = saved_context;
+        result
+    }
+
     /// Helper: Try to initialize with GPU settings
     fn try_gpu(
         &self,
@@ -455,8 +624,10 @@ impl WhisperManager {
             return GpuBackend::Cuda;
         }
 
+        // whisper.cpp always builds the Metal backend on macOS; CoreML is only
+        // reported when the GGML flags say the encoder actually ran on it.
         if cfg!(target_os = "macos") {
-            return GpuBackend::CoreML;
+            return GpuBackend::Metal;
         }
 
         GpuBackend::Vulkan
@@ -577,10 +748,15 @@ impl WhisperManager {
             ],
         );
 
-        // 🧠 STEP 2: Create a state for this specific transcription task
-        let mut state = ctx
-            .create_state()
-            .map_err(|e| format!("Failed to create state: {:?}", e))?;
+        // 🧠 STEP 2: Reuse the session's state so each chunk skips allocating the
+        // KV caches and compute buffers again (created on the first chunk)
+        if self.live_state.is_none() {
+            self.live_state = Some(
+                ctx.create_state()
+                    .map_err(|e| format!("Failed to create state: {:?}", e))?,
+            );
+        }
+        let state = self.live_state.as_mut().unwrap();
 
         // ⚙️ STEP 3: Configure Transcription Parameters
         // "Greedy" strategy picks the most likely word immediately (fastest)
@@ -595,15 +771,16 @@ impl WhisperManager {
         .max(4)
         .min(8) as i32;
         params.set_n_threads(n_threads);
-        params.set_translate(false);
-        params.set_language(Some("en"));
+        params.set_translate(self.translate);
+        params.set_language(self.language.as_deref());
         params.set_print_special(false);
         params.set_print_progress(false);
         params.set_print_realtime(false);
         params.set_print_timestamps(false);
+        params.set_suppress_blank(self.token_suppression.suppress_blank);
         // OpenAI Whisper: suppress_non_speech_tokens — masks tokenizer “event” tokens
         // (music, applause, …) during decoding. See whisper/tokenizer.py in upstream Whisper.
-        params.set_suppress_nst(true);
+        params.set_suppress_nst(self.token_suppression.suppress_non_speech);
 
         // ── Speed optimizations for live chunked transcription ──────────────
         // Dynamic audio context: whisper's encoder attention is O(n²) in frame
@@ -634,18 +811,36 @@ impl WhisperManager {
         let start = std::time::Instant::now();
 
         // 🚀 STEP 5: Run the AI!
-        state
-            .full(params, &audio_data)
-            .map_err(|e| format!("Transcription failed: {:?}", e))?;
+        if let Err(e) = state.full(params, &audio_data) {
+            // Don't carry a state that failed mid-decode into the next chunk.
+            self.live_state = None;
+            return Err(format!("Transcription failed: {:?}", e));
+        }
 
         // 📝 STEP 6: Extract the text from the result
+        // Token probabilities are averaged over text tokens only — special tokens
+        // ([_BEG_], <|endoftext|>, …) sit near 1.0 and would hide uncertain words.
+        let eot = ctx.token_eot();
         let num_segments = state.full_n_segments();
-        let mut transcript = String::new();
+        let mut transcript_bytes = Vec::new();
+        let (mut prob_sum, mut prob_count) = (0.0_f32, 0_u32);
         for i in 0..num_segments {
             if let Some(segment) = state.get_segment(i) {
-                transcript.push_str(&segment.to_string());
+                if let Ok(bytes) = segment.to_bytes() {
+                    push_segment_bytes(&mut transcript_bytes, bytes, b"");
+                }
+                for j in 0..segment.n_tokens() {
+                    if let Some(token) = segment.get_token(j) {
+                        if token.token_id() < eot {
+                            prob_sum += token.token_probability();
+                            prob_count += 1;
+                        }
+                    }
+                }
             }
         }
+        self.last_chunk_confidence = (prob_count > 0).then(|| prob_sum / prob_count as f32);
+        let transcript = decode_segment_bytes(&transcript_bytes);
 
         // Strip caption tags before context prompt — raw "(music)" in initial_prompt
         // strongly biases the next chunk toward the same hallucination.
@@ -723,8 +918,8 @@ impl WhisperManager {
             .map(|n| n.get().min(8) as i32)
             .unwrap_or(8);
         params.set_n_threads(n_threads);
-        params.set_translate(false);
-        params.set_language(Some("en"));
+        params.set_translate(self.translate);
+        params.set_language(self.language.as_deref());
         params.set_print_special(false);
         params.set_print_progress(false);
         params.set_print_realtime(false);
@@ -732,7 +927,8 @@ impl WhisperManager {
         params.set_token_timestamps(false);
         params.set_no_timestamps(true); // timestamps never displayed; skip their generation
         params.set_max_tokens(256); // reasonable cap for a full recording pass
-        params.set_suppress_nst(true);
+        params.set_suppress_blank(self.token_suppression.suppress_blank);
+        params.set_suppress_nst(self.token_suppression.suppress_non_speech);
 
         // Inject active-app context as initial prompt so Whisper favours
         // domain-relevant vocabulary (e.g. code identifiers, document titles).
@@ -750,13 +946,15 @@ impl WhisperManager {
 
         // Extract
         let num_segments = state.full_n_segments();
-        let mut transcript = String::new();
+        let mut transcript_bytes = Vec::new();
         for i in 0..num_segments {
             if let Some(segment) = state.get_segment(i) {
-                transcript.push_str(&segment.to_string());
-                transcript.push(' ');
+                if let Ok(bytes) = segment.to_bytes() {
+                    push_segment_bytes(&mut transcript_bytes, bytes, b" ");
+                }
             }
         }
+        let transcript = decode_segment_bytes(&transcript_bytes);
 
         let duration = start.elapsed();
         let audio_duration = audio_data.len() as f32 / 16000.0;
@@ -771,6 +969,138 @@ impl WhisperManager {
         Ok(strip_whitelisted_sound_captions(transcript.trim()))
     }
 
+    /// Transcribe 16 kHz audio and return `(start_sec, end_sec, text)` per Whisper
+    /// segment. Slower than `transcribe_audio_data` (timestamp tokens are decoded),
+    /// so it's only used for exports, never the live or final-pass path.
+    pub fn transcribe_with_timestamps(
+        &mut self,
+        audio_data: &[f32],
+    ) -> Result<Vec<(f32, f32, String)>, String> {
+        let ctx = self
+            .context
+            .as_ref()
+            .ok_or("Whisper context not initialized")?;
+        let mut state = ctx
+            .create_state()
+            .map_err(|e| format!("Failed to create state: {:?}", e))?;
+
+        let mut params = FullParams::new(SamplingStrategy::BeamSearch {
+            beam_size: 5,
+            patience: -1.0,
+        });
+        let n_threads = std::thread::available_parallelism()
+            .map(|n| n.get().min(8) as i32)
+            .unwrap_or(8);
+        params.set_n_threads(n_threads);
+        params.set_translate(self.translate);
+        params.set_language(self.language.as_deref());
+        params.set_print_special(false);
+        params.set_print_progress(false);
+        params.set_print_realtime(false);
+        params.set_print_timestamps(false);
+        params.set_no_timestamps(false);
+        // Token-level timing tightens segment boundaries (used for subtitles).
+        params.set_token_timestamps(true);
+        params.set_suppress_blank(self.token_suppression.suppress_blank);
+        params.set_suppress_nst(self.token_suppression.suppress_non_speech);
+
+        state
+            .full(params, audio_data)
+            .map_err(|e| format!("Transcription failed: {:?}", e))?;
+
+        // Segment timestamps are in centiseconds.
+        let mut segments = Vec::new();
+        for i in 0..state.full_n_segments() {
+            if let Some(segment) = state.get_segment(i) {
+                let Ok(bytes) = segment.to_bytes() else {
+                    continue;
+                };
+                let text = strip_whitelisted_sound_captions(decode_segment_bytes(bytes).trim());
+                segments.push((
+                    segment.start_timestamp() as f32 / 100.0,
+                    segment.end_timestamp() as f32 / 100.0,
+                    text,
+                ));
+            }
+        }
+        Ok(segments)
+    }
+
+    /// Decode the same 16 kHz audio several times and return up to `n` distinct
+    /// transcripts, best first. whisper.cpp only surfaces the winning beam, so the
+    /// alternatives come from temperature-sampled re-decodes of the same audio —
+    /// the first entry is always the deterministic beam-search result.
+    pub fn transcribe_alternatives(
+        &mut self,
+        audio_data: &[f32],
+        n: usize,
+        initial_prompt: Option<&str>,
+    ) -> Result<Vec<String>, String> {
+        let best = self.transcribe_audio_data(audio_data, initial_prompt)?;
+        let mut alternatives = vec![best];
+
+        let ctx = self
+            .context
+            .as_ref()
+            .ok_or("Whisper context not initialized")?;
+        let n_threads = std::thread::available_parallelism()
+            .map(|n| n.get().min(8) as i32)
+            .unwrap_or(8);
+
+        // Each pass samples at a slightly higher temperature; cap the attempts so
+        // a confident model that keeps producing the same text doesn't loop forever.
+        let max_attempts = n.saturating_sub(1) * 2;
+        for attempt in 0..max_attempts {
+            if alternatives.len() >= n {
+                break;
+            }
+            let mut state = ctx
+                .create_state()
+                .map_err(|e| format!("Failed to create state: {:?}", e))?;
+            let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+            params.set_n_threads(n_threads);
+            params.set_translate(self.translate);
+            params.set_language(self.language.as_deref());
+            params.set_print_special(false);
+            params.set_print_progress(false);
+            params.set_print_realtime(false);
+            params.set_print_timestamps(false);
+            params.set_no_timestamps(true);
+            params.set_max_tokens(256);
+            params.set_suppress_blank(self.token_suppression.suppress_blank);
+            params.set_suppress_nst(self.token_suppression.suppress_non_speech);
+            params.set_temperature(0.4 + 0.1 * attempt as f32);
+            params.set_temperature_inc(0.0); // we choose the temperature, no fallback ladder
+            if let Some(prompt) = initial_prompt.filter(|p| !p.trim().is_empty()) {
+                params.set_initial_prompt(prompt);
+            }
+
+            state
+                .full(params, audio_data)
+                .map_err(|e| format!("Transcription failed: {:?}", e))?;
+
+            let mut transcript_bytes = Vec::new();
+            for i in 0..state.full_n_segments() {
+                if let Some(segment) = state.get_segment(i) {
+                    if let Ok(bytes) = segment.to_bytes() {
+                        push_segment_bytes(&mut transcript_bytes, bytes, b" ");
+                    }
+                }
+            }
+            let transcript = decode_segment_bytes(&transcript_bytes);
+            let text = strip_whitelisted_sound_captions(transcript.trim());
+            if !text.is_empty()
+                && !alternatives
+                    .iter()
+                    .any(|a| a.eq_ignore_ascii_case(text.as_str()))
+            {
+                alternatives.push(text);
+            }
+        }
+
+        Ok(alternatives)
+    }
+
     /// Helper: Load and prepare a WAV file for VAD/Whisper
     /// Handles reading, mono conversion, and resampling in one go
     pub fn load_audio(&self, file_path: &str) -> Result<Vec<f32>, String> {
@@ -788,22 +1118,18 @@ impl WhisperManager {
         if spec.sample_format == hound::SampleFormat::Float {
             samples.extend(reader.samples::<f32>().map(|s| s.unwrap_or(0.0)));
         } else {
+            // i32 reads 8/16/24/32-bit PCM alike; scale by the declared bit depth.
+            let scale = (1_i64 << (spec.bits_per_sample.max(1) - 1)) as f32;
             samples.extend(
                 reader
-                    .samples::<i16>()
-                    .map(|s| s.unwrap_or(0) as f32 / 32768.0),
+                    .samples::<i32>()
+                    .map(|s| s.unwrap_or(0) as f32 / scale),
             );
         }
 
-        // Mono
-        let mono_samples = if spec.channels == 2 {
-            samples
-                .chunks(2)
-                .map(|chunk| (chunk[0] + chunk[1]) / 2.0)
-                .collect::<Vec<f32>>()
-        } else {
-            samples
-        };
+        // Mono (any channel count)
+        let mono_samples =
+            crate::audio_preprocess::downmix_to_mono(&samples, spec.channels as usize);
 
         // Resample
         if spec.sample_rate != 16000 {
@@ -847,3 +1173,52 @@ impl WhisperManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Join segments the way the transcription loops do, then decode.
+    fn join(segments: &[&[u8]], separator: &[u8]) -> String {
+        let mut buf = Vec::new();
+        for segment in segments {
+            push_segment_bytes(&mut buf, segment, separator);
+        }
+        decode_segment_bytes(&buf)
+    }
+
+    #[test]
+    fn detects_incomplete_trailing_sequences() {
+        let e_acute = "é".as_bytes(); // 2 bytes
+        let euro = "€".as_bytes(); // 3 bytes
+        let emoji = "😀".as_bytes(); // 4 bytes
+
+        assert!(!ends_mid_char(b""));
+        assert!(!ends_mid_char(b"abc"));
+        assert!(!ends_mid_char(e_acute));
+        assert!(!ends_mid_char(euro));
+        assert!(!ends_mid_char(emoji));
+        assert!(ends_mid_char(&e_acute[..1]));
+        assert!(ends_mid_char(&euro[..2]));
+        assert!(ends_mid_char(&[b"x".as_slice(), &emoji[..3]].concat()));
+    }
+
+    #[test]
+    fn character_split_across_segments_stays_whole() {
+        let word = "café".as_bytes();
+        let (head, tail) = word.split_at(word.len() - 1); // splits the "é"
+
+        assert_eq!(join(&[head, tail], b""), "café");
+        assert_eq!(join(&[head, tail], b" "), "café");
+    }
+
+    #[test]
+    fn separator_still_goes_between_whole_segments() {
+        let euro = "€".as_bytes();
+        let segments: [&[u8]; 3] = [b"costs 5", &euro[..1], &euro[1..]];
+
+        assert_eq!(join(&segments, b" "), "costs 5 €");
+        assert_eq!(join(&[b"hello", b"world"], b" "), "hello world");
+        assert_eq!(join(&[b"hello", b"world"], b""), "helloworld");
+    }
+}
@@ -197,6 +197,14 @@ function App() {
       if (value) { await store.set('input_device', value); }
       else { await store.delete('input_device'); }
       await store.save();
+      // Mid-session, move the running recording to the new mic as well.
+      if (value && isRecordingRef.current) {
+        const result = await invoke<CommandResult<string>>('switch_input_device', { name: value });
+        setHeaderStatus(
+          result.ok ? `Now recording from ${value}` : (result.error?.message ?? "Couldn't switch microphone"),
+          4000
+        );
+      }
       // Re-resolve the actual device name (in case "default" mapped to a real name)
       invoke<string>('get_active_input_device').then(setActiveMic).catch(() => {});
     } catch (e) { console.error('Failed to set input device:', e); }
@@ -334,8 +342,8 @@ function App() {
   const { volume, muted, setVolume, setMuted, playStart, playPaste, playError } = useSounds();
 
   const {
-    dictionary, dictionaryRef, addDictEntry, updateDictEntry, removeDictEntry,
-    snippets, snippetsRef, addSnippet, updateSnippet, removeSnippet,
+    dictionary, addDictEntry, updateDictEntry, removeDictEntry,
+    snippets, addSnippet, updateSnippet, removeSnippet,
   } = usePersonalization();
 
   // useEngineSwitch must be declared before useRecording is *used* but after
@@ -358,7 +366,6 @@ function App() {
     setCurrentModel, setLoadedEngine: (e) => setLoadedEngineForwarded.current(e), enableGrammarLMRef,
     enableDenoiseRef, enableOverlayRef, muteBackgroundAudioRef, transcriptionStyleRef, setHeaderStatus, setTrayState, setIsSettingsOpen,
     playStart, playPaste, playError,
-    dictionaryRef, snippetsRef,
     onHistorySaved: () => setHistoryRefreshKey(k => k + 1),
     setSessionPhase,
     setSessionNotice,
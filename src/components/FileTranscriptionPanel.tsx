@@ -16,14 +16,25 @@ interface FileItem {
     transcript: string;
     audioDurationMs?: number;
     processingTimeMs?: number;
+    timing?: TimingBreakdown;
     expanded: boolean;
     error?: string;
 }
 
+interface TimingBreakdown {
+    decode_ms: number;
+    downmix_ms: number;
+    resample_ms: number;
+    vad_ms: number;
+    inference_ms: number;
+    postprocess_ms: number;
+}
+
 interface FileTranscriptionResult {
     transcript: string;
     audio_duration_ms: number;
     processing_time_ms: number;
+    timing: TimingBreakdown;
 }
 
 interface ProgressPayload {
@@ -177,6 +188,7 @@ export function FileTranscriptionPanel({ activeEngine, currentModel, currentPara
                               transcript: result.transcript,
                               audioDurationMs: result.audio_duration_ms,
                               processingTimeMs: result.processing_time_ms,
+                              timing: result.timing,
                           }
                         : f
                 )
@@ -238,6 +250,7 @@ export function FileTranscriptionPanel({ activeEngine, currentModel, currentPara
                         expanded: false,
                         audioDurationMs: undefined,
                         processingTimeMs: undefined,
+                        timing: undefined,
                         error: undefined,
                         engine: activeEngineRef.current,
                         modelId: activeModelIdRef.current,
@@ -273,6 +286,23 @@ export function FileTranscriptionPanel({ activeEngine, currentModel, currentPara
         return `${ratio.toFixed(1)}x`;
     };
 
+    const formatTiming = (timing: TimingBreakdown, processingTimeMs: number) => {
+        const stages: [string, number][] = [
+            ["Decode", timing.decode_ms],
+            ["Downmix", timing.downmix_ms],
+            ["Resample", timing.resample_ms],
+            ["VAD", timing.vad_ms],
+            ["Inference", timing.inference_ms],
+            ["Cleanup", timing.postprocess_ms],
+        ];
+        return stages
+            .map(([label, ms]) => {
+                const pct = processingTimeMs > 0 ? Math.round((ms / processingTimeMs) * 100) : 0;
+                return `${label}: ${ms} ms (${pct}%)`;
+            })
+            .join("\n");
+    };
+
     const removeFile = (id: string) => {
         setFiles(prev => prev.filter(f => f.id !== id));
     };
@@ -505,7 +535,12 @@ export function FileTranscriptionPanel({ activeEngine, currentModel, currentPara
                                         </span>
                                     )}
                                     {item.audioDurationMs != null && item.processingTimeMs != null && formatRealtime(item.audioDurationMs, item.processingTimeMs) && (
-                                        <span className="file-meta-badge file-meta-badge--speed" title="Transcription speed vs real-time">
+                                        <span
+                                            className="file-meta-badge file-meta-badge--speed"
+                                            title={item.timing
+                                                ? `Transcription speed vs real-time\n\n${formatTiming(item.timing, item.processingTimeMs)}`
+                                                : "Transcription speed vs real-time"}
+                                        >
                                             {formatRealtime(item.audioDurationMs, item.processingTimeMs)} speed
                                         </span>
                                     )}
@@ -164,6 +164,7 @@ export function SetupWizard({
         cuda_available: false,
         vram_gb: null,
         backend_hint: 'CPU',
+        backends: { whisper_gpu: null, onnx_providers: ['CPU'] },
       }));
     invoke<string>('get_platform').then(setPlatform).catch(() => {});
     invoke<boolean>('is_apple_silicon').then(setIsAppleSilicon).catch(() => {});
@@ -378,6 +379,11 @@ function StepHardware({
             <span className="hw-value">{sysInfo!.backend_hint}</span>
             <span className={`hw-status ${sysInfo!.cuda_available ? 'hw-status--ok' : 'hw-status--warn'}`} />
           </div>
+          <div className="hw-row">
+            <span className="hw-label">ONNX</span>
+            <span className="hw-value">{sysInfo!.backends.onnx_providers.join(', ')}</span>
+            <span className={`hw-status ${sysInfo!.backends.onnx_providers.length > 1 ? 'hw-status--ok' : 'hw-status--warn'}`} />
+          </div>
         </div>
       )}
 
@@ -1,5 +1,4 @@
 import { invoke } from '@tauri-apps/api/core';
-import { Store } from '@tauri-apps/plugin-store';
 
 interface AppTabProps {
     closeBehavior: 'tray' | 'quit';
@@ -17,9 +16,7 @@ export function AppTab({
 
     const handleCloseBehavior = async (val: 'tray' | 'quit') => {
         setCloseBehavior(val);
-        const store = await Store.load('settings.json');
-        await store.set('close_behavior', val);
-        await store.save();
+        // Persisted by the backend.
         await invoke('set_close_behavior', { behavior: val });
     };
 
@@ -37,6 +37,20 @@ export function PostProcessingTab({
     useEffect(() => { invoke<string>('get_platform').then(setPlatform).catch(() => {}); }, []);
     const isMac = platform === 'macos';
 
+    // Spellcheck lives entirely in the backend (it persists its own setting).
+    const [spellcheck, setSpellcheck] = useState(false);
+    const [spellcheckError, setSpellcheckError] = useState<string | null>(null);
+    useEffect(() => { invoke<boolean>('get_spellcheck_enabled').then(setSpellcheck).catch(() => {}); }, []);
+    const toggleSpellcheck = async (enabled: boolean) => {
+        try {
+            await invoke('set_spellcheck_enabled', { enabled });
+            setSpellcheck(enabled);
+            setSpellcheckError(null);
+        } catch (err) {
+            setSpellcheckError(String(err));
+        }
+    };
+
     const llmLoading = llmStatus === 'Loading...';
     const llmLoaded = llmStatus === 'Loaded';
     const llmNotDownloaded = llmStatus === 'Not Downloaded';
@@ -138,6 +152,32 @@ export function PostProcessingTab({
                 </div>
             </div>
 
+            {/* ── Spellcheck ──────────────────────────────────────── */}
+            <div className="setting-card" style={{ marginTop: '12px' }}>
+                <div className="setting-card-header">
+                    <div className="setting-card-label">
+                        <span>Spellcheck</span>
+                        <span className="setting-card-meta">SymSpell · frequency dictionary</span>
+                    </div>
+                    <label className="switch">
+                        <input
+                            type="checkbox"
+                            checked={spellcheck}
+                            onChange={e => toggleSpellcheck(e.target.checked)}
+                        />
+                        <span className="slider round" />
+                    </label>
+                </div>
+                <p className="setting-card-desc">
+                    Fixes misspelled words after the custom dictionary, before grammar correction.
+                </p>
+                {spellcheckError && (
+                    <p className="setting-card-desc" style={{ color: 'var(--error)', marginTop: '8px' }}>
+                        {spellcheckError}
+                    </p>
+                )}
+            </div>
+
             <p className="pp-tab-note">
                 Download the required models from the <strong>Models</strong> tab.
             </p>
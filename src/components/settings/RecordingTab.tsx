@@ -3,7 +3,14 @@ import { invoke } from '@tauri-apps/api/core';
 import { Store } from '@tauri-apps/plugin-store';
 
 type RecordingMode = 'hold' | 'toggle';
-interface HotkeyBinding { keys: string[]; mode: RecordingMode; }
+// start_keys / stop_keys: optional separate combos, set together (then keys/mode are unused).
+interface HotkeyBinding {
+    keys: string[];
+    mode: RecordingMode;
+    start_keys?: string[] | null;
+    stop_keys?: string[] | null;
+}
+type CaptureTarget = 'keys' | 'start_keys' | 'stop_keys';
 
 const isMac = navigator.platform.toLowerCase().includes('mac');
 const isLinux = navigator.platform.toLowerCase().includes('linux');
@@ -18,10 +25,28 @@ const KEY_LABELS: Record<string, string> = {
     F1: 'F1', F2: 'F2', F3: 'F3', F4: 'F4',
     F5: 'F5', F6: 'F6', F7: 'F7', F8: 'F8',
     F9: 'F9', F10: 'F10', F11: 'F11', F12: 'F12',
+    MouseMiddle: 'Middle Click', MouseBack: 'Mouse 4', MouseForward: 'Mouse 5',
 };
 
 const ALLOWED_KEYS = new Set(Object.keys(KEY_LABELS));
 
+// MouseEvent.button → binding code (left/right clicks can't be hotkeys).
+const MOUSE_BUTTON_CODES: Record<number, string> = { 1: 'MouseMiddle', 3: 'MouseBack', 4: 'MouseForward' };
+
+// Two keys/buttons, or a single mouse button (matches HotkeyBinding::is_valid).
+const isValidBinding = (keys: string[]) =>
+    keys.length === 2 || (keys.length === 1 && keys[0].startsWith('Mouse'));
+
+const sameCombo = (a: string[], b: string[]) =>
+    a.length === b.length && a.every(k => b.includes(k));
+
+const toBinding = (saved: Partial<HotkeyBinding>): HotkeyBinding => ({
+    keys: saved.keys!,
+    mode: saved.mode ?? 'hold',
+    start_keys: saved.start_keys ?? null,
+    stop_keys: saved.stop_keys ?? null,
+});
+
 const DEFAULT_BINDING: HotkeyBinding = {
     keys: isMac ? ['ControlLeft', 'AltLeft'] : ['ControlLeft', 'MetaLeft'],
     mode: 'hold',
@@ -49,6 +74,10 @@ export function RecordingTab({
     const [heldKeys, setHeldKeys] = useState<string[]>([]);
     const [pendingKeys, setPendingKeys] = useState<string[]>([]);
     const [hotkeySaved, setHotkeySaved] = useState(false);
+    const [hotkeyError, setHotkeyError] = useState<string | null>(null);
+    const [captureTarget, setCaptureTarget] = useState<CaptureTarget>('keys');
+    // A start/stop combo captured before its partner; saved once both exist.
+    const [splitDraft, setSplitDraft] = useState<{ start_keys?: string[]; stop_keys?: string[] }>({});
 
     const heldRef = useRef<string[]>([]);
     const pendingRef = useRef<string[]>([]);
@@ -59,7 +88,7 @@ export function RecordingTab({
                 const store = await Store.load('settings.json');
                 const saved = await store.get<Partial<HotkeyBinding>>('hotkey_binding');
                 if (saved?.keys?.length) {
-                    const binding: HotkeyBinding = { keys: saved.keys!, mode: saved.mode ?? 'hold' };
+                    const binding = toBinding(saved);
                     setCurrentBinding(binding);
                     setPendingMode(binding.mode);
                     return;
@@ -67,7 +96,7 @@ export function RecordingTab({
             } catch { /* fall through */ }
             const fromRust = await invoke<HotkeyBinding>('get_hotkey').catch(() => null);
             if (fromRust) {
-                const binding: HotkeyBinding = { keys: fromRust.keys, mode: fromRust.mode ?? 'hold' };
+                const binding = toBinding(fromRust);
                 setCurrentBinding(binding);
                 setPendingMode(binding.mode);
             }
@@ -75,35 +104,62 @@ export function RecordingTab({
         load();
     }, []);
 
-    const onKeyDown = useCallback((e: KeyboardEvent) => {
-        e.preventDefault(); e.stopPropagation();
-        if (!ALLOWED_KEYS.has(e.code)) return;
-        if (heldRef.current.includes(e.code)) return;
+    const pressCode = useCallback((code: string) => {
+        if (!ALLOWED_KEYS.has(code)) return;
+        if (heldRef.current.includes(code)) return;
         if (heldRef.current.length >= 2) return;
-        const next = [...heldRef.current, e.code];
+        const next = [...heldRef.current, code];
         heldRef.current = next; setHeldKeys([...next]);
         pendingRef.current = next; setPendingKeys([...next]);
     }, []);
 
-    const onKeyUp = useCallback((e: KeyboardEvent) => {
-        e.preventDefault();
-        heldRef.current = heldRef.current.filter(k => k !== e.code);
+    const releaseCode = useCallback((code: string) => {
+        heldRef.current = heldRef.current.filter(k => k !== code);
         setHeldKeys([...heldRef.current]);
     }, []);
 
+    const onKeyDown = useCallback((e: KeyboardEvent) => {
+        e.preventDefault(); e.stopPropagation();
+        pressCode(e.code);
+    }, [pressCode]);
+
+    const onKeyUp = useCallback((e: KeyboardEvent) => {
+        e.preventDefault();
+        releaseCode(e.code);
+    }, [releaseCode]);
+
+    const onMouseDown = useCallback((e: MouseEvent) => {
+        const code = MOUSE_BUTTON_CODES[e.button];
+        if (!code) return;
+        e.preventDefault(); e.stopPropagation();
+        pressCode(code);
+    }, [pressCode]);
+
+    const onMouseUp = useCallback((e: MouseEvent) => {
+        const code = MOUSE_BUTTON_CODES[e.button];
+        if (!code) return;
+        e.preventDefault();
+        releaseCode(code);
+    }, [releaseCode]);
+
     useEffect(() => {
         if (!recording) return;
         window.addEventListener('keydown', onKeyDown, true);
         window.addEventListener('keyup', onKeyUp, true);
+        window.addEventListener('mousedown', onMouseDown, true);
+        window.addEventListener('mouseup', onMouseUp, true);
         return () => {
             window.removeEventListener('keydown', onKeyDown, true);
             window.removeEventListener('keyup', onKeyUp, true);
+            window.removeEventListener('mousedown', onMouseDown, true);
+            window.removeEventListener('mouseup', onMouseUp, true);
         };
-    }, [recording, onKeyDown, onKeyUp]);
+    }, [recording, onKeyDown, onKeyUp, onMouseDown, onMouseUp]);
 
-    const startRecording = () => {
+    const startRecording = (target: CaptureTarget = 'keys') => {
         heldRef.current = []; pendingRef.current = [];
         setHeldKeys([]); setPendingKeys([]);
+        setCaptureTarget(target); setHotkeyError(null);
         setHotkeySaved(false); setRecording(true);
     };
 
@@ -113,36 +169,66 @@ export function RecordingTab({
         setHeldKeys([]); setPendingKeys([]);
     };
 
-    const saveBinding = async () => {
-        const keys = pendingRef.current;
-        if (keys.length !== 2) return;
-        const binding: HotkeyBinding = { keys, mode: pendingMode };
+    // Push a binding to the backend, which persists it. Callers spread the current
+    // binding so a change to one field never drops the others (e.g. start/stop combos).
+    const applyBinding = async (binding: HotkeyBinding) => {
         try {
             await invoke('set_hotkey', { binding });
-            const store = await Store.load('settings.json');
-            await store.set('hotkey_binding', binding);
-            await store.save();
             setCurrentBinding(binding);
-            setHotkeySaved(true); setRecording(false);
-            setPendingKeys([]); heldRef.current = []; pendingRef.current = [];
+            setHotkeyError(null);
+            setHotkeySaved(true);
             setTimeout(() => setHotkeySaved(false), 2000);
-        } catch (err) { console.error('Failed to save hotkey:', err); }
+            return true;
+        } catch (err) {
+            console.error('Failed to save hotkey:', err);
+            setHotkeyError(String(err));
+            return false;
+        }
+    };
+
+    const saveBinding = async () => {
+        const keys = pendingRef.current;
+        if (!isValidBinding(keys)) return;
+        let binding: HotkeyBinding | null = null;
+        if (captureTarget === 'keys') {
+            binding = { ...currentBinding, keys, mode: pendingMode };
+        } else {
+            const draft = {
+                start_keys: splitDraft.start_keys ?? currentBinding.start_keys ?? undefined,
+                stop_keys: splitDraft.stop_keys ?? currentBinding.stop_keys ?? undefined,
+                [captureTarget]: keys,
+            };
+            if (draft.start_keys && draft.stop_keys && sameCombo(draft.start_keys, draft.stop_keys)) {
+                setHotkeyError('Start and stop combos must be different.');
+                return;
+            }
+            setSplitDraft(draft);
+            if (draft.start_keys && draft.stop_keys) {
+                binding = { ...currentBinding, start_keys: draft.start_keys, stop_keys: draft.stop_keys };
+            }
+        }
+        if (binding && !(await applyBinding(binding))) return;
+        if (binding) setSplitDraft({});
+        setRecording(false);
+        setPendingKeys([]); heldRef.current = []; pendingRef.current = [];
+    };
+
+    const clearSplitCombos = async () => {
+        setSplitDraft({});
+        if (currentBinding.start_keys || currentBinding.stop_keys) {
+            await applyBinding({ ...currentBinding, start_keys: null, stop_keys: null });
+        }
     };
 
     const handleModeChange = async (mode: RecordingMode) => {
         setPendingMode(mode);
-        const binding: HotkeyBinding = { ...currentBinding, mode };
-        try {
-            await invoke('set_hotkey', { binding });
-            const store = await Store.load('settings.json');
-            await store.set('hotkey_binding', binding);
-            await store.save();
-            setCurrentBinding(binding);
-            setHotkeySaved(true);
-            setTimeout(() => setHotkeySaved(false), 2000);
-        } catch (err) { console.error('Failed to save mode:', err); }
+        await applyBinding({ ...currentBinding, mode });
     };
 
+    const startCombo = splitDraft.start_keys ?? currentBinding.start_keys ?? null;
+    const stopCombo = splitDraft.stop_keys ?? currentBinding.stop_keys ?? null;
+    const splitActive = !!(currentBinding.start_keys && currentBinding.stop_keys);
+
     const chips = (keys: string[]) =>
         keys.map((k, i) => <span key={i} className="key-chip">{KEY_LABELS[k] ?? k}</span>);
 
@@ -222,15 +308,46 @@ export function RecordingTab({
                         <div className="hotkey-chips">{chips(currentBinding.keys)}</div>
                         <div className="hotkey-current-actions">
                             {hotkeySaved && <span className="saved-confirm">Saved ✓</span>}
-                            <button className="ghost-btn" onClick={startRecording}>Change</button>
+                            <button className="ghost-btn" onClick={() => startRecording('keys')}>Change</button>
                         </div>
                     </div>
                 )}
 
+                {!recording && (
+                    <div className="hotkey-split" style={{ marginTop: '16px' }}>
+                        <p className="setting-card-desc">
+                            Optional: separate combos that only start or only stop recording.
+                            {splitActive && ' While both are set they replace the hotkey and mode above.'}
+                        </p>
+                        {([['start_keys', 'Start', startCombo], ['stop_keys', 'Stop', stopCombo]] as const).map(([target, label, combo]) => (
+                            <div className="setting-row" key={target}>
+                                <span className="setting-row-label">{label}</span>
+                                <div className="hotkey-current-actions">
+                                    <div className="hotkey-chips">
+                                        {combo ? chips(combo) : <span className="hotkey-capture-placeholder">Not set</span>}
+                                    </div>
+                                    <button className="ghost-btn" onClick={() => startRecording(target)}>
+                                        {combo ? 'Change' : 'Set'}
+                                    </button>
+                                </div>
+                            </div>
+                        ))}
+                        {(startCombo || stopCombo) && (
+                            <button className="ghost-btn" onClick={clearSplitCombos}>Clear start/stop combos</button>
+                        )}
+                    </div>
+                )}
+
+                {hotkeyError && (
+                    <p className="setting-card-desc" style={{ color: 'var(--error)', marginTop: '8px' }}>{hotkeyError}</p>
+                )}
+
                 {recording && (
                     <div className="hotkey-capture">
                         <p className="hotkey-capture-hint">
-                            Press exactly <strong>2 keys</strong> (modifier or F-key), then click Save.
+                            {captureTarget === 'start_keys' && <strong>Start combo: </strong>}
+                            {captureTarget === 'stop_keys' && <strong>Stop combo: </strong>}
+                            Press exactly <strong>2 keys</strong> (modifier or F-key), a key plus a mouse side/middle button, or a single mouse button, then click Save.
                         </p>
                         <div className="hotkey-capture-zone">
                             {captureChips.length > 0
@@ -242,9 +359,9 @@ export function RecordingTab({
                         </div>
                         <div className="hotkey-capture-actions">
                             <button
-                                className={`ghost-btn ghost-btn--confirm ${pendingKeys.length !== 2 ? 'ghost-btn--disabled' : ''}`}
+                                className={`ghost-btn ghost-btn--confirm ${!isValidBinding(pendingKeys) ? 'ghost-btn--disabled' : ''}`}
                                 onClick={saveBinding}
-                                disabled={pendingKeys.length !== 2}
+                                disabled={!isValidBinding(pendingKeys)}
                             >Save</button>
                             <button className="ghost-btn" onClick={cancelRecording}>Cancel</button>
                         </div>
@@ -11,6 +11,14 @@ interface DownloadProgressPayload {
     status: string;
     current_file?: number;
     total_files?: number;
+    bytes_per_sec?: number;
+    eta_secs?: number;
+}
+
+interface DownloadStalledPayload {
+    model_id: string;
+    downloaded_bytes: number;
+    stalled_secs: number;
 }
 
 const STALL_CHECK_INTERVAL_MS = 5_000;
@@ -23,6 +31,9 @@ export function useDownloads(
     const [downloadProgress, setDownloadProgress] = useState<Record<string, DownloadProgress>>({});
     const activeDownloadsRef = useRef<Set<string>>(new Set());
     const cancelledRef = useRef<Set<string>>(new Set());
+    // Downloads being cancelled only to start again (id → display name).
+    const restartingRef = useRef<Map<string, string>>(new Map());
+    const namesRef = useRef<Record<string, string>>({});
     const lastActivityRef = useRef<Record<string, { bytes: number; time: number }>>({});
     const onDownloadFailedRef = useRef(onDownloadFailed);
     onDownloadFailedRef.current = onDownloadFailed;
@@ -123,7 +134,9 @@ export function useDownloads(
                     activeDownloadsRef.current.delete(payload.model_id);
                     cancelledRef.current.add(payload.model_id);
                     delete lastActivityRef.current[payload.model_id];
-                    toast.info(`Download cancelled: ${payload.model_id}`);
+                    if (!restartingRef.current.has(payload.model_id)) {
+                        toast.info(`Download cancelled: ${payload.model_id}`);
+                    }
                     clearProgress(payload.model_id);
                 } else if (payload.status === "delete-done") {
                     clearProgress(payload.model_id);
@@ -138,11 +151,38 @@ export function useDownloads(
         };
     }, [onModelDownloaded, clearProgress, markError]);
 
+    useEffect(() => {
+        let unlisten: (() => void) | undefined;
+
+        listen<DownloadStalledPayload>("download-stalled", (event) => {
+            const { model_id } = event.payload;
+            if (!activeDownloadsRef.current.has(model_id)) return;
+            toast.warning(`Download stalled: ${model_id}`, {
+                id: `stalled-${model_id}`,
+                description: "No data received from the server.",
+                action: {
+                    label: "Restart",
+                    onClick: () => {
+                        restartingRef.current.set(model_id, namesRef.current[model_id] ?? model_id);
+                        invoke("cancel_download", { modelId: model_id }).catch(() => {});
+                    },
+                },
+            });
+        }).then((fn) => {
+            unlisten = fn;
+        });
+
+        return () => {
+            if (unlisten) unlisten();
+        };
+    }, []);
+
     const handleDownload = async (id: string, name: string) => {
         if (activeDownloadsRef.current.has(id)) {
             return;
         }
         activeDownloadsRef.current.add(id);
+        namesRef.current[id] = name;
         lastActivityRef.current[id] = { bytes: 0, time: Date.now() };
         toast.info(`Starting download: ${name}`);
         setDownloadProgress((prev) => ({
@@ -156,6 +196,11 @@ export function useDownloads(
             delete lastActivityRef.current[id];
             if (cancelledRef.current.has(id)) {
                 cancelledRef.current.delete(id);
+                const restartName = restartingRef.current.get(id);
+                if (restartName !== undefined) {
+                    restartingRef.current.delete(id);
+                    void handleDownload(id, restartName);
+                }
                 return;
             }
             const raw = `${e ?? "Unknown error"}`;
@@ -140,12 +140,6 @@ export function useEngineSwitch({
             setCurrentModel(modelId);
             setLoadedEngine("whisper");
 
-            if (storeRef.current) {
-                await storeRef.current.set("whisper_model", modelId);
-                await storeRef.current.set("active_engine", "whisper");
-                await storeRef.current.save();
-            }
-
             const backend = await invoke("get_backend_info");
             setBackendInfo(backend as string);
         }).catch(e => {
@@ -226,12 +220,6 @@ export function useEngineSwitch({
             setLoadedEngine("parakeet");
             setSessionNotice?.(null);
 
-            if (storeRef.current) {
-                await storeRef.current.set("parakeet_model", targetModel);
-                await storeRef.current.set("active_engine", "parakeet");
-                await storeRef.current.save();
-            }
-
             setHeaderStatus("Switched to Parakeet");
             const backend = await invoke("get_backend_info");
             setBackendInfo(backend as string);
@@ -296,12 +284,9 @@ export function useEngineSwitch({
                 setAsrBackend("gpu");
             }
 
-            if (storeRef.current) {
-                await storeRef.current.set("granite_model", targetModel);
-                await storeRef.current.set("active_engine", "cohere");
-                if (fp16) {
-                    await storeRef.current.set("asr_backend", "gpu");
-                }
+            // The engine and model keys are persisted by the backend.
+            if (fp16 && storeRef.current) {
+                await storeRef.current.set("asr_backend", "gpu");
                 await storeRef.current.save();
             }
 
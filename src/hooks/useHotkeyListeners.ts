@@ -2,6 +2,7 @@ import { useEffect, useRef, type MutableRefObject } from "react";
 import { listen } from "@tauri-apps/api/event";
 import { invoke } from "@tauri-apps/api/core";
 import type { ASREngine } from "./useEngineSwitch";
+import type { CommandResult, TranscriptionOutcome } from "../types/session";
 
 interface UseHotkeyListenersParams {
     // Recording state refs
@@ -18,7 +19,7 @@ interface UseHotkeyListenersParams {
 
     // Stable handler refs (always point to latest closure)
     handleStartRecordingRef: React.RefObject<(fromHotkey?: boolean) => Promise<void>>;
-    handleStopRecordingRef: React.RefObject<() => Promise<void>>;
+    handleStopRecordingRef: React.RefObject<(fromHotkey?: boolean, stoppedResult?: CommandResult<TranscriptionOutcome>) => Promise<void>>;
     handlePauseRecordingRef: React.RefObject<() => Promise<void>>;
     handleResumeRecordingRef: React.RefObject<() => Promise<void>>;
     handleCancelRecordingRef: React.RefObject<() => Promise<void>>;
@@ -76,11 +77,15 @@ export function useHotkeyListeners({
         let unlistenStop: (() => void) | undefined;
         let unlistenChunk: (() => void) | undefined;
         let unlistenAccessibility: (() => void) | undefined;
+        let unlistenAccessibilityNeeded: (() => void) | undefined;
         let unlistenAudioFallback: (() => void) | undefined;
         let unlistenAudioDisconnect: (() => void) | undefined;
         let unlistenOverlayAction: (() => void) | undefined;
         let unlistenModelUnloaded: (() => void) | undefined;
         let unlistenAudioLevel: (() => void) | undefined;
+        let unlistenModelMismatch: (() => void) | undefined;
+        let unlistenAutoStopped: (() => void) | undefined;
+        let unlistenTranscriptionError: (() => void) | undefined;
 
         const SILENCE_THRESHOLD = 0.02;
         const SILENCE_DELAY_MS = 3000;
@@ -160,7 +165,7 @@ export function useHotkeyListeners({
                 if (pendingStopRef.current) {
                     pendingStopRef.current = false;
                     setTimeout(async () => {
-                        await handleStopRecordingRef.current?.();
+                        await handleStopRecordingRef.current?.(true);
                     }, 250);
                 }
             });
@@ -182,7 +187,7 @@ export function useHotkeyListeners({
                 lastStopTime.current = now;
 
                 try {
-                    await handleStopRecordingRef.current?.();
+                    await handleStopRecordingRef.current?.(true);
                 } finally {
                     stopInProgressRef.current = false;
                 }
@@ -198,12 +203,14 @@ export function useHotkeyListeners({
                 void refreshMacPermissions();
             });
 
-            const unsub5 = await listen("audio-fallback", (event) => {
-                const deviceName = event.payload as string;
-                setHeaderStatusRef.current?.(`Mic lost, using fallback: ${deviceName}`, 6000);
+            const unsub5 = await listen<{ requested: string; fallback: string }>("device-fallback", (event) => {
+                const { requested, fallback } = event.payload;
+                setHeaderStatusRef.current?.(`${requested} not found, using ${fallback}`, 6000);
             });
 
-            const unsub6 = await listen<{ code?: string; message?: string } | string>("audio-disconnected", (event) => {
+            // The backend already stopped the session when its input device failed;
+            // finish it with the result it reported, as for the length limit.
+            const unsub6 = await listen<{ code?: string; message?: string; result?: CommandResult<TranscriptionOutcome> } | string>("recording-device-lost", (event) => {
                 const payload = typeof event.payload === "string"
                     ? { code: "audio_device_disconnected", message: event.payload }
                     : event.payload;
@@ -215,7 +222,7 @@ export function useHotkeyListeners({
                 );
                 if (isRecordingRef.current && !stopInProgressRef.current) {
                     stopInProgressRef.current = true;
-                    handleStopRecordingRef.current?.().finally(() => {
+                    handleStopRecordingRef.current?.(false, payload?.result).finally(() => {
                         stopInProgressRef.current = false;
                     });
                 }
@@ -270,6 +277,47 @@ export function useHotkeyListeners({
                 }
             });
 
+            // Text insertion was blocked because Accessibility access hasn't been granted.
+            const unsub10 = await listen<string>("accessibility-permission-needed", (event) => {
+                setHeaderStatusRef.current?.(event.payload, 10000);
+                void refreshMacPermissions();
+            });
+
+            // The loaded Whisper model is English-only but another language was selected.
+            const unsub11 = await listen<{ language: string; current_model: string; recommended_model: string; downloaded: boolean }>("model-mismatch", (event) => {
+                const { language, current_model, recommended_model, downloaded } = event.payload;
+                setHeaderStatusRef.current?.(
+                    `${current_model} is English-only and can't transcribe "${language}" — ${downloaded ? "switch to" : "download"} ${recommended_model} in Settings > Models`,
+                    10000
+                );
+            });
+
+            // The backend stopped and transcribed the session at the max-duration
+            // limit; finish it (insert, history) with the result it already has.
+            const unsub12 = await listen<{ max_secs: number; result: CommandResult<TranscriptionOutcome> }>("recording-auto-stopped", (event) => {
+                setHeaderStatusRef.current?.(
+                    `Recording stopped after reaching the ${event.payload.max_secs}s limit`,
+                    6000
+                );
+                if (isRecordingRef.current && !stopInProgressRef.current) {
+                    stopInProgressRef.current = true;
+                    handleStopRecordingRef.current?.(false, event.payload.result).finally(() => {
+                        stopInProgressRef.current = false;
+                    });
+                }
+            });
+
+            // A chunk or final pass failed in the backend (e.g. out of VRAM). Throttled
+            // so a run of failing chunks doesn't keep resetting the header.
+            let lastTranscriptionErrorAt = 0;
+            const unsub13 = await listen<{ engine: string; stage: string; message: string }>("transcription-error", (event) => {
+                const now = Date.now();
+                if (now - lastTranscriptionErrorAt < 5000) return;
+                lastTranscriptionErrorAt = now;
+                const { engine, message } = event.payload;
+                setHeaderStatusRef.current?.(`${engine} transcription failed: ${message}`, 6000);
+            });
+
             if (active) {
                 unlistenStart = unsub1;
                 unlistenStop = unsub2;
@@ -280,9 +328,13 @@ export function useHotkeyListeners({
                 unlistenOverlayAction = unsub7;
                 unlistenModelUnloaded = unsub8;
                 unlistenAudioLevel = unsub9;
+                unlistenAccessibilityNeeded = unsub10;
+                unlistenModelMismatch = unsub11;
+                unlistenAutoStopped = unsub12;
+                unlistenTranscriptionError = unsub13;
             } else {
                 unsub1(); unsub2(); unsub3(); unsub4();
-                unsub5(); unsub6(); unsub7(); unsub8(); unsub9();
+                unsub5(); unsub6(); unsub7(); unsub8(); unsub9(); unsub10(); unsub11(); unsub12(); unsub13();
             }
         };
 
@@ -294,11 +346,15 @@ export function useHotkeyListeners({
             unlistenStop?.();
             unlistenChunk?.();
             unlistenAccessibility?.();
+            unlistenAccessibilityNeeded?.();
             unlistenAudioFallback?.();
             unlistenAudioDisconnect?.();
             unlistenOverlayAction?.();
             unlistenModelUnloaded?.();
             unlistenAudioLevel?.();
+            unlistenModelMismatch?.();
+            unlistenAutoStopped?.();
+            unlistenTranscriptionError?.();
         };
     }, []); // eslint-disable-line react-hooks/exhaustive-deps
 }
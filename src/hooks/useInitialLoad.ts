@@ -138,23 +138,12 @@ export function useInitialLoad({
                     const setupComplete = await loadedStore.get<boolean>("setup_complete");
                     if (!cancelled) setShowSetupWizard(setupComplete !== true);
 
-                    // Restore saved hotkey binding
-                    const savedHotkey = await loadedStore.get<{ keys: string[] }>("hotkey_binding");
-                    if (savedHotkey?.keys?.length && !cancelled) {
-                        invoke("set_hotkey", { binding: savedHotkey }).catch(() => {});
-                    }
-
-                    // Restore saved input device preference
-                    const savedDevice = await loadedStore.get<string>("input_device");
-                    if (savedDevice && !cancelled) {
-                        invoke("set_input_device", { name: savedDevice }).catch(() => {});
-                    }
-
-                    // Restore close-button behavior preference
+                    // The hotkey, input device and close behavior are owned by the
+                    // backend (it persists them and restores them during setup, see
+                    // persist_setting); only mirror them into UI state here.
                     const savedCloseBehavior = await loadedStore.get<"tray" | "quit">("close_behavior");
                     if (savedCloseBehavior && !cancelled) {
                         setCloseBehavior(savedCloseBehavior);
-                        invoke("set_close_behavior", { behaviour: savedCloseBehavior }).catch(() => {});
                     }
 
                     savedEngine =
@@ -29,6 +29,8 @@ export interface ParakeetStatus {
     model_id: string | null;
     model_type: string | null;
     backend: string;
+    language: string | null;
+    supports_streaming: boolean;
 }
 
 /**
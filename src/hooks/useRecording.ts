@@ -3,9 +3,7 @@ import { invoke } from "@tauri-apps/api/core";
 import type { ModelInfo, ParakeetModelInfo, CohereModelInfo } from "./useModels";
 import { COHERE_FP16_MODEL_ID } from "../utils/engineUtils";
 import type { ASREngine } from "./useEngineSwitch";
-import { applyDictionary, applySnippets } from "./usePersonalization";
-import type { DictEntry, SnippetEntry } from "./usePersonalization";
-import type { CommandResult, SessionNotice } from "../types/session";
+import type { CommandResult, SessionNotice, TranscriptionOutcome } from "../types/session";
 
 interface UseRecordingParams {
     activeEngineRef: React.RefObject<ASREngine>;
@@ -31,8 +29,6 @@ interface UseRecordingParams {
     playStart?: () => void;
     playPaste?: () => void;
     playError?: () => void;
-    dictionaryRef: React.RefObject<DictEntry[]>;
-    snippetsRef: React.RefObject<SnippetEntry[]>;
     /** Called after each successful save_transcript_history — lets the parent refresh the history UI. */
     onHistorySaved?: () => void;
     setSessionPhase?: (phase: "idle" | "loading_model" | "recording" | "paused" | "processing" | "success" | "warning" | "error") => void;
@@ -82,8 +78,6 @@ export function useRecording({
     playStart,
     playPaste,
     playError,
-    dictionaryRef,
-    snippetsRef,
     onHistorySaved,
     setSessionPhase,
     setSessionNotice,
@@ -348,7 +342,15 @@ export function useRecording({
             setIsRecording(false);
             isRecordingRef.current = false;
             setSessionPhase?.("error");
-            showNotice(commandErrorToNotice(error, "Recording failed to start"));
+            showNotice(error.code === "no_input_device"
+                ? {
+                    level: "error",
+                    code: "no_input_device",
+                    title: "No microphone found",
+                    message: "Please connect a microphone, then try recording again.",
+                    sticky: true,
+                }
+                : commandErrorToNotice(error, "Recording failed to start"));
             if (fromHotkey) hideOverlay();
         }
     };
@@ -383,7 +385,9 @@ export function useRecording({
         }
     };
 
-    const handleStopRecording = async () => {
+    // `stoppedResult` is passed when the backend already ran stop_recording itself
+    // (max-duration auto-stop); the session is then only finished here.
+    const handleStopRecording = async (fromHotkey = false, stoppedResult?: CommandResult<TranscriptionOutcome>) => {
         const currentEngine = activeEngineRef.current;
         const processingStartMs = Date.now();
         const isOverlay = hotkeySessionRef.current;              // true for any hotkey session
@@ -404,14 +408,31 @@ export function useRecording({
             await setTrayState("processing");
             if (currentEngine === "whisper") setHeaderStatus("Processing transcription...", 15_000, true);
 
-            const stopResult = await invoke<CommandResult<string>>("stop_recording");
+            const stopResult = stoppedResult ?? await invoke<CommandResult<TranscriptionOutcome>>("stop_recording");
             if (!stopResult.ok) {
                 throw stopResult.error ?? new Error("Failed to stop recording");
             }
-            let finalTrans = stopResult.data ?? "";
-
-            // Apply custom dictionary substitutions (before grammar LLM)
-            finalTrans = applyDictionary(finalTrans, dictionaryRef.current ?? []);
+            const outcome = stopResult.data;
+            if (outcome?.kind === "failed") {
+                throw { code: "recording_stop_failed", message: outcome.data };
+            }
+            if (outcome?.kind === "voice_command") {
+                // The backend already emitted "voice-command"; nothing is inserted.
+                setHeaderStatus(`Voice command: ${outcome.data.phrase}`, 3000);
+                if (muteBackgroundAudioRef.current) {
+                    await invoke("unmute_system_audio").catch(() => {});
+                }
+                resetRecordingSession();
+                setIsProcessingTranscript(false);
+                await setTrayState("ready");
+                setSessionPhase?.("success");
+                if (isOverlay) {
+                    hideOverlay();
+                }
+                return;
+            }
+            // The backend has already applied the custom dictionary (before grammar LLM).
+            let finalTrans = outcome?.kind === "text" ? outcome.data : "";
 
             const recordingDurationMs = getEffectiveRecordingMs();
             if (recordingDurationMs < MIN_RECORDING_MS) {
@@ -490,7 +511,7 @@ export function useRecording({
             }
 
             // Apply text snippets last (after grammar LLM so expansions aren't mangled)
-            finalTrans = applySnippets(finalTrans, snippetsRef.current ?? []);
+            finalTrans = await invoke<string>("expand_snippets", { text: finalTrans }).catch(() => finalTrans);
 
             const totalMs = Date.now() - processingStartMs;
             setLatestLatency(totalMs);
@@ -501,16 +522,26 @@ export function useRecording({
 
             // Capture paste result without blocking history/unmute — a failed
             // paste means the transcript is still shown in the UI, just not
-            // inserted into the target app.
+            // inserted into the target app. The post-recording action decides
+            // whether the transcript is pasted, only copied to the clipboard
+            // (always, or just for hotkey stops), or left in the UI for review.
             let pasteError: string | null = null;
-            try {
-                const typeResult = await invoke<CommandResult<null>>("type_text", { text: finalTrans });
-                if (!typeResult.ok) {
-                    pasteError = typeResult.error?.code ?? typeResult.error?.message ?? "paste_failed";
+            const postAction = await invoke<"paste" | "copy_only" | "copy_on_hotkey" | "nothing">(
+                "get_post_recording_action",
+            ).catch(() => "paste" as const);
+            const insertCommand = postAction === "copy_only" || (postAction === "copy_on_hotkey" && fromHotkey)
+                ? "copy_to_clipboard"
+                : "type_text";
+            if (postAction !== "nothing") {
+                try {
+                    const typeResult = await invoke<CommandResult<null>>(insertCommand, { text: finalTrans });
+                    if (!typeResult.ok) {
+                        pasteError = typeResult.error?.code ?? typeResult.error?.message ?? "paste_failed";
+                    }
+                } catch (e) {
+                    pasteError = String(e);
+                    console.warn(`[INSERT] ${insertCommand} failed:`, pasteError);
                 }
-            } catch (e) {
-                pasteError = String(e);
-                console.warn("[INSERT] type_text failed:", pasteError);
             }
 
             if (muteBackgroundAudioRef.current) {
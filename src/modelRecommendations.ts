@@ -6,6 +6,11 @@ export interface SystemInfo {
   cuda_available: boolean;
   vram_gb: number | null;
   backend_hint: string;
+  /** What the compiled runtimes can actually use (vs. the hardware-based hint). */
+  backends: {
+    whisper_gpu: string | null;
+    onnx_providers: string[];
+  };
 }
 
 export type WhisperTier = "Tiny" | "Base" | "Small" | "Medium" | "Large";
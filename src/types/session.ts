@@ -22,6 +22,7 @@ export type SessionErrorCode =
   | "nothing_heard"
   | "model_load_failed"
   | "paste_failed"
+  | "accessibility_denied"
   | "recording_start_failed"
   | "recording_stop_failed"
   | "unknown";
@@ -37,6 +38,20 @@ export interface CommandResult<T> {
   error: CommandErrorPayload | null;
 }
 
+/** Result of `stop_recording` (serde adjacently tagged on `kind`). */
+export type TranscriptionOutcome =
+  | { kind: "text"; data: string }
+  | { kind: "silence" }
+  | { kind: "saved_no_transcript" }
+  | { kind: "failed"; data: string }
+  | { kind: "voice_command"; data: VoiceCommand };
+
+/** Spoken phrase mapped to an action id (see `set_voice_commands`). */
+export interface VoiceCommand {
+  phrase: string;
+  action: string;
+}
+
 export interface EngineSelectionState {
   active_engine: "whisper" | "parakeet" | "cohere";
   selected_model_id: string | null;